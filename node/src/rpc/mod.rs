@@ -59,6 +59,8 @@ where
     C::Api: basednode_custom_rpc_runtime_api::BrainInfoRuntimeApi<Block>,
     C::Api: basednode_custom_rpc_runtime_api::BrainRegistrationRuntimeApi<Block>,
     C::Api: basednode_custom_rpc_runtime_api::TftEnforcerDataRuntimeApi<Block>,
+    C::Api: basednode_custom_rpc_runtime_api::EmissionLedgerRuntimeApi<Block>,
+    C::Api: basednode_custom_rpc_runtime_api::EpochDryRunRuntimeApi<Block>,
     C: BlockchainEvents<Block> + 'static,
     C: HeaderBackend<Block>
         + HeaderMetadata<Block, Error = BlockChainError>
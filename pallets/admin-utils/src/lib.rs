@@ -10,6 +10,7 @@ use sp_runtime::{
 };
 
 use frame_support::dispatch::DispatchError;
+use pallet_basednode::{BrainRole, ConsensusMechanismType, HyperparamChange};
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
@@ -112,8 +113,14 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::sudo_set_serving_rate_limit())]
 		pub fn sudo_set_serving_rate_limit(origin: OriginFor<T>, netuid: u16, serving_rate_limit: u64) -> DispatchResult
 		{
-			T::Basednode::ensure_brain_owner_or_root(origin, netuid)?;
-
+			let is_root = ensure_root(origin.clone()).is_ok();
+			T::Basednode::ensure_brain_role_or_root(origin, netuid, BrainRole::HyperparamAdmin)?;
+
+			if !is_root {
+				T::Basednode::schedule_hyperparam_change(netuid, HyperparamChange::ServingRateLimit(serving_rate_limit));
+				return Ok(());
+			}
+			T::Basednode::cancel_hyperparam_change(netuid, HyperparamChange::ServingRateLimit(serving_rate_limit));
 			T::Basednode::set_serving_rate_limit(netuid, serving_rate_limit);
 			log::info!(
 				"ServingRateLimitSet( serving_rate_limit: {:?} ) ",
@@ -126,12 +133,18 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::sudo_set_min_difficulty())]
 		pub fn sudo_set_min_difficulty(origin: OriginFor<T>, netuid: u16, min_difficulty: u64) -> DispatchResult
 		{
-			T::Basednode::ensure_brain_owner_or_root(origin, netuid)?;
+			let is_root = ensure_root(origin.clone()).is_ok();
+			T::Basednode::ensure_brain_role_or_root(origin, netuid, BrainRole::HyperparamAdmin)?;
 
 			ensure!(
 				T::Basednode::if_brain_exist(netuid),
 				Error::<T>::NetworkDoesNotExist
 			);
+			if !is_root {
+				T::Basednode::schedule_hyperparam_change(netuid, HyperparamChange::MinDifficulty(min_difficulty));
+				return Ok(());
+			}
+			T::Basednode::cancel_hyperparam_change(netuid, HyperparamChange::MinDifficulty(min_difficulty));
 			T::Basednode::set_min_difficulty(netuid, min_difficulty);
 			log::info!(
 				"MinDifficultySet( netuid: {:?} min_difficulty: {:?} ) ",
@@ -145,12 +158,18 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::sudo_set_max_difficulty())]
 		pub fn sudo_set_max_difficulty(origin: OriginFor<T>, netuid: u16, max_difficulty: u64) -> DispatchResult
 		{
-			T::Basednode::ensure_brain_owner_or_root(origin, netuid)?;
+			let is_root = ensure_root(origin.clone()).is_ok();
+			T::Basednode::ensure_brain_role_or_root(origin, netuid, BrainRole::HyperparamAdmin)?;
 
 			ensure!(
 				T::Basednode::if_brain_exist(netuid),
 				Error::<T>::NetworkDoesNotExist
 			);
+			if !is_root {
+				T::Basednode::schedule_hyperparam_change(netuid, HyperparamChange::MaxDifficulty(max_difficulty));
+				return Ok(());
+			}
+			T::Basednode::cancel_hyperparam_change(netuid, HyperparamChange::MaxDifficulty(max_difficulty));
 			T::Basednode::set_max_difficulty(netuid, max_difficulty);
 			log::info!(
 				"MaxDifficultySet( netuid: {:?} max_difficulty: {:?} ) ",
@@ -165,12 +184,18 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::sudo_set_weights_version_key())]
 		pub fn sudo_set_weights_version_key(origin: OriginFor<T>, netuid: u16, weights_version_key: u64) -> DispatchResult
 		{
-			T::Basednode::ensure_brain_owner_or_root(origin, netuid)?;
+			let is_root = ensure_root(origin.clone()).is_ok();
+			T::Basednode::ensure_brain_role_or_root(origin, netuid, BrainRole::HyperparamAdmin)?;
 
 			ensure!(
 				T::Basednode::if_brain_exist(netuid),
 				Error::<T>::NetworkDoesNotExist
 			);
+			if !is_root {
+				T::Basednode::schedule_hyperparam_change(netuid, HyperparamChange::WeightsVersionKey(weights_version_key));
+				return Ok(());
+			}
+			T::Basednode::cancel_hyperparam_change(netuid, HyperparamChange::WeightsVersionKey(weights_version_key));
 			T::Basednode::set_weights_version_key(netuid, weights_version_key);
 			log::info!(
 				"WeightsVersionKeySet( netuid: {:?} weights_version_key: {:?} ) ",
@@ -184,12 +209,18 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::sudo_set_weights_set_rate_limit())]
 		pub fn sudo_set_weights_set_rate_limit(origin: OriginFor<T>, netuid: u16, weights_set_rate_limit: u64) -> DispatchResult
 		{
-			T::Basednode::ensure_brain_owner_or_root(origin, netuid)?;
+			let is_root = ensure_root(origin.clone()).is_ok();
+			T::Basednode::ensure_brain_role_or_root(origin, netuid, BrainRole::HyperparamAdmin)?;
 
 			ensure!(
 				T::Basednode::if_brain_exist(netuid),
 				Error::<T>::NetworkDoesNotExist
 			);
+			if !is_root {
+				T::Basednode::schedule_hyperparam_change(netuid, HyperparamChange::WeightsSetRateLimit(weights_set_rate_limit));
+				return Ok(());
+			}
+			T::Basednode::cancel_hyperparam_change(netuid, HyperparamChange::WeightsSetRateLimit(weights_set_rate_limit));
 			T::Basednode::set_weights_set_rate_limit(netuid, weights_set_rate_limit);
 			log::info!(
 				"WeightsSetRateLimitSet( netuid: {:?} weights_set_rate_limit: {:?} ) ",
@@ -245,12 +276,18 @@ pub mod pallet {
 		#[pallet::call_index(12)]
 		#[pallet::weight(T::WeightInfo::sudo_set_max_weight_limit())]
 		pub fn sudo_set_max_weight_limit(origin: OriginFor<T>, netuid: u16, max_weight_limit: u16) -> DispatchResult {
-		   T::Basednode::ensure_brain_owner_or_root(origin, netuid)?;
+			let is_root = ensure_root(origin.clone()).is_ok();
+			T::Basednode::ensure_brain_role_or_root(origin, netuid, BrainRole::HyperparamAdmin)?;
 
 			ensure!(
 				T::Basednode::if_brain_exist(netuid),
 				Error::<T>::NetworkDoesNotExist
 			);
+			if !is_root {
+				T::Basednode::schedule_hyperparam_change(netuid, HyperparamChange::MaxWeightLimit(max_weight_limit));
+				return Ok(());
+			}
+			T::Basednode::cancel_hyperparam_change(netuid, HyperparamChange::MaxWeightLimit(max_weight_limit));
 			T::Basednode::set_max_weight_limit(netuid, max_weight_limit);
 			log::info!(
 				"MaxWeightLimitSet( netuid: {:?} max_weight_limit: {:?} ) ",
@@ -264,12 +301,18 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::sudo_set_immunity_period())]
 		pub fn sudo_set_immunity_period(origin: OriginFor<T>, netuid: u16, immunity_period: u16) -> DispatchResult
 		{
-			T::Basednode::ensure_brain_owner_or_root(origin, netuid)?;
+			let is_root = ensure_root(origin.clone()).is_ok();
+			T::Basednode::ensure_brain_role_or_root(origin, netuid, BrainRole::HyperparamAdmin)?;
 			ensure!(
 				T::Basednode::if_brain_exist(netuid),
 				Error::<T>::NetworkDoesNotExist
 			);
 
+			if !is_root {
+				T::Basednode::schedule_hyperparam_change(netuid, HyperparamChange::ImmunityPeriod(immunity_period));
+				return Ok(());
+			}
+			T::Basednode::cancel_hyperparam_change(netuid, HyperparamChange::ImmunityPeriod(immunity_period));
 			T::Basednode::set_immunity_period(netuid, immunity_period);
 			log::info!(
 				"ImmunityPeriodSet( netuid: {:?} immunity_period: {:?} ) ",
@@ -283,12 +326,18 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::sudo_set_min_allowed_weights())]
 		pub fn sudo_set_min_allowed_weights(origin: OriginFor<T>, netuid: u16, min_allowed_weights: u16) -> DispatchResult
 		{
-			T::Basednode::ensure_brain_owner_or_root(origin, netuid)?;
+			let is_root = ensure_root(origin.clone()).is_ok();
+			T::Basednode::ensure_brain_role_or_root(origin, netuid, BrainRole::HyperparamAdmin)?;
 
 			ensure!(
 				T::Basednode::if_brain_exist(netuid),
 				Error::<T>::NetworkDoesNotExist
 			);
+			if !is_root {
+				T::Basednode::schedule_hyperparam_change(netuid, HyperparamChange::MinAllowedWeights(min_allowed_weights));
+				return Ok(());
+			}
+			T::Basednode::cancel_hyperparam_change(netuid, HyperparamChange::MinAllowedWeights(min_allowed_weights));
 			T::Basednode::set_min_allowed_weights(netuid, min_allowed_weights);
 			log::info!(
 				"MinAllowedWeightSet( netuid: {:?} min_allowed_weights: {:?} ) ",
@@ -354,12 +403,18 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::sudo_set_activity_cutoff())]
 		pub fn sudo_set_activity_cutoff(origin: OriginFor<T>, netuid: u16, activity_cutoff: u16) -> DispatchResult
 		{
-			T::Basednode::ensure_brain_owner_or_root(origin, netuid)?;
+			let is_root = ensure_root(origin.clone()).is_ok();
+			T::Basednode::ensure_brain_role_or_root(origin, netuid, BrainRole::HyperparamAdmin)?;
 
 			ensure!(
 				T::Basednode::if_brain_exist(netuid),
 				Error::<T>::NetworkDoesNotExist
 			);
+			if !is_root {
+				T::Basednode::schedule_hyperparam_change(netuid, HyperparamChange::ActivityCutoff(activity_cutoff));
+				return Ok(());
+			}
+			T::Basednode::cancel_hyperparam_change(netuid, HyperparamChange::ActivityCutoff(activity_cutoff));
 			T::Basednode::set_activity_cutoff(netuid, activity_cutoff);
 			log::info!(
 				"ActivityCutoffSet( netuid: {:?} activity_cutoff: {:?} ) ",
@@ -379,7 +434,13 @@ pub mod pallet {
 		))]
 		pub fn sudo_set_network_registration_allowed(origin: OriginFor<T>, netuid: u16, registration_allowed: bool) -> DispatchResult
 		{
-			T::Basednode::ensure_brain_owner_or_root(origin, netuid)?;
+			// Closing registrations is also open to the emergency pausers of the brain.
+			if registration_allowed {
+				T::Basednode::ensure_brain_role_or_root(origin, netuid, BrainRole::RegistrationAdmin)?;
+			} else {
+				T::Basednode::ensure_brain_role_or_root(origin.clone(), netuid, BrainRole::EmergencyPauser)
+					.or_else(|_| T::Basednode::ensure_brain_role_or_root(origin, netuid, BrainRole::RegistrationAdmin))?;
+			}
 
 			T::Basednode::set_network_registration_allowed(netuid, registration_allowed);
 			log::info!(
@@ -398,7 +459,13 @@ pub mod pallet {
 		))]
 		pub fn sudo_set_network_pow_registration_allowed(origin: OriginFor<T>, netuid: u16, registration_allowed: bool) -> DispatchResult
 		{
-			T::Basednode::ensure_brain_owner_or_root(origin, netuid)?;
+			// Closing registrations is also open to the emergency pausers of the brain.
+			if registration_allowed {
+				T::Basednode::ensure_brain_role_or_root(origin, netuid, BrainRole::RegistrationAdmin)?;
+			} else {
+				T::Basednode::ensure_brain_role_or_root(origin.clone(), netuid, BrainRole::EmergencyPauser)
+					.or_else(|_| T::Basednode::ensure_brain_role_or_root(origin, netuid, BrainRole::RegistrationAdmin))?;
+			}
 
 			T::Basednode::set_network_pow_registration_allowed(netuid, registration_allowed);
 			log::info!(
@@ -431,12 +498,18 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::sudo_set_min_burn())]
 		pub fn sudo_set_min_burn(origin: OriginFor<T>, netuid: u16, min_burn: u128) -> DispatchResult
 		{
-			T::Basednode::ensure_brain_owner_or_root(origin, netuid)?;
+			let is_root = ensure_root(origin.clone()).is_ok();
+			T::Basednode::ensure_brain_role_or_root(origin, netuid, BrainRole::RegistrationAdmin)?;
 
 			ensure!(
 				T::Basednode::if_brain_exist(netuid),
 				Error::<T>::NetworkDoesNotExist
 			);
+			if !is_root {
+				T::Basednode::schedule_hyperparam_change(netuid, HyperparamChange::MinBurn(min_burn));
+				return Ok(());
+			}
+			T::Basednode::cancel_hyperparam_change(netuid, HyperparamChange::MinBurn(min_burn));
 			T::Basednode::set_min_burn(netuid, min_burn);
 			log::info!(
 				"MinBurnSet( netuid: {:?} min_burn: {:?} ) ",
@@ -450,12 +523,18 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::sudo_set_max_burn())]
 		pub fn sudo_set_max_burn(origin: OriginFor<T>, netuid: u16, max_burn: u128) -> DispatchResult
 		{
-			T::Basednode::ensure_brain_owner_or_root(origin, netuid)?;
+			let is_root = ensure_root(origin.clone()).is_ok();
+			T::Basednode::ensure_brain_role_or_root(origin, netuid, BrainRole::RegistrationAdmin)?;
 
 			ensure!(
 				T::Basednode::if_brain_exist(netuid),
 				Error::<T>::NetworkDoesNotExist
 			);
+			if !is_root {
+				T::Basednode::schedule_hyperparam_change(netuid, HyperparamChange::MaxBurn(max_burn));
+				return Ok(());
+			}
+			T::Basednode::cancel_hyperparam_change(netuid, HyperparamChange::MaxBurn(max_burn));
 			T::Basednode::set_max_burn(netuid, max_burn);
 			log::info!(
 				"MaxBurnSet( netuid: {:?} max_burn: {:?} ) ",
@@ -487,7 +566,8 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::sudo_set_max_allowed_validators())]
 		pub fn sudo_set_max_allowed_validators(origin: OriginFor<T>, netuid: u16, max_allowed_validators: u16) -> DispatchResult
 		{
-			ensure_root(origin)?;
+			let is_root = ensure_root(origin.clone()).is_ok();
+			T::Basednode::ensure_brain_role_or_root(origin, netuid, BrainRole::HyperparamAdmin)?;
 			ensure!(
 				T::Basednode::if_brain_exist(netuid),
 				Error::<T>::NetworkDoesNotExist
@@ -496,8 +576,18 @@ pub mod pallet {
 				max_allowed_validators <= T::Basednode::get_max_allowed_uids(netuid),
 				Error::<T>::StorageValueOutOfRange
 			);
+			// Owners and admins can not leave the brain without validators.
+			ensure!(
+				is_root || max_allowed_validators > 0,
+				Error::<T>::StorageValueOutOfRange
+			);
 
 
+			if !is_root {
+				T::Basednode::schedule_hyperparam_change(netuid, HyperparamChange::MaxAllowedValidators(max_allowed_validators));
+				return Ok(());
+			}
+			T::Basednode::cancel_hyperparam_change(netuid, HyperparamChange::MaxAllowedValidators(max_allowed_validators));
 			T::Basednode::set_max_allowed_validators(netuid, max_allowed_validators);
 			log::info!(
 				"MaxAllowedValidatorsSet( netuid: {:?} max_allowed_validators: {:?} ) ",
@@ -585,11 +675,22 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::sudo_set_tempo())]
 		pub fn sudo_set_tempo(origin: OriginFor<T>, netuid: u16, tempo: u16) -> DispatchResult
 		{
-			ensure_root(origin)?;
+			let is_root = ensure_root(origin.clone()).is_ok();
+			T::Basednode::ensure_brain_role_or_root(origin, netuid, BrainRole::HyperparamAdmin)?;
 			ensure!(
 				T::Basednode::if_brain_exist(netuid),
 				Error::<T>::NetworkDoesNotExist
 			);
+			// Owners and admins keep the epoch between every 10 blocks and once a day of blocks.
+			ensure!(
+				is_root || (10..=7200).contains(&tempo),
+				Error::<T>::StorageValueOutOfRange
+			);
+			if !is_root {
+				T::Basednode::schedule_hyperparam_change(netuid, HyperparamChange::Tempo(tempo));
+				return Ok(());
+			}
+			T::Basednode::cancel_hyperparam_change(netuid, HyperparamChange::Tempo(tempo));
 			T::Basednode::set_tempo(netuid, tempo);
 			log::info!("TempoSet( netuid: {:?} tempo: {:?} ) ", netuid, tempo);
 			Ok(())
@@ -701,6 +802,325 @@ pub mod pallet {
 			T::Basednode::set_rao_recycled(netuid, rao_recycled);
 			Ok(())
 		}
+
+		#[pallet::call_index(40)]
+		#[pallet::weight((
+			Weight::from_ref_time(14_000_000)
+				.saturating_add(T::DbWeight::get().writes(1)),
+			DispatchClass::Operational,
+			Pays::No
+		))]
+		pub fn sudo_set_delegate_take_increase_delay(origin: OriginFor<T>, delay: u64) -> DispatchResult
+		{
+			ensure_root(origin)?;
+			T::Basednode::set_delegate_take_increase_delay(delay);
+			log::info!(
+				"DelegateTakeIncreaseDelaySet( delay: {:?} ) ",
+				delay
+			);
+			Ok(())
+		}
+
+		#[pallet::call_index(41)]
+		#[pallet::weight((
+			Weight::from_ref_time(14_000_000)
+				.saturating_add(T::DbWeight::get().writes(1)),
+			DispatchClass::Operational,
+			Pays::No
+		))]
+		pub fn sudo_set_unbonding_period(origin: OriginFor<T>, unbonding_period: u64) -> DispatchResult
+		{
+			ensure_root(origin)?;
+			T::Basednode::set_unbonding_period(unbonding_period);
+			log::info!(
+				"UnbondingPeriodSet( unbonding_period: {:?} ) ",
+				unbonding_period
+			);
+			Ok(())
+		}
+
+		#[pallet::call_index(42)]
+		#[pallet::weight((
+			Weight::from_ref_time(14_000_000)
+				.saturating_add(T::DbWeight::get().writes(1)),
+			DispatchClass::Operational,
+			Pays::No
+		))]
+		pub fn sudo_set_transaction_fee(origin: OriginFor<T>, call_type: pallet_basednode::CallType, fee: u128) -> DispatchResult
+		{
+			ensure_root(origin)?;
+			T::Basednode::set_transaction_fee(call_type, fee);
+			log::info!(
+				"TransactionFeeSet( call_type: {:?}, fee: {:?} ) ",
+				call_type,
+				fee
+			);
+			Ok(())
+		}
+
+		#[pallet::call_index(43)]
+		#[pallet::weight((
+			Weight::from_ref_time(14_000_000)
+				.saturating_add(T::DbWeight::get().writes(1)),
+			DispatchClass::Operational,
+			Pays::No
+		))]
+		pub fn sudo_set_emission_split(origin: OriginFor<T>, emission_split: u16) -> DispatchResult
+		{
+			ensure_root(origin)?;
+			// The split is the percentage of block emission distributed by root weights.
+			ensure!(
+				emission_split <= 100,
+				Error::<T>::StorageValueOutOfRange
+			);
+			T::Basednode::set_emission_split(emission_split);
+			log::info!(
+				"EmissionSplitSet( emission_split: {:?} ) ",
+				emission_split
+			);
+			Ok(())
+		}
+
+		#[pallet::call_index(44)]
+		#[pallet::weight((
+			Weight::from_ref_time(14_000_000)
+				.saturating_add(T::DbWeight::get().writes(1)),
+			DispatchClass::Operational,
+			Pays::No
+		))]
+		pub fn sudo_set_halving_interval(origin: OriginFor<T>, halving_interval: u64) -> DispatchResult
+		{
+			ensure_root(origin)?;
+			// Halvings can not be closer than a day of blocks.
+			ensure!(
+				halving_interval >= 7200,
+				Error::<T>::StorageValueOutOfRange
+			);
+			T::Basednode::set_halving_interval(halving_interval);
+			log::info!(
+				"HalvingIntervalSet( halving_interval: {:?} ) ",
+				halving_interval
+			);
+			Ok(())
+		}
+
+		#[pallet::call_index(45)]
+		#[pallet::weight((
+			Weight::from_ref_time(14_000_000)
+				.saturating_add(T::DbWeight::get().reads(1))
+				.saturating_add(T::DbWeight::get().writes(1)),
+			DispatchClass::Operational,
+			Pays::No
+		))]
+		pub fn sudo_set_owner_cut(origin: OriginFor<T>, netuid: u16, owner_cut: u16) -> DispatchResult
+		{
+			// Root is not bound by the limit it sets for brain owners.
+			let is_root = ensure_root(origin.clone()).is_ok();
+			T::Basednode::ensure_brain_owner_or_root(origin, netuid)?;
+
+			ensure!(
+				T::Basednode::if_brain_exist(netuid),
+				Error::<T>::NetworkDoesNotExist
+			);
+			if !is_root {
+				ensure!(
+					owner_cut <= T::Basednode::get_max_owner_cut(),
+					Error::<T>::StorageValueOutOfRange
+				);
+				T::Basednode::schedule_hyperparam_change(netuid, HyperparamChange::OwnerCut(owner_cut));
+				return Ok(());
+			}
+			T::Basednode::cancel_hyperparam_change(netuid, HyperparamChange::OwnerCut(owner_cut));
+			T::Basednode::set_owner_cut(netuid, owner_cut);
+			log::info!(
+				"OwnerCutSet( netuid: {:?} owner_cut: {:?} ) ",
+				netuid,
+				owner_cut
+			);
+			Ok(())
+		}
+
+		#[pallet::call_index(46)]
+		#[pallet::weight((
+			Weight::from_ref_time(14_000_000)
+				.saturating_add(T::DbWeight::get().reads(1))
+				.saturating_add(T::DbWeight::get().writes(1)),
+			DispatchClass::Operational,
+			Pays::No
+		))]
+		pub fn sudo_set_token_holder_threshold(origin: OriginFor<T>, netuid: u16, token_holder_threshold: u16) -> DispatchResult
+		{
+			// Root is not bound by the limit it sets for brain owners.
+			let is_root = ensure_root(origin.clone()).is_ok();
+			T::Basednode::ensure_brain_owner_or_root(origin, netuid)?;
+
+			ensure!(
+				T::Basednode::if_brain_exist(netuid),
+				Error::<T>::NetworkDoesNotExist
+			);
+			if !is_root {
+				ensure!(
+					token_holder_threshold <= T::Basednode::get_max_token_holder_threshold(),
+					Error::<T>::StorageValueOutOfRange
+				);
+				T::Basednode::schedule_hyperparam_change(netuid, HyperparamChange::TokenHolderThreshold(token_holder_threshold));
+				return Ok(());
+			}
+			T::Basednode::cancel_hyperparam_change(netuid, HyperparamChange::TokenHolderThreshold(token_holder_threshold));
+			T::Basednode::set_token_holder_threshold(netuid, token_holder_threshold);
+			log::info!(
+				"TokenHolderThresholdSet( netuid: {:?} token_holder_threshold: {:?} ) ",
+				netuid,
+				token_holder_threshold
+			);
+			Ok(())
+		}
+
+		#[pallet::call_index(47)]
+		#[pallet::weight((
+			Weight::from_ref_time(14_000_000)
+				.saturating_add(T::DbWeight::get().writes(1)),
+			DispatchClass::Operational,
+			Pays::No
+		))]
+		pub fn sudo_set_max_owner_cut(origin: OriginFor<T>, max_owner_cut: u16) -> DispatchResult
+		{
+			ensure_root(origin)?;
+			T::Basednode::set_max_owner_cut(max_owner_cut);
+			log::info!(
+				"MaxOwnerCutSet( max_owner_cut: {:?} ) ",
+				max_owner_cut
+			);
+			Ok(())
+		}
+
+		#[pallet::call_index(48)]
+		#[pallet::weight((
+			Weight::from_ref_time(14_000_000)
+				.saturating_add(T::DbWeight::get().writes(1)),
+			DispatchClass::Operational,
+			Pays::No
+		))]
+		pub fn sudo_set_max_token_holder_threshold(origin: OriginFor<T>, max_token_holder_threshold: u16) -> DispatchResult
+		{
+			ensure_root(origin)?;
+			T::Basednode::set_max_token_holder_threshold(max_token_holder_threshold);
+			log::info!(
+				"MaxTokenHolderThresholdSet( max_token_holder_threshold: {:?} ) ",
+				max_token_holder_threshold
+			);
+			Ok(())
+		}
+
+		#[pallet::call_index(49)]
+		#[pallet::weight((
+			Weight::from_ref_time(14_000_000)
+				.saturating_add(T::DbWeight::get().writes(1)),
+			DispatchClass::Operational,
+			Pays::No
+		))]
+		pub fn sudo_set_brain_ownership_proposal_expiry(origin: OriginFor<T>, expiry: u64) -> DispatchResult
+		{
+			ensure_root(origin)?;
+			T::Basednode::set_brain_ownership_proposal_expiry(expiry);
+			log::info!(
+				"BrainOwnershipProposalExpirySet( expiry: {:?} ) ",
+				expiry
+			);
+			Ok(())
+		}
+
+		#[pallet::call_index(50)]
+		#[pallet::weight((
+			Weight::from_ref_time(14_000_000)
+				.saturating_add(T::DbWeight::get().writes(1)),
+			DispatchClass::Operational,
+			Pays::No
+		))]
+		pub fn sudo_set_hyperparam_change_delay(origin: OriginFor<T>, delay: u64) -> DispatchResult
+		{
+			ensure_root(origin)?;
+			T::Basednode::set_hyperparam_change_delay(delay);
+			log::info!(
+				"HyperparamChangeDelaySet( delay: {:?} ) ",
+				delay
+			);
+			Ok(())
+		}
+
+		#[pallet::call_index(51)]
+		#[pallet::weight((
+			Weight::from_ref_time(14_000_000)
+				.saturating_add(T::DbWeight::get().writes(1)),
+			DispatchClass::Operational,
+			Pays::No
+		))]
+		pub fn sudo_set_brain_metadata_deposit(origin: OriginFor<T>, deposit: u128) -> DispatchResult
+		{
+			ensure_root(origin)?;
+			T::Basednode::set_brain_metadata_deposit(deposit);
+			log::info!(
+				"BrainMetadataDepositSet( deposit: {:?} ) ",
+				deposit
+			);
+			Ok(())
+		}
+
+		#[pallet::call_index(52)]
+		#[pallet::weight((
+			Weight::from_ref_time(14_000_000)
+				.saturating_add(T::DbWeight::get().writes(1))
+				.saturating_add(T::DbWeight::get().reads(1)),
+			DispatchClass::Operational,
+			Pays::No
+		))]
+		pub fn sudo_set_commit_reveal_weights_enabled(origin: OriginFor<T>, netuid: u16, enabled: bool) -> DispatchResult
+		{
+			let is_root = ensure_root(origin.clone()).is_ok();
+			T::Basednode::ensure_brain_role_or_root(origin, netuid, BrainRole::HyperparamAdmin)?;
+
+			ensure!(
+				T::Basednode::if_brain_exist(netuid),
+				Error::<T>::NetworkDoesNotExist
+			);
+			if !is_root {
+				T::Basednode::schedule_hyperparam_change(netuid, HyperparamChange::CommitRevealWeightsEnabled(enabled));
+				return Ok(());
+			}
+			T::Basednode::cancel_hyperparam_change(netuid, HyperparamChange::CommitRevealWeightsEnabled(enabled));
+			T::Basednode::set_commit_reveal_weights_enabled(netuid, enabled);
+			log::info!(
+				"CommitRevealWeightsEnabledSet( netuid: {:?} enabled: {:?} ) ",
+				netuid,
+				enabled
+			);
+			Ok(())
+		}
+
+		#[pallet::call_index(53)]
+		#[pallet::weight((
+			Weight::from_ref_time(14_000_000)
+				.saturating_add(T::DbWeight::get().writes(1))
+				.saturating_add(T::DbWeight::get().reads(1)),
+			DispatchClass::Operational,
+			Pays::No
+		))]
+		pub fn sudo_set_consensus_mechanism(origin: OriginFor<T>, netuid: u16, mechanism: ConsensusMechanismType) -> DispatchResult
+		{
+			ensure_root(origin)?;
+
+			ensure!(
+				T::Basednode::if_brain_exist(netuid),
+				Error::<T>::NetworkDoesNotExist
+			);
+			T::Basednode::set_consensus_mechanism(netuid, mechanism);
+			log::info!(
+				"ConsensusMechanismSet( netuid: {:?} mechanism: {:?} ) ",
+				netuid,
+				mechanism
+			);
+			Ok(())
+		}
     }
 }
 
@@ -725,6 +1145,25 @@ pub trait BasednodeInterface<AccountId, Balance, RuntimeOrigin>
 {
 	fn set_default_take(default_take: u16);
 	fn set_tx_rate_limit(rate_limit: u64);
+	fn set_delegate_take_increase_delay(delay: u64);
+	fn set_unbonding_period(unbonding_period: u64);
+	fn set_transaction_fee(call_type: pallet_basednode::CallType, fee: u128);
+	fn set_emission_split(emission_split: u16);
+	fn set_halving_interval(halving_interval: u64);
+	fn set_owner_cut(netuid: u16, owner_cut: u16);
+	fn get_max_owner_cut() -> u16;
+	fn set_max_owner_cut(max_owner_cut: u16);
+	fn set_token_holder_threshold(netuid: u16, token_holder_threshold: u16);
+	fn get_max_token_holder_threshold() -> u16;
+	fn set_max_token_holder_threshold(max_token_holder_threshold: u16);
+	fn set_brain_ownership_proposal_expiry(expiry: u64);
+
+	fn set_hyperparam_change_delay(delay: u64);
+
+	fn schedule_hyperparam_change(netuid: u16, change: HyperparamChange);
+	fn cancel_hyperparam_change(netuid: u16, change: HyperparamChange);
+
+	fn set_brain_metadata_deposit(deposit: u128);
 
 	fn set_serving_rate_limit(netuid: u16, rate_limit: u64);
 
@@ -748,7 +1187,7 @@ pub trait BasednodeInterface<AccountId, Balance, RuntimeOrigin>
 	fn if_brain_exist(netuid: u16) -> bool;
 	fn create_account_if_non_existent(personalkey: &AccountId, computekey: &AccountId);
 	fn personalkey_owns_computekey(personalkey: &AccountId, computekey: &AccountId) -> bool;
-	fn increase_stake_on_personalkey_computekey_account(personalkey: &AccountId, computekey: &AccountId, increment: u64);
+	fn increase_stake_on_personalkey_computekey_account(personalkey: &AccountId, computekey: &AccountId, increment: u128);
 	fn u64_to_balance(input: u64) -> Option<Balance>;
 	fn add_balance_to_personalkey_account(personalkey: &AccountId, amount: Balance);
 	fn get_current_block_as_u64() -> u64;
@@ -774,6 +1213,7 @@ pub trait BasednodeInterface<AccountId, Balance, RuntimeOrigin>
 	fn set_network_registration_allowed(netuid: u16, registration_allowed: bool);
 	fn set_activity_cutoff(netuid: u16, activity_cutoff: u16);
 	fn ensure_brain_owner_or_root(o: RuntimeOrigin, netuid: u16) -> Result<(), DispatchError>;
+	fn ensure_brain_role_or_root(o: RuntimeOrigin, netuid: u16, role: BrainRole) -> Result<(), DispatchError>;
 	fn set_rho(netuid: u16, rho: u16);
 	fn set_kappa(netuid: u16, kappa: u16);
 	fn set_max_allowed_uids(netuid: u16, max_allowed: u16);
@@ -784,5 +1224,7 @@ pub trait BasednodeInterface<AccountId, Balance, RuntimeOrigin>
 	fn set_validator_prune_len(netuid: u16, validator_prune_len: u64);
 	fn set_adjustment_interval(netuid: u16, adjustment_interval: u16);
 	fn set_weights_set_rate_limit(netuid: u16, weights_set_rate_limit: u64);
+	fn set_commit_reveal_weights_enabled(netuid: u16, enabled: bool);
+	fn set_consensus_mechanism(netuid: u16, mechanism: ConsensusMechanismType);
 	fn init_new_network(netuid: u16, tempo: u16);
 }
@@ -57,7 +57,7 @@ pub type Address = AccountId;
 
 // Balance of an account.
 #[allow(dead_code)]
-pub type Balance = u64;
+pub type Balance = u128;
 
 // An index to a block.
 #[allow(dead_code)]
@@ -84,13 +84,13 @@ parameter_types! {
     pub const InitialWeightsVersionKey: u16 = 0;
     pub const InitialServingRateLimit: u64 = 0; // No limit.
     pub const InitialTxRateLimit: u64 = 0; // Disable rate limit for testing
-    pub const InitialBurn: u64 = 0;
-    pub const InitialMinBurn: u64 = 0;
-    pub const InitialMaxBurn: u64 = 1_000_000_000;
+    pub const InitialBurn: u128 = 0;
+    pub const InitialMinBurn: u128 = 0;
+    pub const InitialMaxBurn: u128 = 1_000_000_000;
     pub const InitialValidatorPruneLen: u64 = 0;
     pub const InitialScalingLawPower: u16 = 50;
     pub const InitialMaxAllowedValidators: u16 = 100;
-    pub const InitialIssuance: u64 = 0;
+    pub const InitialIssuance: u128 = 0;
     pub const InitialDifficulty: u64 = 10000;
     pub const InitialActivityCutoff: u16 = 5000;
     pub const InitialAdjustmentInterval: u16 = 100;
@@ -101,27 +101,42 @@ parameter_types! {
     pub const InitialRegistrationRequirement: u16 = u16::MAX; // Top 100%
     pub const InitialMinDifficulty: u64 = 1;
     pub const InitialMaxDifficulty: u64 = u64::MAX;
-    pub const InitialRAORecycledForRegistration: u64 = 0;
+    pub const InitialRAORecycledForRegistration: u128 = 0;
     pub const InitialSenateRequiredStakePercentage: u64 = 2; // 2 percent of total stake
     pub const InitialNetworkImmunityPeriod: u64 = 7200 * 7;
     pub const InitialNetworkMinAllowedUids: u16 = 128;
-    pub const InitialNetworkMinLockCost: u64 = 100_000_000_000;
+    pub const InitialNetworkMinLockCost: u128 = 100_000_000_000;
     pub const InitialBrainOwnerCut: u16 = 0; // 0%. 100% of rewards go to validators + miners.
     pub const InitialNetworkLockReductionInterval: u64 = 2; // 2 blocks.
     pub const InitialBrainLimit: u16 = 10; // Max 10 brains.
     pub const InitialNetworkRateLimit: u64 = 0;
 	pub const InitialBrainOwnerByTokenBalanceCut: u128 = 10;
+    pub const InitialDelegateTakeIncreaseDelay: u64 = 10; // 10 blocks.
+    pub const InitialUnbondingPeriod: u64 = 0; // Release removed stake immediately.
+    pub const InitialEmissionSplit: u16 = 80; // 80% by root weights, 20% split equally.
+    pub const InitialHalvingInterval: u64 = 3_153_600; // A year of 10s blocks.
+    pub const InitialTokenHolderThreshold: u16 = 1_311; // 2% of the token supply.
+    pub const InitialBrainOwnershipProposalExpiry: u64 = 10; // 10 blocks.
+    pub const InitialHyperparamChangeDelay: u64 = 10; // 10 blocks.
+    pub const InitialBrainMetadataDeposit: u128 = 1_000;
+    pub const TransactionFeeDestination: pallet_basednode::TransactionFeeDestination<U256> =
+        pallet_basednode::TransactionFeeDestination::Burn;
 }
 
 impl pallet_basednode::Config for Test
 {
     type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
     type Currency = Balances;
     type InitialIssuance = InitialIssuance;
     type SudoRuntimeCall = TestRuntimeCall;
     type CouncilOrigin = EnsureNever<AccountId>;
     type SenateMembers = ();
     type TriumvirateInterface = ();
+    type FindAuthor = ();
+    type TokenTransferLogs = ();
+    type BrainTokenInspector = ();
+    type TransactionFeeDestination = TransactionFeeDestination;
 
     type InitialMinAllowedWeights = InitialMinAllowedWeights;
     type InitialEmissionValue = InitialEmissionValue;
@@ -161,6 +176,14 @@ impl pallet_basednode::Config for Test
     type InitialBrainLimit = InitialBrainLimit;
     type InitialNetworkRateLimit = InitialNetworkRateLimit;
 	type InitialBrainOwnerByTokenBalanceCut = InitialBrainOwnerByTokenBalanceCut;
+    type InitialDelegateTakeIncreaseDelay = InitialDelegateTakeIncreaseDelay;
+    type InitialUnbondingPeriod = InitialUnbondingPeriod;
+    type InitialEmissionSplit = InitialEmissionSplit;
+    type InitialHalvingInterval = InitialHalvingInterval;
+    type InitialTokenHolderThreshold = InitialTokenHolderThreshold;
+    type InitialBrainOwnershipProposalExpiry = InitialBrainOwnershipProposalExpiry;
+    type InitialHyperparamChangeDelay = InitialHyperparamChangeDelay;
+    type InitialBrainMetadataDeposit = InitialBrainMetadataDeposit;
 }
 
 impl system::Config for Test {
@@ -221,6 +244,86 @@ impl pallet_admin_utils::BasednodeInterface<AccountId, Balance, RuntimeOrigin> f
         BasedNode::set_tx_rate_limit(rate_limit);
     }
 
+	fn set_delegate_take_increase_delay(delay: u64)
+    {
+        BasedNode::set_delegate_take_increase_delay(delay);
+    }
+
+	fn set_unbonding_period(unbonding_period: u64)
+    {
+        BasedNode::set_unbonding_period(unbonding_period);
+    }
+
+	fn set_transaction_fee(call_type: pallet_basednode::CallType, fee: u128)
+    {
+        BasedNode::set_transaction_fee(call_type, fee);
+    }
+
+	fn set_emission_split(emission_split: u16)
+    {
+        BasedNode::set_emission_split(emission_split);
+    }
+
+	fn set_halving_interval(halving_interval: u64)
+    {
+        BasedNode::set_halving_interval(halving_interval);
+    }
+
+	fn set_owner_cut(netuid: u16, owner_cut: u16)
+    {
+        BasedNode::set_owner_cut(netuid, owner_cut);
+    }
+
+	fn get_max_owner_cut() -> u16
+    {
+        BasedNode::get_max_owner_cut()
+    }
+
+	fn set_max_owner_cut(max_owner_cut: u16)
+    {
+        BasedNode::set_max_owner_cut(max_owner_cut);
+    }
+
+	fn set_token_holder_threshold(netuid: u16, token_holder_threshold: u16)
+    {
+        BasedNode::set_token_holder_threshold(netuid, token_holder_threshold);
+    }
+
+	fn get_max_token_holder_threshold() -> u16
+    {
+        BasedNode::get_max_token_holder_threshold()
+    }
+
+	fn set_max_token_holder_threshold(max_token_holder_threshold: u16)
+    {
+        BasedNode::set_max_token_holder_threshold(max_token_holder_threshold);
+    }
+
+	fn set_brain_ownership_proposal_expiry(expiry: u64)
+    {
+        BasedNode::set_brain_ownership_proposal_expiry(expiry);
+    }
+
+	fn set_hyperparam_change_delay(delay: u64)
+    {
+        BasedNode::set_hyperparam_change_delay(delay);
+    }
+
+	fn schedule_hyperparam_change(netuid: u16, change: pallet_basednode::HyperparamChange)
+    {
+        BasedNode::schedule_hyperparam_change(netuid, change);
+    }
+
+	fn cancel_hyperparam_change(netuid: u16, change: pallet_basednode::HyperparamChange)
+    {
+        BasedNode::cancel_hyperparam_change(netuid, &change);
+    }
+
+	fn set_brain_metadata_deposit(deposit: u128)
+    {
+        BasedNode::set_brain_metadata_deposit(deposit);
+    }
+
 	fn set_serving_rate_limit(netuid: u16, rate_limit: u64)
     {
         BasedNode::set_serving_rate_limit(netuid, rate_limit);
@@ -296,7 +399,7 @@ impl pallet_admin_utils::BasednodeInterface<AccountId, Balance, RuntimeOrigin> f
         return BasedNode::personalkey_owns_computekey(personalkey, computekey);
     }
 
-	fn increase_stake_on_personalkey_computekey_account(personalkey: &AccountId, computekey: &AccountId, increment: u64)
+	fn increase_stake_on_personalkey_computekey_account(personalkey: &AccountId, computekey: &AccountId, increment: u128)
     {
         BasedNode::increase_stake_on_personalkey_computekey_account(personalkey, computekey, increment);
     }
@@ -416,6 +519,11 @@ impl pallet_admin_utils::BasednodeInterface<AccountId, Balance, RuntimeOrigin> f
         return BasedNode::ensure_brain_owner_or_root(o, netuid);
     }
 
+    fn ensure_brain_role_or_root(o: RuntimeOrigin, netuid: u16, role: pallet_basednode::BrainRole) -> Result<(), DispatchError>
+    {
+        return BasedNode::ensure_brain_role_or_root(o, netuid, role);
+    }
+
     fn set_rho(netuid: u16, rho: u16)
     {
         BasedNode::set_rho(netuid, rho);
@@ -466,6 +574,16 @@ impl pallet_admin_utils::BasednodeInterface<AccountId, Balance, RuntimeOrigin> f
         BasedNode::set_weights_set_rate_limit(netuid, weights_set_rate_limit);
     }
 
+    fn set_commit_reveal_weights_enabled(netuid: u16, enabled: bool)
+    {
+        BasedNode::set_commit_reveal_weights_enabled(netuid, enabled);
+    }
+
+    fn set_consensus_mechanism(netuid: u16, mechanism: pallet_basednode::ConsensusMechanismType)
+    {
+        BasedNode::set_consensus_mechanism(netuid, mechanism);
+    }
+
     fn set_rao_recycled(netuid: u16, rao_recycled: u64)
     {
         BasedNode::set_rao_recycled(netuid, rao_recycled);
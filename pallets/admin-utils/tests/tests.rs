@@ -37,6 +37,452 @@ fn test_sudo_set_default_take() {
     });
 }
 
+#[test]
+fn test_sudo_set_delegate_take_increase_delay() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u64 = 7200;
+        let init_value: u64 = BasedNode::get_delegate_take_increase_delay();
+        assert_eq!(
+            AdminUtils::sudo_set_delegate_take_increase_delay(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(0)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin.into())
+        );
+        assert_eq!(BasedNode::get_delegate_take_increase_delay(), init_value);
+        assert_ok!(AdminUtils::sudo_set_delegate_take_increase_delay(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(BasedNode::get_delegate_take_increase_delay(), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_unbonding_period() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u64 = 7200;
+        let init_value: u64 = BasedNode::get_unbonding_period();
+        assert_eq!(
+            AdminUtils::sudo_set_unbonding_period(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(0)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin.into())
+        );
+        assert_eq!(BasedNode::get_unbonding_period(), init_value);
+        assert_ok!(AdminUtils::sudo_set_unbonding_period(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(BasedNode::get_unbonding_period(), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_transaction_fee() {
+    new_test_ext().execute_with(|| {
+        let call_type = pallet_basednode::CallType::AddStake;
+        let to_be_set: u128 = 100_000;
+        let init_value: u128 = BasedNode::get_transaction_fee(&call_type);
+        assert_eq!(
+            AdminUtils::sudo_set_transaction_fee(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(0)),
+                call_type,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin.into())
+        );
+        assert_eq!(BasedNode::get_transaction_fee(&call_type), init_value);
+        assert_ok!(AdminUtils::sudo_set_transaction_fee(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            call_type,
+            to_be_set
+        ));
+        assert_eq!(BasedNode::get_transaction_fee(&call_type), to_be_set);
+        assert_eq!(
+            BasedNode::get_transaction_fee(&pallet_basednode::CallType::RemoveStake),
+            0
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_emission_split() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u16 = 60;
+        let init_value: u16 = BasedNode::get_emission_split();
+        assert_eq!(
+            AdminUtils::sudo_set_emission_split(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(0)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_emission_split(<<Test as Config>::RuntimeOrigin>::root(), 101),
+            Err(Error::<Test>::StorageValueOutOfRange.into())
+        );
+        assert_eq!(BasedNode::get_emission_split(), init_value);
+        assert_ok!(AdminUtils::sudo_set_emission_split(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(BasedNode::get_emission_split(), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_halving_interval() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u64 = 7200 * 365;
+        let init_value: u64 = BasedNode::get_halving_interval();
+        assert_eq!(
+            AdminUtils::sudo_set_halving_interval(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(0)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_halving_interval(<<Test as Config>::RuntimeOrigin>::root(), 7199),
+            Err(Error::<Test>::StorageValueOutOfRange.into())
+        );
+        assert_eq!(BasedNode::get_halving_interval(), init_value);
+        assert_ok!(AdminUtils::sudo_set_halving_interval(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(BasedNode::get_halving_interval(), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_owner_cut() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(77);
+        add_network(netuid, 10, 0);
+        pallet_basednode::BrainOwner::<Test>::insert(netuid, owner);
+        assert_ok!(AdminUtils::sudo_set_max_owner_cut(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            u16::MAX / 2
+        ));
+        assert_eq!(BasedNode::get_max_owner_cut(), u16::MAX / 2);
+        assert_eq!(
+            AdminUtils::sudo_set_max_owner_cut(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                u16::MAX
+            ),
+            Err(DispatchError::BadOrigin.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_owner_cut(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(0)),
+                netuid,
+                10
+            ),
+            Err(DispatchError::BadOrigin.into())
+        );
+        // Owners stay within the limit set by root.
+        assert_eq!(
+            AdminUtils::sudo_set_owner_cut(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                u16::MAX / 2 + 1
+            ),
+            Err(Error::<Test>::StorageValueOutOfRange.into())
+        );
+        // Owner changes are queued until the hyperparameter change delay has passed.
+        let init_value: u16 = BasedNode::get_owner_cut(netuid);
+        let effective_block: u64 = BasedNode::get_current_block_as_u64() + BasedNode::get_hyperparam_change_delay();
+        assert_ok!(AdminUtils::sudo_set_owner_cut(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            u16::MAX / 2
+        ));
+        assert_eq!(BasedNode::get_owner_cut(netuid), init_value);
+        assert_eq!(
+            BasedNode::get_pending_hyperparam_changes(netuid),
+            vec![(effective_block, pallet_basednode::HyperparamChange::OwnerCut(u16::MAX / 2))]
+        );
+        // A maximum lowered in the meantime still applies.
+        assert_ok!(AdminUtils::sudo_set_max_owner_cut(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            u16::MAX / 4
+        ));
+        BasedNode::apply_pending_hyperparam_changes(effective_block);
+        assert_eq!(BasedNode::get_owner_cut(netuid), u16::MAX / 4);
+
+        // Root applies its value now and drops the queued change.
+        assert_ok!(AdminUtils::sudo_set_owner_cut(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            10
+        ));
+        assert_ok!(AdminUtils::sudo_set_owner_cut(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            u16::MAX
+        ));
+        assert_eq!(BasedNode::get_owner_cut(netuid), u16::MAX);
+        assert!(BasedNode::get_pending_hyperparam_changes(netuid).is_empty());
+    });
+}
+
+#[test]
+fn test_sudo_set_token_holder_threshold() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(77);
+        add_network(netuid, 10, 0);
+        pallet_basednode::BrainOwner::<Test>::insert(netuid, owner);
+        assert_ok!(AdminUtils::sudo_set_max_token_holder_threshold(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            6_553
+        ));
+        assert_eq!(BasedNode::get_max_token_holder_threshold(), 6_553);
+        assert_eq!(
+            AdminUtils::sudo_set_token_holder_threshold(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(0)),
+                netuid,
+                10
+            ),
+            Err(DispatchError::BadOrigin.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_token_holder_threshold(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                10
+            ),
+            Err(Error::<Test>::NetworkDoesNotExist.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_token_holder_threshold(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                6_554
+            ),
+            Err(Error::<Test>::StorageValueOutOfRange.into())
+        );
+        let init_value: u16 = BasedNode::get_token_holder_threshold(netuid);
+        let effective_block: u64 = BasedNode::get_current_block_as_u64() + BasedNode::get_hyperparam_change_delay();
+        assert_ok!(AdminUtils::sudo_set_token_holder_threshold(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            6_553
+        ));
+        assert_eq!(BasedNode::get_token_holder_threshold(netuid), init_value);
+        assert_eq!(
+            BasedNode::get_pending_hyperparam_changes(netuid),
+            vec![(effective_block, pallet_basednode::HyperparamChange::TokenHolderThreshold(6_553))]
+        );
+        BasedNode::apply_pending_hyperparam_changes(effective_block);
+        assert_eq!(BasedNode::get_token_holder_threshold(netuid), 6_553);
+        assert_ok!(AdminUtils::sudo_set_token_holder_threshold(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            10
+        ));
+        assert_eq!(BasedNode::get_token_holder_threshold(netuid), 10);
+    });
+}
+
+#[test]
+fn test_sudo_set_brain_ownership_proposal_expiry() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u64 = 7200;
+        let init_value: u64 = BasedNode::get_brain_ownership_proposal_expiry();
+        assert_eq!(
+            AdminUtils::sudo_set_brain_ownership_proposal_expiry(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(0)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin.into())
+        );
+        assert_eq!(BasedNode::get_brain_ownership_proposal_expiry(), init_value);
+        assert_ok!(AdminUtils::sudo_set_brain_ownership_proposal_expiry(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(BasedNode::get_brain_ownership_proposal_expiry(), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_hyperparam_change_delay() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u64 = 7200;
+        let init_value: u64 = BasedNode::get_hyperparam_change_delay();
+        assert_eq!(
+            AdminUtils::sudo_set_hyperparam_change_delay(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(0)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin.into())
+        );
+        assert_eq!(BasedNode::get_hyperparam_change_delay(), init_value);
+        assert_ok!(AdminUtils::sudo_set_hyperparam_change_delay(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(BasedNode::get_hyperparam_change_delay(), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_brain_metadata_deposit() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u128 = 10_000;
+        let init_value: u128 = BasedNode::get_brain_metadata_deposit();
+        assert_eq!(
+            AdminUtils::sudo_set_brain_metadata_deposit(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(0)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin.into())
+        );
+        assert_eq!(BasedNode::get_brain_metadata_deposit(), init_value);
+        assert_ok!(AdminUtils::sudo_set_brain_metadata_deposit(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(BasedNode::get_brain_metadata_deposit(), to_be_set);
+    });
+}
+
+#[test]
+fn test_brain_roles_gate_owner_settings() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(77);
+        let (tuner, registrar, pauser) = (U256::from(2), U256::from(3), U256::from(4));
+        add_network(netuid, 10, 0);
+        pallet_basednode::BrainOwner::<Test>::insert(netuid, owner);
+        for (account, role) in [
+            (tuner, pallet_basednode::BrainRole::HyperparamAdmin),
+            (registrar, pallet_basednode::BrainRole::RegistrationAdmin),
+            (pauser, pallet_basednode::BrainRole::EmergencyPauser),
+        ] {
+            assert_ok!(BasedNode::grant_brain_role(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                account,
+                role
+            ));
+        }
+
+        // Hyperparameter admins tune the brain, other roles do not.
+        let effective_block: u64 = BasedNode::get_current_block_as_u64() + BasedNode::get_hyperparam_change_delay();
+        assert_ok!(AdminUtils::sudo_set_tempo(<<Test as Config>::RuntimeOrigin>::signed(tuner), netuid, 20));
+        assert_ok!(AdminUtils::sudo_set_max_allowed_validators(
+            <<Test as Config>::RuntimeOrigin>::signed(tuner),
+            netuid,
+            10
+        ));
+        assert_eq!(
+            AdminUtils::sudo_set_tempo(<<Test as Config>::RuntimeOrigin>::signed(registrar), netuid, 30),
+            Err(DispatchError::BadOrigin.into())
+        );
+        // Their values stay within the ranges owners can set.
+        for tempo in [0, 9, 7201] {
+            assert_eq!(
+                AdminUtils::sudo_set_tempo(<<Test as Config>::RuntimeOrigin>::signed(tuner), netuid, tempo),
+                Err(Error::<Test>::StorageValueOutOfRange.into())
+            );
+        }
+        assert_eq!(
+            AdminUtils::sudo_set_max_allowed_validators(<<Test as Config>::RuntimeOrigin>::signed(tuner), netuid, 0),
+            Err(Error::<Test>::StorageValueOutOfRange.into())
+        );
+
+        // Registration admins set the burn range, hyperparameter admins do not.
+        assert_ok!(AdminUtils::sudo_set_max_burn(<<Test as Config>::RuntimeOrigin>::signed(registrar), netuid, 1_000));
+        assert_eq!(
+            AdminUtils::sudo_set_min_burn(<<Test as Config>::RuntimeOrigin>::signed(tuner), netuid, 10),
+            Err(DispatchError::BadOrigin.into())
+        );
+
+        // Their changes are queued until the hyperparameter change delay has passed.
+        assert_eq!(BasedNode::get_tempo(netuid), 10);
+        assert_eq!(
+            BasedNode::get_pending_hyperparam_changes(netuid),
+            vec![
+                (effective_block, pallet_basednode::HyperparamChange::Tempo(20)),
+                (effective_block, pallet_basednode::HyperparamChange::MaxAllowedValidators(10)),
+                (effective_block, pallet_basednode::HyperparamChange::MaxBurn(1_000)),
+            ]
+        );
+        BasedNode::apply_pending_hyperparam_changes(effective_block);
+        assert_eq!(BasedNode::get_tempo(netuid), 20);
+        assert_eq!(BasedNode::get_max_allowed_validators(netuid), 10);
+        assert_eq!(BasedNode::get_max_burn_as_u64(netuid), 1_000);
+        assert!(BasedNode::get_pending_hyperparam_changes(netuid).is_empty());
+
+        // Pausers only close registrations.
+        assert_ok!(AdminUtils::sudo_set_network_registration_allowed(
+            <<Test as Config>::RuntimeOrigin>::signed(pauser),
+            netuid,
+            false
+        ));
+        assert!(!BasedNode::get_network_registration_allowed(netuid));
+        assert_eq!(
+            AdminUtils::sudo_set_network_registration_allowed(
+                <<Test as Config>::RuntimeOrigin>::signed(pauser),
+                netuid,
+                true
+            ),
+            Err(DispatchError::BadOrigin.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_network_registration_allowed(
+            <<Test as Config>::RuntimeOrigin>::signed(registrar),
+            netuid,
+            true
+        ));
+        assert!(BasedNode::get_network_registration_allowed(netuid));
+
+        // Revoked roles stop working.
+        assert_ok!(BasedNode::revoke_brain_role(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            tuner,
+            pallet_basednode::BrainRole::HyperparamAdmin
+        ));
+        assert_eq!(
+            AdminUtils::sudo_set_tempo(<<Test as Config>::RuntimeOrigin>::signed(tuner), netuid, 30),
+            Err(DispatchError::BadOrigin.into())
+        );
+    });
+}
+
+#[test]
+fn test_root_hyperparam_change_cancels_queued_change() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(77);
+        add_network(netuid, 10, 0);
+        pallet_basednode::BrainOwner::<Test>::insert(netuid, owner);
+
+        let effective_block: u64 = BasedNode::get_current_block_as_u64() + BasedNode::get_hyperparam_change_delay();
+        assert_ok!(AdminUtils::sudo_set_tempo(<<Test as Config>::RuntimeOrigin>::signed(owner), netuid, 20));
+        assert_ok!(AdminUtils::sudo_set_immunity_period(<<Test as Config>::RuntimeOrigin>::signed(owner), netuid, 7));
+
+        // Root applies its value now and drops the queued change of the same hyperparameter only.
+        assert_ok!(AdminUtils::sudo_set_tempo(<<Test as Config>::RuntimeOrigin>::root(), netuid, 30));
+        assert_eq!(BasedNode::get_tempo(netuid), 30);
+        assert_eq!(
+            BasedNode::get_pending_hyperparam_changes(netuid),
+            vec![(effective_block, pallet_basednode::HyperparamChange::ImmunityPeriod(7))]
+        );
+
+        BasedNode::apply_pending_hyperparam_changes(effective_block);
+        assert_eq!(BasedNode::get_tempo(netuid), 30);
+        assert_eq!(BasedNode::get_immunity_period(netuid), 7);
+        assert!(BasedNode::get_pending_hyperparam_changes(netuid).is_empty());
+    });
+}
+
 #[test]
 fn test_sudo_set_serving_rate_limit() {
     new_test_ext().execute_with(|| {
@@ -199,6 +645,79 @@ fn test_sudo_set_weights_set_rate_limit() {
     });
 }
 
+#[test]
+fn test_sudo_set_commit_reveal_weights_enabled() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10, 0);
+        assert!(!BasedNode::get_commit_reveal_weights_enabled(netuid));
+        assert_eq!(
+            AdminUtils::sudo_set_commit_reveal_weights_enabled(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                true
+            ),
+            Err(DispatchError::BadOrigin.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_commit_reveal_weights_enabled(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                true
+            ),
+            Err(Error::<Test>::NetworkDoesNotExist.into())
+        );
+        assert!(!BasedNode::get_commit_reveal_weights_enabled(netuid));
+        assert_ok!(AdminUtils::sudo_set_commit_reveal_weights_enabled(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            true
+        ));
+        assert!(BasedNode::get_commit_reveal_weights_enabled(netuid));
+    });
+}
+
+#[test]
+fn test_sudo_set_consensus_mechanism() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        add_network(netuid, 10, 0);
+        pallet_basednode::BrainOwner::<Test>::insert(netuid, owner);
+        assert_eq!(
+            BasedNode::get_consensus_mechanism(netuid),
+            pallet_basednode::ConsensusMechanismType::Yuma
+        );
+        // Not even the brain owner can change the mechanism.
+        assert_eq!(
+            AdminUtils::sudo_set_consensus_mechanism(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                pallet_basednode::ConsensusMechanismType::StakeWeightedAverage
+            ),
+            Err(DispatchError::BadOrigin.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_consensus_mechanism(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                pallet_basednode::ConsensusMechanismType::StakeWeightedAverage
+            ),
+            Err(Error::<Test>::NetworkDoesNotExist.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_consensus_mechanism(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            pallet_basednode::ConsensusMechanismType::StakeWeightedAverage
+        ));
+        assert_eq!(
+            BasedNode::get_consensus_mechanism(netuid),
+            pallet_basednode::ConsensusMechanismType::StakeWeightedAverage
+        );
+        assert!(BasedNode::get_pending_hyperparam_changes(netuid).is_empty());
+    });
+}
+
 #[test]
 fn test_sudo_set_adjustment_interval() {
     new_test_ext().execute_with(|| {
@@ -12,10 +12,13 @@ use std::sync::Arc;
 use sp_api::ProvideRuntimeApi;
 use sp_api::HeaderT;
 use sp_api::Encode;
+use codec::Decode;
+use pallet_basednode::epoch_info::EpochDryRun;
 
 pub use basednode_custom_rpc_runtime_api::{
     DelegateInfoRuntimeApi, AgentInfoRuntimeApi, BrainInfoRuntimeApi,
-    BrainRegistrationRuntimeApi, TftEnforcerDataRuntimeApi
+    BrainRegistrationRuntimeApi, TftEnforcerDataRuntimeApi, EmissionLedgerRuntimeApi,
+    EpochDryRunRuntimeApi
 };
 
 #[rpc(client, server)]
@@ -50,12 +53,20 @@ pub trait BasednodeCustomApi<BlockHash> {
     fn get_brains_info(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
     #[method(name = "brainInfo_getBrainHyperparams")]
     fn get_brain_hyperparams(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "brainInfo_getBrainMetadata")]
+    fn get_brain_metadata(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
 
     #[method(name = "brainInfo_getLockCost")]
     fn get_network_lock_cost(&self, at: Option<BlockHash>) -> RpcResult<u128>;
 
     #[method(name = "tftEnforcer_getTftEnforcerData")]
     fn get_tft_enforcer_data(&self, from_block: Option<BlockHash>, block_count: Option<u64>, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+
+    #[method(name = "emissionLedger_getEmissionLedger")]
+    fn get_emission_ledger(&self, from_block: u64, to_block: u64, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+
+    #[method(name = "epochInfo_epochDryRun")]
+    fn epoch_dry_run(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Option<EpochDryRun>>;
 }
 
 pub struct BasednodeCustom<C, P> {
@@ -97,6 +108,8 @@ where
     C::Api: BrainInfoRuntimeApi<Block>,
     C::Api: BrainRegistrationRuntimeApi<Block>,
     C::Api: TftEnforcerDataRuntimeApi<Block>,
+    C::Api: EmissionLedgerRuntimeApi<Block>,
+    C::Api: EpochDryRunRuntimeApi<Block>,
 {
     fn get_delegates(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
         let api = self.client.runtime_api();
@@ -254,6 +267,24 @@ where
         })
     }
 
+    fn get_brain_metadata(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_brain_metadata(at, netuid).map_err(|e| {
+            CallError::Custom(ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to get brain metadata.",
+                Some(e.to_string()),
+            ))
+            .into()
+        })
+    }
+
     fn get_brains_info(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
         let api = self.client.runtime_api();
         let at = at.unwrap_or_else(|| self.client.info().best_hash);
@@ -295,4 +326,51 @@ where
         })
 
     }
+
+    fn get_emission_ledger(
+        &self,
+        from_block: u64,
+        to_block: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_emission_ledger(at, from_block, to_block).map_err(|e| {
+            CallError::Custom(ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to get emission ledger.",
+                Some(e.to_string()),
+            ))
+            .into()
+        })
+    }
+
+    fn epoch_dry_run(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<EpochDryRun>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let encoded: Vec<u8> = api.epoch_dry_run(at, netuid).map_err(|e| {
+            CallError::Custom(ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to run the epoch.",
+                Some(e.to_string()),
+            ))
+        })?;
+        if encoded.is_empty() {
+            return Ok(None);
+        }
+        EpochDryRun::decode(&mut &encoded[..]).map(Some).map_err(|e| {
+            CallError::Custom(ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to decode the epoch dry run.",
+                Some(e.to_string()),
+            ))
+            .into()
+        })
+    }
 }
@@ -4,7 +4,7 @@ use alloc::vec::Vec;
 
 // Here we declare the runtime API. It is implemented it the `impl` block in
 // src/tft_enforcer_data.rs, src/agent_info.rs, src/brain_info.rs,
-// and src/delegate_info.rs
+// src/emission_ledger.rs, src/hyperparam_changes.rs, src/epoch_info.rs and src/delegate_info.rs
 sp_api::decl_runtime_apis! {
     pub trait DelegateInfoRuntimeApi {
         fn get_delegates() -> Vec<u8>;
@@ -23,6 +23,7 @@ sp_api::decl_runtime_apis! {
         fn get_brain_info(netuid: u16) -> Vec<u8>;
         fn get_brains_info() -> Vec<u8>;
         fn get_brain_hyperparams(netuid: u16) -> Vec<u8>;
+        fn get_brain_metadata(netuid: u16) -> Vec<u8>;
     }
 
     pub trait StakeInfoRuntimeApi {
@@ -37,4 +38,23 @@ sp_api::decl_runtime_apis! {
     pub trait TftEnforcerDataRuntimeApi {
         fn get_tft_enforcer_data(from_block: Vec<u8>, block_count: Option<u64>) -> Vec<u8>;
     }
+
+    pub trait EmissionLedgerRuntimeApi {
+        fn get_emission_ledger(from_block: u64, to_block: u64) -> Vec<u8>;
+    }
+
+    pub trait EmissionProjectionRuntimeApi {
+        // Returns ( total, weighted by root, equal per brain floor ) emission at the block.
+        fn project_block_emission(block_number: u64) -> (u128, u128, u128);
+    }
+
+    pub trait HyperparamChangesRuntimeApi {
+        // Returns the SCALE encoded Vec<( effective block, HyperparamChange )> queued on the brain.
+        fn get_pending_hyperparam_changes(netuid: u16) -> Vec<u8>;
+    }
+
+    pub trait EpochDryRunRuntimeApi {
+        // Returns the SCALE encoded EpochDryRun of the brain, empty if the brain does not exist.
+        fn epoch_dry_run(netuid: u16) -> Vec<u8>;
+    }
 }
@@ -14,9 +14,9 @@ pub struct AgentInfo<T: Config> {
     active: bool,
     brainport_info: BrainportInfo,
     prometheus_info: PrometheusInfo,
-    stake: Vec<(T::AccountId, Compact<u64>)>, // map of personalkey to stake on this agent/computekey (includes delegations)
+    stake: Vec<(T::AccountId, Compact<u128>)>, // map of personalkey to stake on this agent/computekey (includes delegations)
     rank: Compact<u16>,
-    emission: Compact<u64>,
+    emission: Compact<u128>,
     incentive: Compact<u16>,
     consensus: Compact<u16>,
     trust: Compact<u16>,
@@ -38,9 +38,9 @@ pub struct AgentInfoLite<T: Config> {
     active: bool,
     brainport_info: BrainportInfo,
     prometheus_info: PrometheusInfo,
-    stake: Vec<(T::AccountId, Compact<u64>)>, // map of personalkey to stake on this agent/computekey (includes delegations)
+    stake: Vec<(T::AccountId, Compact<u128>)>, // map of personalkey to stake on this agent/computekey (includes delegations)
     rank: Compact<u16>,
-    emission: Compact<u64>,
+    emission: Compact<u128>,
     incentive: Compact<u16>,
     consensus: Compact<u16>,
     trust: Compact<u16>,
@@ -115,7 +115,7 @@ impl<T: Config> Pallet<T> {
             .filter_map(|(i, b)| if *b > 0 { Some((i.into(), b.into())) } else { None })
             .collect::<Vec<(Compact<u16>, Compact<u16>)>>();
 
-        let stake: Vec<(T::AccountId, Compact<u64>)> = < Stake<T> as IterableStorageDoubleMap<T::AccountId, T::AccountId, u64> >::iter_prefix( computekey.clone() )
+        let stake: Vec<(T::AccountId, Compact<u128>)> = < Stake<T> as IterableStorageDoubleMap<T::AccountId, T::AccountId, u128> >::iter_prefix( computekey.clone() )
             .map(|(personalkey, stake)| (personalkey, stake.into()))
             .collect();
 
@@ -183,7 +183,7 @@ impl<T: Config> Pallet<T> {
         let last_update = Self::get_last_update_for_uid( netuid, uid as u16 );
         let validator_permit = Self::get_validator_permit_for_uid( netuid, uid as u16 );
 
-        let stake: Vec<(T::AccountId, Compact<u64>)> = < Stake<T> as IterableStorageDoubleMap<T::AccountId, T::AccountId, u64> >::iter_prefix( computekey.clone() )
+        let stake: Vec<(T::AccountId, Compact<u128>)> = < Stake<T> as IterableStorageDoubleMap<T::AccountId, T::AccountId, u128> >::iter_prefix( computekey.clone() )
             .map(|(personalkey, stake)| (personalkey, stake.into()))
             .collect();
 
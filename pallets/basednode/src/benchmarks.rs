@@ -121,7 +121,7 @@ benchmarks! {
 	let personalkey: T::AccountId = account("Test", 0, seed);
 	let computekey: T::AccountId = account("Alice", 0, seed);
 
-	let amount: u64 = 1;
+	let amount: u128 = 1;
 	let amoun_to_be_staked = Basednode::<T>::u64_to_balance( 1000000000);
 	Basednode::<T>::add_balance_to_personalkey_account(&personalkey.clone(), amoun_to_be_staked.unwrap());
 
@@ -157,13 +157,13 @@ benchmarks! {
 	assert_ok!(Basednode::<T>::do_become_delegate(RawOrigin::Signed(personalkey.clone()).into(), computekey.clone(), Basednode::<T>::get_default_take()));
 
 	  // Stake 10% of our current total staked BASED
-	  let u64_staked_amt = 100_000_000_000;
-	let amount_to_be_staked = Basednode::<T>::u64_to_balance(u64_staked_amt);
+	  let u128_staked_amt: u128 = 100_000_000_000;
+	let amount_to_be_staked = Basednode::<T>::u128_to_balance(u128_staked_amt);
 	Basednode::<T>::add_balance_to_personalkey_account(&personalkey.clone(), amount_to_be_staked.unwrap());
 
-	assert_ok!( Basednode::<T>::add_stake(RawOrigin::Signed( personalkey.clone() ).into() , computekey.clone(), u64_staked_amt));
+	assert_ok!( Basednode::<T>::add_stake(RawOrigin::Signed( personalkey.clone() ).into() , computekey.clone(), u128_staked_amt));
 
-	let amount_unstaked: u64 = u64_staked_amt - 1;
+	let amount_unstaked: u128 = u128_staked_amt - 1;
   }: remove_stake(RawOrigin::Signed( personalkey.clone() ), computekey.clone(), amount_unstaked)
 
   benchmark_serve_brainport{
@@ -312,4 +312,55 @@ benchmarks! {
 		assert_ok!(Basednode::<T>::add_stake(RawOrigin::Signed(personalkey).into(), old_computekey.clone(), 1_000_000_000));
 	}
   }: _(RawOrigin::Signed(personalkey), old_computekey, new_computekey)
+
+  benchmark_epoch {
+	// Brain size the epoch weight is fitted over. Every permitted validator sets weights on every agent and
+	// holds bonds on all of them, which is the most work epoch does for a brain of n agents.
+	let n in 1 .. 4096;
+	let netuid: u16 = 1;
+	let tempo: u16 = 1;
+
+	Basednode::<T>::init_new_network(netuid, tempo);
+	Basednode::<T>::set_max_allowed_uids( netuid, 4096 );
+	let validators: u16 = (n as u16).min( Basednode::<T>::get_max_allowed_validators( netuid ) );
+
+	for i in 0..n {
+		let computekey: T::AccountId = account("Computekey", 0, i);
+		Basednode::<T>::append_agent(netuid, &computekey, 0);
+		Basednode::<T>::increase_stake_on_computekey_account(&computekey, 1_000_000_000);
+	}
+	let row: Vec<(u16, u16)> = (0..n as u16).map(|uid| (uid, u16::MAX)).collect();
+	for uid in 0..validators {
+		Basednode::<T>::set_validator_permit_for_uid(netuid, uid, true);
+		Basednode::<T>::set_last_update_for_uid(netuid, uid, 1);
+		Weights::<T>::insert(netuid, uid, row.clone());
+		Bonds::<T>::insert(netuid, uid, row.clone());
+	}
+  }: {
+	Basednode::<T>::epoch(netuid, 1_000_000_000);
+  }
+
+  benchmark_root_epoch {
+	// Number of root validators and of brains they set weights on. Every root validator is staked and
+	// weights every brain, which is the most work root_epoch does for n validators and k brains.
+	let n in 1 .. 64;
+	let k in 1 .. 32;
+	let root_netuid: u16 = Basednode::<T>::get_root_netuid();
+	let tempo: u16 = 1;
+
+	Basednode::<T>::init_new_network(root_netuid, tempo);
+	Basednode::<T>::set_max_allowed_uids( root_netuid, 64 );
+	for netuid in 1..=k as u16 {
+		Basednode::<T>::init_new_network(netuid, tempo);
+	}
+	let row: Vec<(u16, u16)> = (1..=k as u16).map(|netuid| (netuid, u16::MAX)).collect();
+	for i in 0..n {
+		let computekey: T::AccountId = account("Computekey", 0, i);
+		Basednode::<T>::append_agent(root_netuid, &computekey, 0);
+		Basednode::<T>::increase_stake_on_computekey_account(&computekey, 1_000_000_000);
+		Weights::<T>::insert(root_netuid, i as u16, row.clone());
+	}
+  }: {
+	let _ = Basednode::<T>::root_epoch(0);
+  }
 }
@@ -1,22 +1,29 @@
 use super::*;
+use crate::default_weights::WeightInfo;
 use frame_support::inherent::Vec;
 use frame_support::storage::IterableStorageDoubleMap;
 use frame_support::storage::IterableStorageMap;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
 use substrate_fixed::types::I110F18;
-use substrate_fixed::types::I64F64;
 use substrate_fixed::types::I96F32;
 
-// 1M of total tokens with 10^18 precision,
-const BRAIN_ERC20_TOTAL_SUPPLY: i128 = 1_000_000_000_000_000_000_000_000;
-
 impl<T: Config> Pallet<T> {
-    /// Executes the necessary operations for each block.
-    pub fn block_step() -> Result<(), &'static str> {
+    /// Executes the necessary operations for each block. Returns the weight of the stages that ran.
+    pub fn block_step() -> Result<Weight, &'static str> {
         let block_number: u64 = Self::get_current_block_as_u64();
         log::debug!("block_step for block: {:?} ", block_number);
+        let mut weight: Weight = T::DbWeight::get().reads(1);
+        // --- 0. Sync the brain token balances with the EVM Transfer logs of the previous block.
+        weight.saturating_accrue(Self::sync_brain_token_transfers());
         // --- 1. Adjust difficulties.
-        Self::adjust_registration_terms_for_networks();
+        weight.saturating_accrue(Self::adjust_registration_terms_for_networks());
+        // --- 1a. Apply delegate take increases whose delay has passed.
+        weight.saturating_accrue(Self::apply_pending_delegate_takes(block_number));
+        // --- 1b. Apply the brain hyperparameter changes whose effective block has come.
+        weight.saturating_accrue(Self::apply_pending_hyperparam_changes(block_number));
         // --- 2. Calculate per-brain emissions
+        weight.saturating_accrue(Self::root_epoch_weight(block_number));
         match Self::root_epoch(block_number) {
             Ok(_) => (),
             Err(e) => {
@@ -24,11 +31,32 @@ impl<T: Config> Pallet<T> {
             }
         }
         // --- 3. Drains emission tuples ( computekey, amount ).
-        Self::drain_emission(block_number);
+        weight.saturating_accrue(Self::drain_emission(block_number));
         // --- 4. Generates emission tuples from epoch functions.
-        Self::generate_emission(block_number);
-        // Return ok.
-        Ok(())
+        weight.saturating_accrue(Self::generate_emission(block_number));
+        // Return ok with the weight used.
+        Ok(weight)
+    }
+
+    // Returns the weight of running the epoch of a brain with n agents. The compute part is not benchmarked
+    // yet and is the fixed block step weight, see default_weights.rs.
+    //
+    pub fn epoch_weight(n: u16) -> Weight {
+        T::WeightInfo::benchmark_epoch(n as u32)
+    }
+
+    // Returns the weight of the root epoch if it runs this block, over the number of root validators and
+    // brains. The compute part is not benchmarked yet and is the fixed block step weight, see default_weights.rs.
+    //
+    pub fn root_epoch_weight(block_number: u64) -> Weight {
+        let root_netuid: u16 = Self::get_root_netuid();
+        let weight: Weight = T::DbWeight::get().reads(2);
+        if Self::blocks_until_next_epoch(root_netuid, Self::get_tempo(root_netuid), block_number) != 0 {
+            return weight;
+        }
+        let n: u32 = Self::get_num_root_validators() as u32;
+        let k: u32 = Self::get_all_brain_netuids().len() as u32;
+        weight.saturating_add(T::WeightInfo::benchmark_root_epoch(n, k))
     }
 
     // Helper function which returns the number of blocks remaining before we will run the epoch on this
@@ -79,50 +107,110 @@ impl<T: Config> Pallet<T> {
     }
 
     pub fn has_loaded_emission_tuples(netuid: u16) -> bool {
-        LoadedEmission::<T>::contains_key(netuid)
+        LoadedEmissionLength::<T>::contains_key(netuid)
+    }
+    // Returns the loaded emission tuples which have not been drained yet.
+    pub fn get_loaded_emission_tuples(netuid: u16) -> Vec<(T::AccountId, u128, u128)> {
+        let length: u32 = LoadedEmissionLength::<T>::get(netuid).unwrap_or(0);
+        let cursor: u32 = LoadedEmissionCursor::<T>::get(netuid).min(length);
+        (cursor..length)
+            .filter_map(|position| LoadedEmissionTuples::<T>::get(netuid, position))
+            .collect()
     }
-    pub fn get_loaded_emission_tuples(netuid: u16) -> Vec<(T::AccountId, u64, u64)> {
-        LoadedEmission::<T>::get(netuid).unwrap()
+
+    // Appends the emission tuples behind the ones still loaded on the network.
+    //
+    pub fn load_emission_tuples(netuid: u16, tuples: Vec<(T::AccountId, u128, u128)>) -> Weight {
+        let mut length: u32 = LoadedEmissionLength::<T>::get(netuid).unwrap_or(0);
+        let weight: Weight = T::DbWeight::get().reads_writes(1, tuples.len() as u64 + 1);
+        for tuple in tuples {
+            LoadedEmissionTuples::<T>::insert(netuid, length, tuple);
+            length = length.saturating_add(1);
+        }
+        LoadedEmissionLength::<T>::insert(netuid, length);
+        weight
     }
 
-    // Reads from the loaded emission storage which contains lists of pending emission tuples ( computekey, amount )
-    // and distributes small chunks of them at a time.
+    // Reads from the loaded emission storage which contains the pending emission tuples ( computekey, amount )
+    // and distributes small chunks of them at a time. The chunk size is given by tuples_to_drain_this_block and
+    // a cursor keeps track of the next tuple to drain. Tuples are stored by position, so only the drained ones
+    // are read. Returns the weight used.
     //
-    pub fn drain_emission(_: u64) {
+    pub fn drain_emission(block_number: u64) -> Weight {
+        let mut weight: Weight = Weight::from_ref_time(0);
+        let mut ledger: Vec<EmissionLedgerEntry> = Vec::new();
         // --- 1. We iterate across each network.
-        for (netuid, _) in <Tempo<T> as IterableStorageMap<u16, u16>>::iter() {
-            log::trace!("drain_emission netuid: {:?}, LoadedEmissions::<T>: {:?}", netuid, LoadedEmission::<T>::get(netuid));
-            if !Self::has_loaded_emission_tuples(netuid) {
-                continue;
-            } // There are no tuples to emit.
-            let tuples_to_drain: Vec<(T::AccountId, u64, u64)> =
-                Self::get_loaded_emission_tuples(netuid);
-            log::trace!("drain_emission tuples_to_drain: {:?}", tuples_to_drain);
-            let mut total_emitted: u64 = 0;
-            for (computekey, server_amount, validator_amount) in tuples_to_drain.iter() {
-                Self::emit_inflation_through_computekey_account(
+        for (netuid, tempo) in <Tempo<T> as IterableStorageMap<u16, u16>>::iter() {
+            weight.saturating_accrue(T::DbWeight::get().reads(2));
+            let length: u32 = match LoadedEmissionLength::<T>::get(netuid) {
+                Some(length) => length,
+                None => continue, // There are no tuples to emit.
+            };
+
+            // --- 2. Work out the positions of the tuples to drain this block.
+            weight.saturating_accrue(T::DbWeight::get().reads(1));
+            let cursor: u32 = LoadedEmissionCursor::<T>::get(netuid).min(length);
+            let n_remaining: usize = (length - cursor) as usize;
+            let n_to_drain: u32 =
+                Self::tuples_to_drain_this_block(netuid, tempo, block_number, n_remaining)
+                    .min(n_remaining) as u32;
+            log::trace!("drain_emission netuid: {:?}, cursor: {:?}, n_to_drain: {:?}", netuid, cursor, n_to_drain);
+
+            // --- 3. Emit the tuples.
+            let mut total_emitted: u128 = 0;
+            for position in cursor..cursor + n_to_drain {
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+                let (computekey, server_amount, validator_amount) =
+                    match LoadedEmissionTuples::<T>::take(netuid, position) {
+                        Some(tuple) => tuple,
+                        None => continue,
+                    };
+                weight.saturating_accrue(Self::emit_inflation_through_computekey_account(
                     &computekey,
-                    *server_amount,
-                    *validator_amount,
-                );
-                total_emitted += *server_amount + *validator_amount;
+                    server_amount,
+                    validator_amount,
+                ));
+                total_emitted += server_amount + validator_amount;
+            }
+            TotalIssuance::<T>::put(TotalIssuance::<T>::get().saturating_add(total_emitted));
+            if total_emitted > 0 {
+                Self::note_emission(&mut ledger, netuid, |entry| entry.drained = total_emitted);
+            }
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+
+            // --- 4. Move the cursor, or clear the tuples once all of them are drained.
+            if cursor + n_to_drain >= length {
+                LoadedEmissionLength::<T>::remove(netuid);
+                LoadedEmissionCursor::<T>::remove(netuid);
+            } else {
+                LoadedEmissionCursor::<T>::insert(netuid, cursor + n_to_drain);
             }
-            LoadedEmission::<T>::remove(netuid);
-            TotalIssuance::<T>::put(TotalIssuance::<T>::get().saturating_add(total_emitted.into()));
+            weight.saturating_accrue(T::DbWeight::get().writes(2));
         }
+        weight.saturating_accrue(Self::store_emission_ledger(block_number, ledger));
+        weight
     }
 
     // Iterates through networks queues more emission onto their pending storage.
     // If a network has no blocks left until tempo, we run the epoch function and generate
     // more token emission tuples for later draining onto accounts.
     //
-    pub fn generate_emission(block_number: u64) {
+    pub fn generate_emission(block_number: u64) -> Weight {
+        let mut weight: Weight = Weight::from_ref_time(0);
+        let mut ledger: Vec<EmissionLedgerEntry> = Vec::new();
+        Self::prune_emission_ledger(block_number);
+        weight.saturating_accrue(T::DbWeight::get().writes(1));
         // --- 1. Iterate across each network and add pending emission into stash.
         for (netuid, tempo) in <Tempo<T> as IterableStorageMap<u16, u16>>::iter() {
+            weight.saturating_accrue(T::DbWeight::get().reads(2));
             // FIXME should root emissions be burned?
             // Skip the root network.
             if netuid == Self::get_root_netuid() {
                 // Root emission is burned.
+                let burned: u128 = Self::get_brain_emission_value(netuid);
+                if burned > 0 {
+                    Self::note_emission(&mut ledger, netuid, |entry| entry.burned = burned);
+                }
                 continue;
             }
 
@@ -135,6 +223,7 @@ impl<T: Config> Pallet<T> {
                 new_queued_emission,
             );
 
+            weight.saturating_accrue(T::DbWeight::get().reads(2));
             let brain_has_owner = BrainOwner::<T>::contains_key(netuid);
             let mut remaining = I96F32::from_num(new_queued_emission);
             if brain_has_owner {
@@ -145,49 +234,26 @@ impl<T: Config> Pallet<T> {
                 if contract_activated {
                     let contract_address = BrainContract::<T>::get(netuid);
                     log::debug!("contract is activated for brain = {:?}, addres = {:?}", netuid, contract_address);
-                    let mut total_cut = I96F32::from(0);
-                    let mut cuts = vec![];
-
-                    // (emissions) iter over all 2nd keys (token balance owner) of first key (which is brain owner),
-                    // value / total tokens is percentage of the cut, returns list of (AccountId,
-                    // value)
-                    for (token_owner, balance) in
-                        BrainOwnerByTokenBalanceCut::<T>::iter_prefix(contract_address)
-                    {
-                        if balance < (BRAIN_ERC20_TOTAL_SUPPLY / 100 * 2) as u128 {
-                            continue;
-                        }
-                        let balance_ratio = I96F32::from_num(balance)
-                            .saturating_div(I96F32::from_num(BRAIN_ERC20_TOTAL_SUPPLY));
-                        log::debug!("token_owner = {:?}, balance = {:?}, balance_ratio = {:?}", token_owner, balance, balance_ratio);
-
-                        let cut = remaining
-                            .saturating_mul(I96F32::from_num(Self::get_brain_owner_cut()))
-                            .saturating_div(I96F32::from_num(u16::MAX))
-                            .saturating_mul(balance_ratio);
-                        total_cut = total_cut.saturating_add(cut);
-                        remaining.saturating_sub(cut);
-                        cuts.push((token_owner, cut));
-                    }
 
-                    log::debug!("distributing cuts: {:?}", cuts);
-
-                    // TODO(emissions) see mem usage in test and possibly move to upper loop and do
-                    // not allocate cuts Vec
-                    for (owner, cut) in cuts {
-                        Self::add_balance_to_personalkey_account(
-                            &owner,
-                            Self::u64_to_balance(cut.to_num::<u64>()).unwrap(),
-                        );
-                    }
-
-                    TotalIssuance::<T>::put(
-                        TotalIssuance::<T>::get().saturating_add(total_cut.to_num::<u128>()),
-                    );
+                    // The cut the holders would share if the whole supply was above the threshold.
+                    // It is added to the contract dividend index, holders claim their part of it
+                    // with claim_brain_dividends, so the cost here does not grow with the holders.
+                    let holders_cut: u128 = remaining
+                        .saturating_mul(I96F32::from_num(Self::get_owner_cut(netuid)))
+                        .saturating_div(I96F32::from_num(u16::MAX))
+                        .to_num::<u128>();
+                    let total_cut: u128 = Self::accrue_brain_dividends(&contract_address, holders_cut);
+                    weight.saturating_accrue(T::DbWeight::get().reads_writes(3, 1));
+                    remaining = remaining.saturating_sub(I96F32::from_num(total_cut));
+                    log::debug!("holders_cut = {:?}, total_cut = {:?}, remaining = {:?}", holders_cut, total_cut, remaining);
+
+                    Self::note_emission(&mut ledger, netuid, |entry| {
+                        entry.holder_cut = total_cut
+                    });
                 } else {
                     log::trace!("generate_emissions, contract_activated = false, remaining = {:?}", remaining);
                     let cut = remaining
-                        .saturating_mul(I96F32::from_num(Self::get_brain_owner_cut()))
+                        .saturating_mul(I96F32::from_num(Self::get_owner_cut(netuid)))
                         .saturating_div(I96F32::from_num(u16::MAX));
 
                     remaining = remaining.saturating_sub(cut);
@@ -196,16 +262,23 @@ impl<T: Config> Pallet<T> {
                     // iter over all tuples, add to PK and subtract from the remaining pull
                     Self::add_balance_to_personalkey_account(
                         &Self::get_brain_owner(netuid),
-                        Self::u64_to_balance(cut.to_num::<u64>()).unwrap(),
+                        Self::u128_to_balance(cut.to_num::<u128>()).unwrap(),
                     );
                     // TODO(emissions) sum of all cuts
                     TotalIssuance::<T>::put(
                         TotalIssuance::<T>::get().saturating_add(cut.to_num::<u128>()),
                     );
+                    Self::note_emission(&mut ledger, netuid, |entry| {
+                        entry.owner_cut = cut.to_num::<u128>()
+                    });
                 }
             }
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(3, 2));
+            Self::note_emission(&mut ledger, netuid, |entry| {
+                entry.queued = new_queued_emission
+            });
             // --- 5. Add remaining amount to the network's pending emission.
-            PendingEmission::<T>::mutate(netuid, |queued| *queued += remaining.to_num::<u64>());
+            PendingEmission::<T>::mutate(netuid, |queued| *queued = queued.saturating_add(remaining.to_num::<u128>()));
             log::debug!(
                 "netuid_i: {:?} queued_emission: +{:?} , PendingEmission::<T> = {:?}",
                 netuid,
@@ -225,11 +298,12 @@ impl<T: Config> Pallet<T> {
 
             // --- 7 This network is at tempo and we are running its epoch.
             // First drain the queued emission.
-            let emission_to_drain: u64 = PendingEmission::<T>::get(netuid);
+            let emission_to_drain: u128 = PendingEmission::<T>::get(netuid);
             PendingEmission::<T>::insert(netuid, 0);
 
-            // --- 8. Run the epoch mechanism and return emission tuples for computekeys in the network.
-            let emission_tuples_this_block: Vec<(T::AccountId, u64, u64)> =
+            // --- 8. Run the epoch with the consensus mechanism of the brain and return emission tuples for computekeys in the network.
+            weight.saturating_accrue(Self::epoch_weight(Self::get_brain_n(netuid)));
+            let emission_tuples_this_block: Vec<(T::AccountId, u128, u128)> =
                 Self::epoch(netuid, emission_to_drain);
             log::debug!(
                 "netuid_i: {:?} emission_to_drain: {:?} emission_tuples_this_block: {:?}",
@@ -241,76 +315,81 @@ impl<T: Config> Pallet<T> {
             // --- 9. Check that the emission does not exceed the allowed total.
             let emission_sum: u128 = emission_tuples_this_block
                 .iter()
-                .map(|(_account_id, ve, se)| *ve as u128 + *se as u128)
+                .map(|(_account_id, ve, se)| *ve + *se)
                 .sum();
-            if emission_sum > emission_to_drain as u128 {
+            if emission_sum > emission_to_drain {
                 continue;
             } // Saftey check.
 
-            // --- 10. Sink the emission tuples onto the already loaded.
-            let mut concat_emission_tuples: Vec<(T::AccountId, u64, u64)> =
-                emission_tuples_this_block.clone();
-            if Self::has_loaded_emission_tuples(netuid) {
-                // 10.a We already have loaded emission tuples, so we concat the new ones.
-                let mut current_emission_tuples: Vec<(T::AccountId, u64, u64)> =
-                    Self::get_loaded_emission_tuples(netuid);
-                concat_emission_tuples.append(&mut current_emission_tuples);
-            }
-            LoadedEmission::<T>::insert(netuid, concat_emission_tuples);
+            // --- 10. Sink the emission tuples behind the ones not drained yet.
+            weight.saturating_accrue(Self::load_emission_tuples(netuid, emission_tuples_this_block));
 
             // --- 11 Set counters.
             Self::set_blocks_since_last_step(netuid, 0);
             Self::set_last_mechanism_step_block(netuid, block_number);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 5));
+
+            // --- 12. Drop the weight commits that can no longer be revealed.
+            weight.saturating_accrue(Self::remove_expired_weight_commits(netuid, block_number));
         }
+        weight.saturating_accrue(Self::store_emission_ledger(block_number, ledger));
+        weight
     }
     // Distributes token inflation through the computekey based on emission. The call ensures that the inflation
     // is distributed onto the accounts in proportion of the stake delegated minus the take. This function
-    // is called after an epoch to distribute the newly minted stake according to delegation.
+    // is called after an epoch to distribute the newly minted stake according to delegation. Returns the weight used.
     //
     pub fn emit_inflation_through_computekey_account(
         computekey: &T::AccountId,
-        server_emission: u64,
-        validator_emission: u64,
-    ) {
+        server_emission: u128,
+        validator_emission: u128,
+    ) -> Weight {
         log::debug!("emit_inflation_through_computekey_account ck: {:?}, server_emission: {:?}, validator_emission: {:?}",
             computekey, server_emission, validator_emission
             );
         // --- 1. Check if the computekey is a delegate. If not, we simply pass the stake through to the
         // personalkey - computekey account as normal.
+        let mut weight: Weight = T::DbWeight::get().reads(2);
+        let owning_personalkey: T::AccountId = Self::get_owning_personalkey_for_computekey(computekey);
         if !Self::computekey_is_delegate(computekey) {
-            Self::increase_stake_on_computekey_account(
+            Self::emit_reward_through_personalkey_computekey_account(
+                &owning_personalkey,
                 &computekey,
                 server_emission + validator_emission,
             );
-            return;
+            weight.saturating_accrue(Self::emit_reward_weight());
+            return weight;
         }
         // Then this is a delegate, we distribute validator_emission, then server_emission.
 
         // --- 2. The computekey is a delegate. We first distribute a proportion of the validator_emission to the computekey
         // directly as a function of its 'take'
-        let total_computekey_stake: u64 = Self::get_total_stake_for_computekey(computekey);
-        let delegate_take: u64 =
+        let total_computekey_stake: u128 = Self::get_total_stake_for_computekey(computekey);
+        let delegate_take: u128 =
             Self::calculate_delegate_proportional_take(computekey, validator_emission);
-        let validator_emission_minus_take: u64 = validator_emission - delegate_take;
-        let mut remaining_validator_emission: u64 = validator_emission_minus_take;
+        weight.saturating_accrue(T::DbWeight::get().reads(2));
+        let validator_emission_minus_take: u128 = validator_emission - delegate_take;
+        let mut remaining_validator_emission: u128 = validator_emission_minus_take;
 
         // 3. -- The remaining emission goes to the owners in proportion to the stake delegated.
         for (owning_personalkey_i, stake_i) in
-            <Stake<T> as IterableStorageDoubleMap<T::AccountId, T::AccountId, u64>>::iter_prefix(
+            <Stake<T> as IterableStorageDoubleMap<T::AccountId, T::AccountId, u128>>::iter_prefix(
                 computekey,
             )
         {
             // --- 4. The emission proportion is remaining_emission * ( stake / total_stake ).
-            let stake_proportion: u64 = Self::calculate_stake_proportional_emission(
+            let stake_proportion: u128 = Self::calculate_stake_proportional_emission(
                 stake_i,
                 total_computekey_stake,
                 validator_emission_minus_take,
             );
-            Self::increase_stake_on_personalkey_computekey_account(
+            Self::emit_reward_through_personalkey_computekey_account(
                 &owning_personalkey_i,
                 &computekey,
                 stake_proportion,
             );
+            weight.saturating_accrue(T::DbWeight::get().reads(1));
+            weight.saturating_accrue(Self::emit_reward_weight());
             log::debug!(
                 "owning_personalkey_i: {:?} computekey: {:?} emission: +{:?} ",
                 owning_personalkey_i,
@@ -322,7 +401,8 @@ impl<T: Config> Pallet<T> {
 
         // --- 5. Last increase final account balance of delegate after 4, since 5 will change the stake proportion of
         // the delegate and effect calculation in 4.
-        Self::increase_stake_on_computekey_account(
+        Self::emit_reward_through_personalkey_computekey_account(
+            &owning_personalkey,
             &computekey,
             delegate_take + remaining_validator_emission,
         );
@@ -334,7 +414,47 @@ impl<T: Config> Pallet<T> {
         // Also emit the server_emission to the computekey
         // The server emission is distributed in-full to the delegate owner.
         // We do this after 4. for the same reason as above.
-        Self::increase_stake_on_computekey_account(&computekey, server_emission);
+        Self::emit_reward_through_personalkey_computekey_account(
+            &owning_personalkey,
+            &computekey,
+            server_emission,
+        );
+        weight.saturating_accrue(Self::emit_reward_weight().saturating_mul(2));
+        weight
+    }
+
+    // Returns the worst case weight of paying one reward through emit_reward_through_personalkey_computekey_account.
+    //
+    pub fn emit_reward_weight() -> Weight {
+        T::DbWeight::get().reads_writes(5, 5)
+    }
+
+    // Pays emission earned by the cold - hot position to its reward destination. Rewards paid out
+    // to a free balance still count towards the total issuance, like compounded stake does. A reward
+    // that can not be minted to the payee, e.g. below the existential deposit of a new account, is
+    // compounded on the position instead.
+    //
+    pub fn emit_reward_through_personalkey_computekey_account(
+        personalkey: &T::AccountId,
+        computekey: &T::AccountId,
+        amount: u128,
+    ) {
+        let payee: T::AccountId = match Self::get_reward_destination(personalkey, computekey) {
+            RewardDestination::Stake => {
+                Self::increase_stake_on_personalkey_computekey_account(personalkey, computekey, amount);
+                return;
+            }
+            RewardDestination::Balance => personalkey.clone(),
+            RewardDestination::Account(account) => account,
+        };
+        if let Some(amount_as_balance) = Self::u128_to_balance(amount) {
+            if Self::try_add_balance_to_personalkey_account(&payee, amount_as_balance) {
+                TotalIssuance::<T>::put(TotalIssuance::<T>::get().saturating_add(amount));
+                return;
+            }
+        }
+        // Can not be paid out, keep it on the position instead.
+        Self::increase_stake_on_personalkey_computekey_account(personalkey, computekey, amount);
     }
 
     // Increases the stake on the cold - hot pairing by increment while also incrementing other counters.
@@ -343,7 +463,7 @@ impl<T: Config> Pallet<T> {
     pub fn block_step_increase_stake_on_personalkey_computekey_account(
         personalkey: &T::AccountId,
         computekey: &T::AccountId,
-        increment: u64,
+        increment: u128,
     ) {
         TotalPersonalkeyStake::<T>::mutate(personalkey, |old| old.saturating_add(increment));
         TotalComputekeyStake::<T>::insert(
@@ -356,7 +476,7 @@ impl<T: Config> Pallet<T> {
             Stake::<T>::get(computekey, personalkey).saturating_add(increment),
         );
         TotalStake::<T>::put(TotalStake::<T>::get().saturating_add(increment));
-        TotalIssuance::<T>::put(TotalIssuance::<T>::get().saturating_add(increment.into()));
+        TotalIssuance::<T>::put(TotalIssuance::<T>::get().saturating_add(increment));
     }
 
     // Decreases the stake on the cold - hot pairing by the decrement while decreasing other counters.
@@ -364,7 +484,7 @@ impl<T: Config> Pallet<T> {
     pub fn block_step_decrease_stake_on_personalkey_computekey_account(
         personalkey: &T::AccountId,
         computekey: &T::AccountId,
-        decrement: u64,
+        decrement: u128,
     ) {
         TotalPersonalkeyStake::<T>::mutate(personalkey, |old| old.saturating_sub(decrement));
         TotalComputekeyStake::<T>::insert(
@@ -377,32 +497,32 @@ impl<T: Config> Pallet<T> {
             Stake::<T>::get(computekey, personalkey).saturating_sub(decrement),
         );
         TotalStake::<T>::put(TotalStake::<T>::get().saturating_sub(decrement));
-        TotalIssuance::<T>::put(TotalIssuance::<T>::get().saturating_sub(decrement.into()));
+        TotalIssuance::<T>::put(TotalIssuance::<T>::get().saturating_sub(decrement));
     }
 
     // Returns emission awarded to a computekey as a function of its proportion of the total stake.
     //
     pub fn calculate_stake_proportional_emission(
-        stake: u64,
-        total_stake: u64,
-        emission: u64,
-    ) -> u64 {
+        stake: u128,
+        total_stake: u128,
+        emission: u128,
+    ) -> u128 {
         if total_stake == 0 {
             return 0;
         };
-        let stake_proportion: I64F64 = I64F64::from_num(stake) / I64F64::from_num(total_stake);
-        let proportional_emission: I64F64 = I64F64::from_num(emission) * stake_proportion;
-        return proportional_emission.to_num::<u64>();
+        let stake_proportion: I96F32 = I96F32::from_num(stake) / I96F32::from_num(total_stake);
+        let proportional_emission: I96F32 = I96F32::from_num(emission) * stake_proportion;
+        return proportional_emission.to_num::<u128>();
     }
 
     // Returns the delegated stake 'take' assigned to this key. (If exists, otherwise 0)
     //
-    pub fn calculate_delegate_proportional_take(computekey: &T::AccountId, emission: u64) -> u64 {
+    pub fn calculate_delegate_proportional_take(computekey: &T::AccountId, emission: u128) -> u128 {
         if Self::computekey_is_delegate(computekey) {
-            let take_proportion: I64F64 =
-                I64F64::from_num(Delegates::<T>::get(computekey)) / I64F64::from_num(u16::MAX);
-            let take_emission: I64F64 = take_proportion * I64F64::from_num(emission);
-            return take_emission.to_num::<u64>();
+            let take_proportion: I96F32 =
+                I96F32::from_num(Self::get_delegate_take(computekey)) / I96F32::from_num(u16::MAX);
+            let take_emission: I96F32 = take_proportion * I96F32::from_num(emission);
+            return take_emission.to_num::<u128>();
         } else {
             return 0;
         }
@@ -410,11 +530,13 @@ impl<T: Config> Pallet<T> {
 
     // Adjusts the network difficulties/burns of every active network. Resetting state parameters.
     //
-    pub fn adjust_registration_terms_for_networks() {
+    pub fn adjust_registration_terms_for_networks() -> Weight {
         log::debug!("adjust_registration_terms_for_networks");
+        let mut weight: Weight = Weight::from_ref_time(0);
 
         // --- 1. Iterate through each network.
         for (netuid, _) in <NetworksAdded<T> as IterableStorageMap<u16, bool>>::iter() {
+            weight.saturating_accrue(T::DbWeight::get().reads(3));
             // --- 2. Pull counters for network difficulty.
             let last_adjustment_block: u64 = Self::get_last_adjustment_block(netuid);
             let adjustment_interval: u16 = Self::get_adjustment_interval(netuid);
@@ -430,6 +552,7 @@ impl<T: Config> Pallet<T> {
             // If so, we need to adjust the registration difficulty based on target and actual registrations.
             if (current_block - last_adjustment_block) >= adjustment_interval as u64 {
                 log::debug!("interval reached.");
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(11, 6));
 
                 // --- 4. Get the current counters for this network w.r.t burn and difficulty values.
                 let current_burn: u128 = Self::get_burn_as_u64(netuid);
@@ -560,7 +683,9 @@ impl<T: Config> Pallet<T> {
 
             // --- 7. Drain block registrations for each network. Needed for registration rate limits.
             Self::set_registrations_this_block(netuid, 0);
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
         }
+        weight
     }
 
     // Performs the difficulty adjustment by multiplying the current difficulty by the ratio ( reg_actual + reg_target / reg_target * reg_target )
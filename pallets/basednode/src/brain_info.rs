@@ -22,7 +22,7 @@ pub struct BrainInfo<T: Config> {
     tempo: Compact<u16>,
     network_modality: Compact<u16>,
     network_connect: Vec<[u16; 2]>,
-    emission_values: Compact<u64>,
+    emission_values: Compact<u128>,
     burn: Compact<u128>,
     owner: T::AccountId,
 }
@@ -48,7 +48,19 @@ pub struct BrainHyperparams {
     bonds_moving_avg: Compact<u64>,
     max_regs_per_block: Compact<u16>,
     serving_rate_limit: Compact<u64>,
-    max_validators: Compact<u16>
+    max_validators: Compact<u16>,
+    owner_cut: Compact<u16>,
+    token_holder_threshold: Compact<u16>,
+    commit_reveal_weights_enabled: bool,
+    consensus_mechanism: crate::ConsensusMechanismType,
+}
+
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct BrainMetadataInfo<T: Config> {
+    netuid: Compact<u16>,
+    metadata: BrainMetadata,
+    depositor: T::AccountId,
+    deposit: Compact<u128>,
 }
 
 impl<T: Config> Pallet<T> {
@@ -149,6 +161,10 @@ impl<T: Config> Pallet<T> {
         let max_regs_per_block = Self::get_max_registrations_per_block(netuid);
         let serving_rate_limit = Self::get_serving_rate_limit(netuid);
         let max_validators = Self::get_max_allowed_validators(netuid);
+        let owner_cut = Self::get_owner_cut(netuid);
+        let token_holder_threshold = Self::get_token_holder_threshold(netuid);
+        let commit_reveal_weights_enabled = Self::get_commit_reveal_weights_enabled(netuid);
+        let consensus_mechanism = Self::get_consensus_mechanism(netuid);
 
         return Some(BrainHyperparams {
             rho: rho.into(),
@@ -170,7 +186,22 @@ impl<T: Config> Pallet<T> {
             bonds_moving_avg: bonds_moving_avg.into(),
             max_regs_per_block: max_regs_per_block.into(),
             serving_rate_limit: serving_rate_limit.into(),
-            max_validators: max_validators.into()
+            max_validators: max_validators.into(),
+            owner_cut: owner_cut.into(),
+            token_holder_threshold: token_holder_threshold.into(),
+            commit_reveal_weights_enabled,
+            consensus_mechanism,
+        });
+    }
+
+    pub fn get_brain_metadata(netuid: u16) -> Option<BrainMetadataInfo<T>> {
+        let (depositor, deposit, metadata) = BrainMetadataOf::<T>::get(netuid)?;
+
+        return Some(BrainMetadataInfo {
+            netuid: netuid.into(),
+            metadata,
+            depositor,
+            deposit: deposit.into(),
         });
     }
 }
@@ -0,0 +1,117 @@
+use super::*;
+use crate::epoch::{EpochInputs, EpochOutput};
+use crate::math::*;
+use frame_support::sp_std::vec;
+use frame_support::inherent::Vec;
+use substrate_fixed::types::I32F32;
+
+// Turns the weights and stake of a brain into ranks, incentive, dividends and emission. Each brain runs the
+// mechanism its consensus mechanism hyperparameter selects, epoch then writes the output to storage.
+pub trait ConsensusMechanism<T: Config> {
+    // Runs the mechanism on the current storage of the brain without writing anything.
+    fn compute_epoch(netuid: u16, rao_emission: u128) -> EpochOutput<T>;
+}
+
+// Yuma consensus, the default: stake-weighted median clipping of weights and EMA bonds.
+pub struct Yuma;
+
+impl<T: Config> ConsensusMechanism<T> for Yuma {
+    fn compute_epoch(netuid: u16, rao_emission: u128) -> EpochOutput<T> {
+        Pallet::<T>::compute_yuma_epoch(netuid, rao_emission)
+    }
+}
+
+// Ranks agents by the stake-weighted average of the weights of validators, with no clipping and no bonds.
+// Suits brains where agents are scored directly, such as ranking competitions.
+pub struct StakeWeightedAverage;
+
+impl<T: Config> ConsensusMechanism<T> for StakeWeightedAverage {
+    fn compute_epoch(netuid: u16, rao_emission: u128) -> EpochOutput<T> {
+        Pallet::<T>::compute_stake_weighted_average_epoch(netuid, rao_emission)
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    // Runs the consensus mechanism selected for the brain without writing anything.
+    //
+    pub fn compute_consensus( netuid: u16, rao_emission: u128 ) -> EpochOutput<T> {
+        match Self::get_consensus_mechanism( netuid ) {
+            ConsensusMechanismType::Yuma => <Yuma as ConsensusMechanism<T>>::compute_epoch( netuid, rao_emission ),
+            ConsensusMechanismType::StakeWeightedAverage => {
+                <StakeWeightedAverage as ConsensusMechanism<T>>::compute_epoch( netuid, rao_emission )
+            }
+        }
+    }
+
+    // Computes the epoch of a brain with stake-weighted average consensus: ranks are the weights of active
+    // permitted validators averaged by stake, and validators earn dividends in proportion to their active stake
+    // once they set weights. Bonds are not used, permitted validators are left with empty bonds.
+    //
+    pub fn compute_stake_weighted_average_epoch( netuid: u16, rao_emission: u128 ) -> EpochOutput<T> {
+        let EpochInputs {
+            n,
+            computekeys,
+            active,
+            stake,
+            active_stake,
+            validator_permits,
+            new_validator_permits,
+            weights,
+            ..
+        } = Self::compute_epoch_inputs( netuid );
+
+        // =============================
+        // == Ranks, Trust, Incentive ==
+        // =============================
+
+        // Stake-weighted average weight: r_j = SUM(i) w_ij * s_i.
+        let mut ranks: Vec<I32F32> = matmul_sparse( &weights, &active_stake, n );
+        let consensus: Vec<I32F32> = ranks.clone();
+        log::trace!( "C: {:?}", &consensus );
+
+        // Nothing is clipped, so every ranked agent keeps all of its rank.
+        let trust: Vec<I32F32> = ranks.iter().map(| rank | if *rank > I32F32::from_num(0) { I32F32::from_num(1) } else { I32F32::from_num(0) } ).collect();
+
+        inplace_normalize( &mut ranks );
+        let incentive: Vec<I32F32> = ranks.clone();
+        log::trace!( "I (=R): {:?}", &incentive );
+
+        // ===============
+        // == Dividends ==
+        // ===============
+
+        // Validators that set weights share dividends by active stake: d_i = s_i * SUM(j) w_ij.
+        let validator_trust: Vec<I32F32> = row_sum_sparse( &weights );
+        let mut dividends: Vec<I32F32> = active_stake.iter().zip( validator_trust.iter() ).map( |(si, ti)| *si * *ti ).collect();
+        inplace_normalize( &mut dividends );
+        log::trace!( "D: {:?}", &dividends );
+
+        // =================================
+        // == Emission and Pruning scores ==
+        // =================================
+
+        let ( pruning_scores, combined_emission, server_emission, validator_emission ) =
+            Self::compute_emission( &stake, &active_stake, &incentive, &dividends, rao_emission );
+        log::trace!( "P: {:?}", &pruning_scores );
+
+        EpochOutput {
+            computekeys,
+            stake,
+            active,
+            weights,
+            consensus,
+            validator_trust,
+            ranks,
+            trust,
+            incentive,
+            dividends,
+            bonds: vec![ vec![]; n as usize ],
+            pruning_scores,
+            validator_permits,
+            new_validator_permits,
+            combined_emission,
+            server_emission,
+            validator_emission,
+        }
+    }
+}
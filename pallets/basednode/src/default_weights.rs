@@ -0,0 +1,65 @@
+//! Weights for the block step stages of `pallet_basednode`.
+//!
+//! These figures are NOT benchmark output. `benchmark_epoch` and `benchmark_root_epoch` have not been run
+//! on reference hardware yet, so until they are, both epochs are charged the fixed weight the whole block
+//! step was charged before it was weighed per stage, plus the storage they access counted from the code.
+//! Replace this file with the output of the benchmark CLI once it has been run:
+//!
+//! ./target/release/basednode benchmark pallet --chain=local --execution=wasm --wasm-execution=compiled
+//!     --pallet=pallet_basednode --extrinsic=benchmark_epoch,benchmark_root_epoch --steps 50 --repeat 20
+//!     --output=pallets/basednode/src/default_weights.rs --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_basednode`.
+pub trait WeightInfo {
+	fn benchmark_epoch(n: u32, ) -> Weight;
+	fn benchmark_root_epoch(n: u32, k: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_basednode`, not measured, see the module documentation.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// The range of component `n` is `[1, 4096]`.
+	fn benchmark_epoch(n: u32, ) -> Weight {
+		Weight::from_ref_time(110_634_229_000)
+			.saturating_add(T::DbWeight::get().reads(112_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(110_u64))
+	}
+	/// The range of component `n` is `[1, 64]`.
+	/// The range of component `k` is `[1, 32]`.
+	fn benchmark_root_epoch(n: u32, k: u32, ) -> Weight {
+		Weight::from_ref_time(110_634_229_000)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(k.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(k.into())))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// The range of component `n` is `[1, 4096]`.
+	fn benchmark_epoch(n: u32, ) -> Weight {
+		Weight::from_ref_time(110_634_229_000)
+			.saturating_add(RocksDbWeight::get().reads(112_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(110_u64))
+	}
+	/// The range of component `n` is `[1, 64]`.
+	/// The range of component `k` is `[1, 32]`.
+	fn benchmark_root_epoch(n: u32, k: u32, ) -> Weight {
+		Weight::from_ref_time(110_634_229_000)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(k.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(k.into())))
+	}
+}
@@ -1,5 +1,5 @@
 use super::*;
-use substrate_fixed::types::{U64F64};
+use substrate_fixed::types::U96F32;
 use frame_support::IterableStorageDoubleMap;
 use frame_support::storage::IterableStorageMap;
 use frame_support::pallet_prelude::{Decode, Encode};
@@ -13,19 +13,20 @@ use codec::Compact;
 pub struct DelegateInfo<T: Config> {
     delegate_ss58: T::AccountId,
     take: Compact<u16>,
-    nominators: Vec<(T::AccountId, Compact<u64>)>, // map of nominator_ss58 to stake amount
+    pending_take: Option<(Compact<u16>, Compact<u64>)>, // Announced take increase and the block it applies at
+    nominators: Vec<(T::AccountId, Compact<u128>)>, // map of nominator_ss58 to stake amount
     owner_ss58: T::AccountId,
     registrations: Vec<Compact<u16>>, // Vec of netuid this delegate is registered on
     validator_permits: Vec<Compact<u16>>, // Vec of netuid this delegate has validator permit on
-    return_per_1000: Compact<u64>, // Delegators current daily return per 1000 BASED staked minus take fee
-    total_daily_return: Compact<u64>, // Delegators current daily return
+    return_per_1000: Compact<u128>, // Delegators current daily return per 1000 BASED staked minus take fee
+    total_daily_return: Compact<u128>, // Delegators current daily return
 }
 
 impl<T: Config> Pallet<T> {
     fn get_delegate_by_existing_account( delegate: AccountIdOf<T> ) -> DelegateInfo<T> {
-        let mut nominators = Vec::<(T::AccountId, Compact<u64>)>::new();
+        let mut nominators = Vec::<(T::AccountId, Compact<u128>)>::new();
 
-        for ( nominator, stake ) in < Stake<T> as IterableStorageDoubleMap<T::AccountId, T::AccountId, u64> >::iter_prefix( delegate.clone() ) {
+        for ( nominator, stake ) in < Stake<T> as IterableStorageDoubleMap<T::AccountId, T::AccountId, u128> >::iter_prefix( delegate.clone() ) {
             if stake == 0 { continue; }
             // Only add nominators with stake
             nominators.push( ( nominator.clone(), stake.into() ) );
@@ -33,7 +34,7 @@ impl<T: Config> Pallet<T> {
 
         let registrations = Self::get_registered_networks_for_computekey( &delegate.clone() );
         let mut validator_permits = Vec::<Compact<u16>>::new();
-        let mut emissions_per_day: U64F64 = U64F64::from_num(0);
+        let mut emissions_per_day: U96F32 = U96F32::from_num(0);
 
         for netuid in registrations.iter() {
             let _uid = Self::get_uid_for_net_and_computekey( *netuid, &delegate.clone());
@@ -46,33 +47,36 @@ impl<T: Config> Pallet<T> {
                     validator_permits.push( (*netuid).into() );
                 }
 
-                let emission: U64F64 = Self::get_emission_for_uid( *netuid, uid).into();
-                let tempo: U64F64 = Self::get_tempo( *netuid ).into();
-                let epochs_per_day: U64F64 = U64F64::from_num(7200) / tempo;
+                let emission: U96F32 = U96F32::from_num( Self::get_emission_for_uid( *netuid, uid) );
+                let tempo: U96F32 = U96F32::from_num( Self::get_tempo( *netuid ) );
+                let epochs_per_day: U96F32 = U96F32::from_num(7200) / tempo;
                 emissions_per_day += emission * epochs_per_day;
             }
         }
 
         let owner = Self::get_owning_personalkey_for_computekey( &delegate.clone() );
         let take: Compact<u16> = <Delegates<T>>::get( delegate.clone() ).into();
+        let pending_take: Option<(Compact<u16>, Compact<u64>)> = Self::get_pending_delegate_take( &delegate.clone() )
+            .map(|(pending_take, apply_at_block)| (pending_take.into(), apply_at_block.into()));
 
-        let total_stake: U64F64 = Self::get_total_stake_for_computekey( &delegate.clone() ).into();
+        let total_stake: U96F32 = U96F32::from_num( Self::get_total_stake_for_computekey( &delegate.clone() ) );
 
-        let mut return_per_1000: U64F64 = U64F64::from_num(0);
+        let mut return_per_1000: U96F32 = U96F32::from_num(0);
 
-        if total_stake > U64F64::from_num(0) {
-            return_per_1000 = ( emissions_per_day *  U64F64::from_num(0.82)) / (total_stake /  U64F64::from_num(1000));
+        if total_stake > U96F32::from_num(0) {
+            return_per_1000 = ( emissions_per_day *  U96F32::from_num(0.82)) / (total_stake /  U96F32::from_num(1000));
         }
 
         return DelegateInfo {
             delegate_ss58: delegate.clone(),
             take,
+            pending_take,
             nominators,
             owner_ss58: owner.clone(),
             registrations: registrations.iter().map(|x| x.into()).collect(),
             validator_permits,
-            return_per_1000: U64F64::to_num::<u64>(return_per_1000).into(),
-            total_daily_return: U64F64::to_num::<u64>(emissions_per_day).into(),
+            return_per_1000: U96F32::to_num::<u128>(return_per_1000).into(),
+            total_daily_return: U96F32::to_num::<u128>(emissions_per_day).into(),
         };
     }
 
@@ -102,7 +106,7 @@ impl<T: Config> Pallet<T> {
         return delegates;
 	}
 
-    pub fn get_delegated(delegatee_account_vec: Vec<u8>) -> Vec<(DelegateInfo<T>, Compact<u64>)> {
+    pub fn get_delegated(delegatee_account_vec: Vec<u8>) -> Vec<(DelegateInfo<T>, Compact<u128>)> {
         if delegatee_account_vec.len() != 32 {
             return Vec::new(); // No delegates for invalid account
         }
@@ -110,7 +114,7 @@ impl<T: Config> Pallet<T> {
         let delegatee: AccountIdOf<T> = T::AccountId::decode( &mut delegatee_account_vec.as_bytes_ref() ).unwrap();
 
 
-        let mut delegates: Vec<(DelegateInfo<T>, Compact<u64>)> = Vec::new();
+        let mut delegates: Vec<(DelegateInfo<T>, Compact<u128>)> = Vec::new();
         for delegate in < Delegates<T> as IterableStorageMap<T::AccountId, u16> >::iter_keys().into_iter() {
             let staked_to_this_delegatee = Self::get_stake_for_personalkey_and_computekey( &delegatee.clone(), &delegate.clone() );
             if staked_to_this_delegatee == 0 {
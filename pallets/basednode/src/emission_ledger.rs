@@ -0,0 +1,86 @@
+use super::*;
+extern crate alloc;
+use alloc::vec::Vec;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+
+// Number of blocks the emission ledger is kept for. Older blocks are pruned.
+pub const EMISSION_LEDGER_LENGTH: u64 = 7200;
+
+impl<T: Config> Pallet<T> {
+    // Applies the update to the in-memory ledger entry of the brain, creating it if needed. The entries
+    // of a block step stage are written with store_emission_ledger once the stage is done.
+    //
+    pub fn note_emission(
+        ledger: &mut Vec<EmissionLedgerEntry>,
+        netuid: u16,
+        update: impl FnOnce(&mut EmissionLedgerEntry),
+    ) {
+        match ledger.iter_mut().find(|entry| entry.netuid == netuid) {
+            Some(entry) => update(entry),
+            None => {
+                let mut entry = EmissionLedgerEntry {
+                    netuid,
+                    ..Default::default()
+                };
+                update(&mut entry);
+                ledger.push(entry);
+            }
+        }
+    }
+
+    // Adds the entries to the ledger of the block in one read and one write. Entries of a brain already
+    // in the ledger, e.g. from an earlier stage of the block step, are summed. Returns the weight used.
+    //
+    pub fn store_emission_ledger(block_number: u64, ledger: Vec<EmissionLedgerEntry>) -> Weight {
+        if ledger.is_empty() {
+            return Weight::from_ref_time(0);
+        }
+        EmissionLedger::<T>::mutate(block_number, |entries| {
+            for entry in ledger {
+                match entries.iter_mut().find(|stored| stored.netuid == entry.netuid) {
+                    Some(stored) => {
+                        stored.queued = stored.queued.saturating_add(entry.queued);
+                        stored.owner_cut = stored.owner_cut.saturating_add(entry.owner_cut);
+                        stored.holder_cut = stored.holder_cut.saturating_add(entry.holder_cut);
+                        stored.burned = stored.burned.saturating_add(entry.burned);
+                        stored.drained = stored.drained.saturating_add(entry.drained);
+                    }
+                    None => entries.push(entry),
+                }
+            }
+        });
+        T::DbWeight::get().reads_writes(1, 1)
+    }
+
+    // Removes the ledger of the block which fell out of the window.
+    //
+    pub fn prune_emission_ledger(block_number: u64) {
+        if block_number >= EMISSION_LEDGER_LENGTH {
+            EmissionLedger::<T>::remove(block_number - EMISSION_LEDGER_LENGTH);
+        }
+    }
+
+    // Returns the ledger entries of the blocks in [from_block, to_block] which are still kept.
+    //
+    pub fn get_emission_ledger(
+        from_block: u64,
+        to_block: u64,
+    ) -> Vec<(u64, Vec<EmissionLedgerEntry>)> {
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let oldest_block: u64 = current_block.saturating_sub(EMISSION_LEDGER_LENGTH - 1);
+        let from_block: u64 = from_block.max(oldest_block);
+        let to_block: u64 = to_block.min(current_block);
+
+        let mut ledger: Vec<(u64, Vec<EmissionLedgerEntry>)> = Vec::new();
+        if from_block > to_block {
+            return ledger; // Nothing kept in the range.
+        }
+        for block_number in from_block..=to_block {
+            if EmissionLedger::<T>::contains_key(block_number) {
+                ledger.push((block_number, EmissionLedger::<T>::get(block_number)));
+            }
+        }
+        return ledger;
+    }
+}
@@ -5,11 +5,46 @@ use frame_support::inherent::Vec;
 use substrate_fixed::types::{I32F32, I64F64, I96F32};
 use frame_support::storage::IterableStorageDoubleMap;
 
+// Every vector the epoch of a brain computes, before anything is written to storage.
+pub struct EpochOutput<T: Config> {
+    pub computekeys: Vec<(u16, T::AccountId)>,
+    pub stake: Vec<I32F32>,                    // Normalized stake.
+    pub active: Vec<bool>,                     // Agents that set weights within the activity cutoff.
+    pub weights: Vec<Vec<(u16, I32F32)>>,      // Weights of permitted validators, masked and row normalized, before consensus clipping.
+    pub consensus: Vec<I32F32>,
+    pub validator_trust: Vec<I32F32>,
+    pub ranks: Vec<I32F32>,
+    pub trust: Vec<I32F32>,
+    pub incentive: Vec<I32F32>,
+    pub dividends: Vec<I32F32>,
+    pub bonds: Vec<Vec<(u16, I32F32)>>,        // New EMA bonds, column max upscaled.
+    pub pruning_scores: Vec<I32F32>,
+    pub validator_permits: Vec<bool>,          // Permits before the epoch.
+    pub new_validator_permits: Vec<bool>,
+    pub combined_emission: Vec<u128>,
+    pub server_emission: Vec<u128>,
+    pub validator_emission: Vec<u128>,
+}
+
+// The inputs every consensus mechanism computes its epoch from, read from the storage of a brain.
+pub struct EpochInputs<T: Config> {
+    pub n: u16,
+    pub computekeys: Vec<(u16, T::AccountId)>,
+    pub last_update: Vec<u64>,
+    pub block_at_registration: Vec<u64>,
+    pub active: Vec<bool>,                     // Agents that set weights within the activity cutoff.
+    pub stake: Vec<I32F32>,                    // Normalized stake.
+    pub active_stake: Vec<I32F32>,             // Normalized stake of active permitted validators.
+    pub validator_permits: Vec<bool>,          // Permits before the epoch.
+    pub new_validator_permits: Vec<bool>,
+    pub weights: Vec<Vec<(u16, I32F32)>>,      // Weights of permitted validators, masked and row normalized.
+}
+
 impl<T: Config> Pallet<T> {
 
     // Calculates reward consensus and returns the emissions for uids/computekeys in a given `netuid`.
     // (Dense version used only for testing purposes.)
-    pub fn epoch_dense( netuid: u16, rao_emission: u64 ) -> Vec<(T::AccountId, u64, u64)> {
+    pub fn epoch_dense( netuid: u16, rao_emission: u128 ) -> Vec<(T::AccountId, u128, u128)> {
 
         // Get brain size.
         let n: u16 = Self::get_brain_n( netuid );
@@ -57,12 +92,12 @@ impl<T: Config> Pallet<T> {
         log::trace!( "computekeys: {:?}", &computekeys );
 
         // Access network stake as normalized vector.
-        let mut stake_64: Vec<I64F64> = vec![ I64F64::from_num(0.0); n as usize ];
+        let mut stake_96: Vec<I96F32> = vec![ I96F32::from_num(0.0); n as usize ];
         for (uid_i, computekey) in computekeys.iter() {
-            stake_64[ *uid_i as usize ] = I64F64::from_num( Self::get_total_stake_for_computekey( computekey ) );
+            stake_96[ *uid_i as usize ] = I96F32::from_num( Self::get_total_stake_for_computekey( computekey ) );
         }
-        inplace_normalize_64( &mut stake_64 );
-        let stake: Vec<I32F32> = vec_fixed64_to_fixed32( stake_64 );
+        inplace_normalize_96( &mut stake_96 );
+        let stake: Vec<I32F32> = vec_fixed96_to_fixed32( stake_96 );
         log::trace!( "S:\n{:?}\n", &stake );
 
         // =======================
@@ -213,14 +248,14 @@ impl<T: Config> Pallet<T> {
         let float_rao_emission: I96F32 = I96F32::from_num( rao_emission );
 
         let server_emission: Vec<I96F32> = normalized_server_emission.iter().map( |se: &I32F32| I96F32::from_num( *se ) * float_rao_emission ).collect();
-        let server_emission: Vec<u64> = server_emission.iter().map( |e: &I96F32| e.to_num::<u64>() ).collect();
+        let server_emission: Vec<u128> = server_emission.iter().map( |e: &I96F32| e.to_num::<u128>() ).collect();
 
         let validator_emission: Vec<I96F32> = normalized_validator_emission.iter().map( |ve: &I32F32| I96F32::from_num( *ve ) * float_rao_emission ).collect();
-        let validator_emission: Vec<u64> = validator_emission.iter().map( |e: &I96F32| e.to_num::<u64>() ).collect();
+        let validator_emission: Vec<u128> = validator_emission.iter().map( |e: &I96F32| e.to_num::<u128>() ).collect();
 
         // Used only to track combined emission in the storage.
         let combined_emission: Vec<I96F32> = normalized_combined_emission.iter().map( |ce: &I32F32| I96F32::from_num( *ce ) * float_rao_emission ).collect();
-        let combined_emission: Vec<u64> = combined_emission.iter().map( |e: &I96F32| e.to_num::<u64>() ).collect();
+        let combined_emission: Vec<u128> = combined_emission.iter().map( |e: &I96F32| e.to_num::<u128>() ).collect();
 
         log::trace!( "nSE: {:?}", &normalized_server_emission );
         log::trace!( "SE: {:?}", &server_emission );
@@ -237,7 +272,7 @@ impl<T: Config> Pallet<T> {
         // ===================
         // == Value storage ==
         // ===================
-        let cloned_emission: Vec<u64> = combined_emission.clone();
+        let cloned_emission: Vec<u128> = combined_emission.clone();
         let cloned_ranks: Vec<u16> = ranks.iter().map(|xi| fixed_proportion_to_u16(*xi)).collect::<Vec<u16>>();
         let cloned_trust: Vec<u16> = trust.iter().map(|xi| fixed_proportion_to_u16(*xi)).collect::<Vec<u16>>();
         let cloned_consensus: Vec<u16> = consensus.iter().map(|xi| fixed_proportion_to_u16(*xi)).collect::<Vec<u16>>();
@@ -271,7 +306,7 @@ impl<T: Config> Pallet<T> {
             }
         }
 
-        let mut result: Vec<(T::AccountId, u64, u64)> = vec![];
+        let mut result: Vec<(T::AccountId, u128, u128)> = vec![];
         for ( uid_i, computekey ) in computekeys.iter() {
             result.push( ( computekey.clone(), server_emission[ *uid_i as usize ], validator_emission[ *uid_i as usize ] ) );
         }
@@ -279,20 +314,68 @@ impl<T: Config> Pallet<T> {
 
     }
 
-    // Calculates reward consensus values, then updates rank, trust, consensus, incentive, dividend, pruning_score, emission and bonds, and
-    // returns the emissions for uids/computekeys in a given `netuid`.
+    // Calculates reward consensus values with the consensus mechanism of the brain, then updates rank, trust, consensus, incentive,
+    // dividend, pruning_score, emission and bonds, and returns the emissions for uids/computekeys in a given `netuid`.
     //
     // # Args:
     // 	* 'netuid': ( u16 ):
     //         - The network to distribute the emission onto.
     //
-    // 	* 'rao_emission': ( u64 ):
+    // 	* 'rao_emission': ( u128 ):
     //         - The total emission for the epoch.
     //
-    // 	* 'debug' ( bool ):
-    // 		- Print debugging outputs.
+    pub fn epoch( netuid: u16, rao_emission: u128 ) -> Vec<(T::AccountId, u128, u128)> {
+        let output: EpochOutput<T> = Self::compute_consensus( netuid, rao_emission );
+        let n: u16 = Self::get_brain_n( netuid );
+
+        // ===================
+        // == Value storage ==
+        // ===================
+        let cloned_emission: Vec<u128> = output.combined_emission.clone();
+        let cloned_ranks: Vec<u16> = output.ranks.iter().map(|xi| fixed_proportion_to_u16(*xi)).collect::<Vec<u16>>();
+        let cloned_trust: Vec<u16> = output.trust.iter().map(|xi| fixed_proportion_to_u16(*xi)).collect::<Vec<u16>>();
+        let cloned_consensus: Vec<u16> = output.consensus.iter().map(|xi| fixed_proportion_to_u16(*xi)).collect::<Vec<u16>>();
+        let cloned_incentive: Vec<u16> = output.incentive.iter().map(|xi| fixed_proportion_to_u16(*xi)).collect::<Vec<u16>>();
+        let cloned_dividends: Vec<u16> = output.dividends.iter().map(|xi| fixed_proportion_to_u16(*xi)).collect::<Vec<u16>>();
+        let cloned_pruning_scores: Vec<u16> = vec_max_upscale_to_u16(&output.pruning_scores);
+        let cloned_validator_trust: Vec<u16> = output.validator_trust.iter().map(|xi| fixed_proportion_to_u16(*xi)).collect::<Vec<u16>>();
+        Active::<T>::insert( netuid, output.active.clone() );
+        Emission::<T>::insert( netuid, cloned_emission );
+        Rank::<T>::insert( netuid, cloned_ranks);
+        Trust::<T>::insert( netuid, cloned_trust);
+        Consensus::<T>::insert( netuid, cloned_consensus );
+        Incentive::<T>::insert( netuid, cloned_incentive );
+        Dividends::<T>::insert( netuid, cloned_dividends );
+        PruningScores::<T>::insert( netuid, cloned_pruning_scores );
+        ValidatorTrust::<T>::insert( netuid, cloned_validator_trust );
+        ValidatorPermit::<T>::insert( netuid, output.new_validator_permits.clone() );
+
+        for i in 0..n {
+            // Set bonds only if uid retains validator permit, otherwise clear bonds.
+            if output.new_validator_permits[i as usize] {
+                let new_bonds_row: Vec<(u16,u16)> = output.bonds[i as usize].iter().map( |(j, value)| (*j, fixed_proportion_to_u16(*value))).collect();
+                Bonds::<T>::insert( netuid, i, new_bonds_row );
+            }
+            else if output.validator_permits[ i as usize ] {
+                // Only overwrite the intersection.
+                let new_empty_bonds_row: Vec<(u16,u16)> = vec![];
+                Bonds::<T>::insert( netuid, i, new_empty_bonds_row );
+            }
+        }
+
+        // Emission tuples ( computekeys, server_emission, validator_emission )
+        let mut result: Vec<(T::AccountId, u128, u128)> = vec![];
+        for ( uid_i, computekey ) in output.computekeys.iter() {
+            result.push( ( computekey.clone(), output.server_emission[ *uid_i as usize ], output.validator_emission[ *uid_i as usize ] ) );
+        }
+        result
+    }
+
+    // Reads the inputs every consensus mechanism starts from out of the current storage of the brain: the
+    // active agents, the normalized stake, the validator permits and the weights of permitted validators,
+    // masked and row normalized.
     //
-    pub fn epoch( netuid: u16, rao_emission: u64 ) -> Vec<(T::AccountId, u64, u64)> {
+    pub fn compute_epoch_inputs( netuid: u16 ) -> EpochInputs<T> {
         // Get brain size.
         let n: u16 = Self::get_brain_n( netuid );
         log::trace!( "n: {:?}", n );
@@ -335,12 +418,12 @@ impl<T: Config> Pallet<T> {
         log::trace!( "computekeys: {:?}", &computekeys );
 
         // Access network stake as normalized vector.
-        let mut stake_64: Vec<I64F64> = vec![ I64F64::from_num(0.0); n as usize ];
+        let mut stake_96: Vec<I96F32> = vec![ I96F32::from_num(0.0); n as usize ];
         for (uid_i, computekey) in computekeys.iter() {
-            stake_64[ *uid_i as usize ] = I64F64::from_num( Self::get_total_stake_for_computekey( computekey ) );
+            stake_96[ *uid_i as usize ] = I96F32::from_num( Self::get_total_stake_for_computekey( computekey ) );
         }
-        inplace_normalize_64( &mut stake_64 );
-        let stake: Vec<I32F32> = vec_fixed64_to_fixed32( stake_64 );
+        inplace_normalize_96( &mut stake_96 );
+        let stake: Vec<I32F32> = vec_fixed96_to_fixed32( stake_96 );
         // range: I32F32(0, 1)
         log::trace!( "S: {:?}", &stake );
 
@@ -403,6 +486,38 @@ impl<T: Config> Pallet<T> {
         inplace_row_normalize_sparse( &mut weights );
         // log::trace!( "W (mask+norm): {:?}", &weights );
 
+        EpochInputs {
+            n,
+            computekeys,
+            last_update,
+            block_at_registration,
+            active,
+            stake,
+            active_stake,
+            validator_permits,
+            new_validator_permits,
+            weights,
+        }
+    }
+
+    // Runs the Yuma consensus math of a brain on the current storage and returns every vector it computes,
+    // without writing anything. The default consensus mechanism, see consensus.rs.
+    //
+    pub fn compute_yuma_epoch( netuid: u16, rao_emission: u128 ) -> EpochOutput<T> {
+        let EpochInputs {
+            n,
+            computekeys,
+            last_update,
+            block_at_registration,
+            active,
+            stake,
+            active_stake,
+            validator_permits,
+            new_validator_permits,
+            mut weights,
+        } = Self::compute_epoch_inputs( netuid );
+        let masked_weights: Vec<Vec<(u16, I32F32)>> = weights.clone();
+
         // ================================
         // == Consensus, Validator Trust ==
         // ================================
@@ -481,6 +596,38 @@ impl<T: Config> Pallet<T> {
         // == Emission and Pruning scores ==
         // =================================
 
+        let ( pruning_scores, combined_emission, server_emission, validator_emission ) =
+            Self::compute_emission( &stake, &active_stake, &incentive, &dividends, rao_emission );
+        log::trace!( "P: {:?}", &pruning_scores );
+
+        // Column max-upscale EMA bonds for storage: max_i w_ij = 1.
+        inplace_col_max_upscale_sparse( &mut ema_bonds, n );
+
+        EpochOutput {
+            computekeys,
+            stake,
+            active,
+            weights: masked_weights,
+            consensus,
+            validator_trust,
+            ranks,
+            trust,
+            incentive,
+            dividends,
+            bonds: ema_bonds,
+            pruning_scores,
+            validator_permits,
+            new_validator_permits,
+            combined_emission,
+            server_emission,
+            validator_emission,
+        }
+    }
+
+    // Splits the emission of an epoch between servers, by incentive, and validators, by dividends, falling back to
+    // stake when neither is set. Returns the normalized combined emission, used as pruning scores, with the combined,
+    // server and validator emission in rao.
+    pub fn compute_emission( stake: &Vec<I32F32>, active_stake: &Vec<I32F32>, incentive: &Vec<I32F32>, dividends: &Vec<I32F32>, rao_emission: u128 ) -> ( Vec<I32F32>, Vec<u128>, Vec<u128>, Vec<u128> ) {
         // Compute normalized emission scores. range: I32F32(0, 1)
         let combined_emission: Vec<I32F32> = incentive.iter().zip( dividends.clone() ).map( |(ii, di)| ii + di ).collect();
         let emission_sum: I32F32 = combined_emission.iter().sum();
@@ -495,7 +642,7 @@ impl<T: Config> Pallet<T> {
 
         // If emission is zero, replace emission with normalized stake.
         if emission_sum == I32F32::from(0) { // no weights set | outdated weights | self_weights
-            if is_zero( &active_stake ) { // no active stake
+            if is_zero( active_stake ) { // no active stake
                 normalized_validator_emission = stake.clone(); // do not mask inactive, assumes stake is normalized
                 normalized_combined_emission = stake.clone();
             }
@@ -509,14 +656,14 @@ impl<T: Config> Pallet<T> {
         let float_rao_emission: I96F32 = I96F32::from_num( rao_emission );
 
         let server_emission: Vec<I96F32> = normalized_server_emission.iter().map( |se: &I32F32| I96F32::from_num( *se ) * float_rao_emission ).collect();
-        let server_emission: Vec<u64> = server_emission.iter().map( |e: &I96F32| e.to_num::<u64>() ).collect();
+        let server_emission: Vec<u128> = server_emission.iter().map( |e: &I96F32| e.to_num::<u128>() ).collect();
 
         let validator_emission: Vec<I96F32> = normalized_validator_emission.iter().map( |ve: &I32F32| I96F32::from_num( *ve ) * float_rao_emission ).collect();
-        let validator_emission: Vec<u64> = validator_emission.iter().map( |e: &I96F32| e.to_num::<u64>() ).collect();
+        let validator_emission: Vec<u128> = validator_emission.iter().map( |e: &I96F32| e.to_num::<u128>() ).collect();
 
         // Only used to track emission in storage.
         let combined_emission: Vec<I96F32> = normalized_combined_emission.iter().map( |ce: &I32F32| I96F32::from_num( *ce ) * float_rao_emission ).collect();
-        let combined_emission: Vec<u64> = combined_emission.iter().map( |e: &I96F32| e.to_num::<u64>() ).collect();
+        let combined_emission: Vec<u128> = combined_emission.iter().map( |e: &I96F32| e.to_num::<u128>() ).collect();
 
         log::trace!( "nSE: {:?}", &normalized_server_emission );
         log::trace!( "SE: {:?}", &server_emission );
@@ -525,54 +672,7 @@ impl<T: Config> Pallet<T> {
         log::trace!( "nCE: {:?}", &normalized_combined_emission );
         log::trace!( "CE: {:?}", &combined_emission );
 
-
-        // Set pruning scores using combined emission scores.
-        let pruning_scores: Vec<I32F32> = normalized_combined_emission.clone();
-        log::trace!( "P: {:?}", &pruning_scores );
-
-        // ===================
-        // == Value storage ==
-        // ===================
-        let cloned_emission: Vec<u64> = combined_emission.clone();
-        let cloned_ranks: Vec<u16> = ranks.iter().map(|xi| fixed_proportion_to_u16(*xi)).collect::<Vec<u16>>();
-        let cloned_trust: Vec<u16> = trust.iter().map(|xi| fixed_proportion_to_u16(*xi)).collect::<Vec<u16>>();
-        let cloned_consensus: Vec<u16> = consensus.iter().map(|xi| fixed_proportion_to_u16(*xi)).collect::<Vec<u16>>();
-        let cloned_incentive: Vec<u16> = incentive.iter().map(|xi| fixed_proportion_to_u16(*xi)).collect::<Vec<u16>>();
-        let cloned_dividends: Vec<u16> = dividends.iter().map(|xi| fixed_proportion_to_u16(*xi)).collect::<Vec<u16>>();
-        let cloned_pruning_scores: Vec<u16> = vec_max_upscale_to_u16(&pruning_scores);
-        let cloned_validator_trust: Vec<u16> = validator_trust.iter().map(|xi| fixed_proportion_to_u16(*xi)).collect::<Vec<u16>>();
-        Active::<T>::insert( netuid, active.clone() );
-        Emission::<T>::insert( netuid, cloned_emission );
-        Rank::<T>::insert( netuid, cloned_ranks);
-        Trust::<T>::insert( netuid, cloned_trust);
-        Consensus::<T>::insert( netuid, cloned_consensus );
-        Incentive::<T>::insert( netuid, cloned_incentive );
-        Dividends::<T>::insert( netuid, cloned_dividends );
-        PruningScores::<T>::insert( netuid, cloned_pruning_scores );
-        ValidatorTrust::<T>::insert( netuid, cloned_validator_trust );
-        ValidatorPermit::<T>::insert( netuid, new_validator_permits.clone() );
-
-        // Column max-upscale EMA bonds for storage: max_i w_ij = 1.
-        inplace_col_max_upscale_sparse( &mut ema_bonds, n );
-        for i in 0..n {
-            // Set bonds only if uid retains validator permit, otherwise clear bonds.
-            if new_validator_permits[i as usize] {
-                let new_bonds_row: Vec<(u16,u16)> = ema_bonds[i as usize].iter().map( |(j, value)| (*j, fixed_proportion_to_u16(*value))).collect();
-                Bonds::<T>::insert( netuid, i, new_bonds_row );
-            }
-            else if validator_permits[ i as usize ] {
-                // Only overwrite the intersection.
-                let new_empty_bonds_row: Vec<(u16,u16)> = vec![];
-                Bonds::<T>::insert( netuid, i, new_empty_bonds_row );
-            }
-        }
-
-        // Emission tuples ( computekeys, server_emission, validator_emission )
-        let mut result: Vec<(T::AccountId, u64, u64)> = vec![];
-        for ( uid_i, computekey ) in computekeys.iter() {
-            result.push( ( computekey.clone(), server_emission[ *uid_i as usize ], validator_emission[ *uid_i as usize ] ) );
-        }
-        result
+        ( normalized_combined_emission, combined_emission, server_emission, validator_emission )
     }
 
     pub fn get_float_rho( netuid:u16 ) -> I32F32 { I32F32::from_num( Self::get_rho( netuid ) )  }
@@ -580,12 +680,12 @@ impl<T: Config> Pallet<T> {
 
     pub fn get_normalized_stake( netuid:u16 ) -> Vec<I32F32> {
         let n: usize = Self::get_brain_n( netuid ) as usize;
-        let mut stake_64: Vec<I64F64> = vec![ I64F64::from_num(0.0); n ];
+        let mut stake_96: Vec<I96F32> = vec![ I96F32::from_num(0.0); n ];
         for agent_uid in 0..n {
-            stake_64[agent_uid] = I64F64::from_num( Self::get_stake_for_uid_and_brain( netuid, agent_uid as u16 ) );
+            stake_96[agent_uid] = I96F32::from_num( Self::get_stake_for_uid_and_brain( netuid, agent_uid as u16 ) );
         }
-        inplace_normalize_64( &mut stake_64 );
-        let stake: Vec<I32F32> = vec_fixed64_to_fixed32( stake_64 );
+        inplace_normalize_96( &mut stake_96 );
+        let stake: Vec<I32F32> = vec_fixed96_to_fixed32( stake_96 );
         stake
     }
 
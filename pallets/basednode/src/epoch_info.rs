@@ -0,0 +1,80 @@
+use super::*;
+use crate::epoch::EpochOutput;
+use crate::math::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use alloc::vec::Vec;
+use substrate_fixed::types::I32F32;
+
+// The vectors of an epoch run without writing to storage. Proportions are scaled to u16 as in storage.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct EpochDryRun {
+    pub netuid: u16,
+    pub block: u64,
+    pub rao_emission: u128,                // Emission pending for the brain, distributed by the epoch.
+    pub stake: Vec<u16>,                  // S
+    pub active: Vec<bool>,
+    pub weights: Vec<Vec<(u16, u16)>>,    // W, masked and row normalized, before consensus clipping.
+    pub consensus: Vec<u16>,              // C
+    pub validator_trust: Vec<u16>,
+    pub ranks: Vec<u16>,                  // R
+    pub trust: Vec<u16>,                  // T
+    pub incentive: Vec<u16>,              // I
+    pub dividends: Vec<u16>,              // D
+    pub bonds: Vec<Vec<(u16, u16)>>,      // B, the new EMA bonds.
+    pub validator_permits: Vec<bool>,     // Permits after the epoch.
+    pub emission: Vec<(u16, u128, u128)>, // ( uid, server_emission, validator_emission )
+}
+
+fn to_u16(vector: &Vec<I32F32>) -> Vec<u16> {
+    vector.iter().map(|xi| fixed_proportion_to_u16(*xi)).collect()
+}
+
+fn sparse_to_u16(matrix: &Vec<Vec<(u16, I32F32)>>) -> Vec<Vec<(u16, u16)>> {
+    matrix
+        .iter()
+        .map(|row| row.iter().map(|(j, value)| (*j, fixed_proportion_to_u16(*value))).collect())
+        .collect()
+}
+
+impl<T: Config> Pallet<T> {
+    // Runs the epoch of the brain, with its consensus mechanism, on the emission pending for it and returns
+    // what it computes, leaving storage untouched.
+    pub fn epoch_dry_run(netuid: u16) -> Option<EpochDryRun> {
+        if !Self::if_brain_exist(netuid) {
+            return None;
+        }
+
+        let rao_emission: u128 = PendingEmission::<T>::get(netuid);
+        let output: EpochOutput<T> = Self::compute_consensus(netuid, rao_emission);
+
+        return Some(EpochDryRun {
+            netuid,
+            block: Self::get_current_block_as_u64(),
+            rao_emission,
+            stake: to_u16(&output.stake),
+            active: output.active,
+            weights: sparse_to_u16(&output.weights),
+            consensus: to_u16(&output.consensus),
+            validator_trust: to_u16(&output.validator_trust),
+            ranks: to_u16(&output.ranks),
+            trust: to_u16(&output.trust),
+            incentive: to_u16(&output.incentive),
+            dividends: to_u16(&output.dividends),
+            bonds: sparse_to_u16(&output.bonds),
+            validator_permits: output.new_validator_permits,
+            emission: output
+                .computekeys
+                .iter()
+                .map(|(uid, _)| {
+                    (
+                        *uid,
+                        output.server_emission[*uid as usize],
+                        output.validator_emission[*uid as usize],
+                    )
+                })
+                .collect(),
+        });
+    }
+}
@@ -0,0 +1,119 @@
+use super::*;
+extern crate alloc;
+use alloc::vec::Vec;
+use frame_support::sp_std::mem::discriminant;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+
+impl<T: Config> Pallet<T> {
+    // Queues a hyperparameter change on the brain to apply once the hyperparameter change delay
+    // has passed. A queued change of the same hyperparameter is replaced. Returns the block the
+    // change applies at, at the earliest the next block.
+    //
+    pub fn schedule_hyperparam_change(netuid: u16, change: HyperparamChange) -> u64 {
+        let effective_block: u64 = Self::get_current_block_as_u64()
+            .saturating_add(Self::get_hyperparam_change_delay().max(1));
+        PendingHyperparamChanges::<T>::mutate(netuid, |changes| {
+            changes.retain(|(_, pending)| discriminant(pending) != discriminant(&change));
+            changes.push((effective_block, change.clone()));
+        });
+        HyperparamChangeQueue::<T>::mutate(effective_block, |netuids| {
+            if !netuids.contains(&netuid) {
+                netuids.push(netuid);
+            }
+        });
+        log::info!(
+            "HyperparamChangeScheduled( netuid:{:?}, change:{:?}, effective_block:{:?} )",
+            netuid,
+            change,
+            effective_block
+        );
+        Self::deposit_event(Event::HyperparamChangeScheduled(netuid, change, effective_block));
+        effective_block
+    }
+
+    // Drops the queued change of the same hyperparameter as the given change, called when root sets
+    // the hyperparameter directly so the queued value does not overwrite it later.
+    //
+    pub fn cancel_hyperparam_change(netuid: u16, change: &HyperparamChange) {
+        PendingHyperparamChanges::<T>::mutate_exists(netuid, |changes| {
+            if let Some(pending) = changes {
+                pending.retain(|(_, pending)| discriminant(pending) != discriminant(change));
+                if pending.is_empty() {
+                    *changes = None;
+                }
+            }
+        });
+    }
+
+    // Applies the queued hyperparameter changes of the brains indexed at the block. Returns the weight used.
+    //
+    pub fn apply_pending_hyperparam_changes(block_number: u64) -> Weight {
+        let netuids: Vec<u16> = HyperparamChangeQueue::<T>::take(block_number);
+        let mut weight: Weight = T::DbWeight::get().reads_writes(1, 1);
+
+        for netuid in netuids {
+            // Changes replaced or cancelled since they were indexed are no longer due at this block.
+            weight.saturating_accrue(T::DbWeight::get().reads(1));
+            let (due, pending): (Vec<_>, Vec<_>) = PendingHyperparamChanges::<T>::get(netuid)
+                .into_iter()
+                .partition(|(effective_block, _)| *effective_block <= block_number);
+            if due.is_empty() {
+                continue;
+            }
+            weight.saturating_accrue(T::DbWeight::get().writes(1 + due.len() as u64));
+            if pending.is_empty() {
+                PendingHyperparamChanges::<T>::remove(netuid);
+            } else {
+                PendingHyperparamChanges::<T>::insert(netuid, pending);
+            }
+            for (_, change) in due {
+                Self::apply_hyperparam_change(netuid, change.clone());
+                log::info!("HyperparamChangeApplied( netuid:{:?}, change:{:?} )", netuid, change);
+                Self::deposit_event(Event::HyperparamChangeApplied(netuid, change));
+            }
+        }
+        weight
+    }
+
+    // Sets the hyperparameter of the brain to the value of the change.
+    //
+    pub fn apply_hyperparam_change(netuid: u16, change: HyperparamChange) {
+        match change {
+            HyperparamChange::ServingRateLimit(value) => Self::set_serving_rate_limit(netuid, value),
+            HyperparamChange::MinDifficulty(value) => Self::set_min_difficulty(netuid, value),
+            HyperparamChange::MaxDifficulty(value) => Self::set_max_difficulty(netuid, value),
+            HyperparamChange::WeightsVersionKey(value) => Self::set_weights_version_key(netuid, value),
+            HyperparamChange::WeightsSetRateLimit(value) => Self::set_weights_set_rate_limit(netuid, value),
+            HyperparamChange::MaxWeightLimit(value) => Self::set_max_weight_limit(netuid, value),
+            HyperparamChange::ImmunityPeriod(value) => Self::set_immunity_period(netuid, value),
+            HyperparamChange::MinAllowedWeights(value) => Self::set_min_allowed_weights(netuid, value),
+            HyperparamChange::ActivityCutoff(value) => Self::set_activity_cutoff(netuid, value),
+            // The uid limit may have been lowered while the change was queued.
+            HyperparamChange::MaxAllowedValidators(value) => Self::set_max_allowed_validators(
+                netuid,
+                value.min(Self::get_max_allowed_uids(netuid)),
+            ),
+            HyperparamChange::Tempo(value) => Self::set_tempo(netuid, value),
+            HyperparamChange::MinBurn(value) => Self::set_min_burn(netuid, value),
+            HyperparamChange::MaxBurn(value) => Self::set_max_burn(netuid, value),
+            HyperparamChange::CommitRevealWeightsEnabled(enabled) => {
+                Self::set_commit_reveal_weights_enabled(netuid, enabled)
+            }
+            // Root may have lowered the maximums while the change was queued.
+            HyperparamChange::OwnerCut(value) => {
+                Self::set_owner_cut(netuid, value.min(Self::get_max_owner_cut()))
+            }
+            HyperparamChange::TokenHolderThreshold(value) => Self::set_token_holder_threshold(
+                netuid,
+                value.min(Self::get_max_token_holder_threshold()),
+            ),
+        }
+    }
+
+    // Returns the hyperparameter changes queued on the brain with the block each applies at.
+    //
+    pub fn get_pending_hyperparam_changes(netuid: u16) -> Vec<(u64, HyperparamChange)> {
+        PendingHyperparamChanges::<T>::get(netuid)
+    }
+}
@@ -19,7 +19,8 @@ use frame_support::sp_runtime::transaction_validity::ValidTransaction;
 use scale_info::TypeInfo;
 use sp_runtime::{
     traits::{DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SignedExtension},
-    transaction_validity::{TransactionValidity, TransactionValidityError},
+    transaction_validity::{InvalidTransaction, TransactionValidity, TransactionValidityError},
+    Perbill,
 };
 use sp_std::marker::PhantomData;
 
@@ -47,7 +48,12 @@ mod weights;
 pub mod delegate_info;
 pub mod agent_info;
 pub mod stake_info;
+pub mod emission_ledger;
+pub mod hyperparam_changes;
 pub mod brain_info;
+pub mod epoch_info;
+pub mod consensus;
+pub mod default_weights;
 pub mod tft_enforcer_data;
 
 // apparently this is stabilized since rust 1.36
@@ -62,7 +68,7 @@ pub mod pallet {
         inherent::Vec,
         pallet_prelude::{DispatchResult, StorageMap, ValueQuery, *},
         sp_std::vec,
-        traits::{Currency, UnfilteredDispatchable},
+        traits::{Currency, FindAuthor, UnfilteredDispatchable},
     };
     use frame_system::pallet_prelude::*;
     use sp_runtime::traits::TrailingZeroInput;
@@ -88,6 +94,9 @@ pub mod pallet {
         // Because this pallet emits events, it depends on the runtime's definition of an event.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+        /// Weight information for the block step stages of this pallet.
+        type WeightInfo: crate::default_weights::WeightInfo;
+
         /// A sudo-able call.
         type SudoRuntimeCall: Parameter
             + UnfilteredDispatchable<RuntimeOrigin = Self::RuntimeOrigin>
@@ -103,6 +112,18 @@ pub mod pallet {
 
         type TriumvirateInterface: crate::CollectiveInterface<Self::AccountId, Self::Hash, u32>;
 
+        // --- Finds the author of the current block, used when transaction fees are paid to authors.
+        type FindAuthor: FindAuthor<Self::AccountId>;
+
+        // --- Reads the ERC20 Transfer logs the EVM recorded, used to keep the brain token balances in sync.
+        type TokenTransferLogs: crate::TokenTransferLogs<Self::AccountId>;
+
+        // --- Inspects the code and storage of EVM contracts, used when a brain owner binds its ERC20 contract.
+        type BrainTokenInspector: crate::BrainTokenInspector<Self::AccountId>;
+
+        #[pallet::constant] // Where the transaction fees charged on basednode calls are sent.
+        type TransactionFeeDestination: Get<TransactionFeeDestination<Self::AccountId>>;
+
         // =================================
         // ==== Initial Value Constants ====
         // =================================
@@ -184,6 +205,22 @@ pub mod pallet {
         type InitialNetworkRateLimit: Get<u64>;
         #[pallet::constant] // Initial network brain cut.
         type InitialBrainOwnerByTokenBalanceCut: Get<u128>;
+        #[pallet::constant] // Initial share of the token supply a holder needs to earn brain dividends.
+        type InitialTokenHolderThreshold: Get<u16>;
+        #[pallet::constant] // Initial delay in blocks before a delegate take increase applies.
+        type InitialDelegateTakeIncreaseDelay: Get<u64>;
+        #[pallet::constant] // Initial number of blocks removed stake stays unbonding.
+        type InitialUnbondingPeriod: Get<u64>;
+        #[pallet::constant] // Initial percentage of block emission distributed by root weights, the rest is split equally.
+        type InitialEmissionSplit: Get<u16>;
+        #[pallet::constant] // Initial number of blocks between block emission halvings.
+        type InitialHalvingInterval: Get<u64>;
+        #[pallet::constant] // Initial number of blocks a brain ownership proposal can be accepted for.
+        type InitialBrainOwnershipProposalExpiry: Get<u64>;
+        #[pallet::constant] // Initial number of blocks a hyperparameter change made by a brain owner waits before it applies.
+        type InitialHyperparamChangeDelay: Get<u64>;
+        #[pallet::constant] // Initial deposit a brain owner locks to publish brain metadata.
+        type InitialBrainMetadataDeposit: Get<u128>;
     }
 
     pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
@@ -206,7 +243,7 @@ pub mod pallet {
         T::InitialDefaultTake::get()
     }
     #[pallet::type_value]
-    pub fn DefaultAccountTake<T: Config>() -> u64 {
+    pub fn DefaultAccountTake<T: Config>() -> u128 {
         0
     }
     #[pallet::type_value]
@@ -225,9 +262,25 @@ pub mod pallet {
     pub fn DefaultAccount<T: Config>() -> T::AccountId {
         T::AccountId::decode(&mut TrailingZeroInput::zeroes()).unwrap()
     }
+    #[pallet::type_value]
+    pub fn DefaultDelegateTakeIncreaseDelay<T: Config>() -> u64 {
+        T::InitialDelegateTakeIncreaseDelay::get()
+    }
+    #[pallet::type_value]
+    pub fn DefaultUnbondingPeriod<T: Config>() -> u64 {
+        T::InitialUnbondingPeriod::get()
+    }
+    #[pallet::type_value]
+    pub fn DefaultTransactionFee<T: Config>() -> u128 {
+        0
+    }
+    #[pallet::type_value]
+    pub fn DefaultUnbonding<T: Config>() -> Vec<(T::AccountId, u128, u64)> {
+        vec![]
+    }
 
     #[pallet::storage] // --- ITEM ( total_stake )
-    pub type TotalStake<T> = StorageValue<_, u64, ValueQuery>;
+    pub type TotalStake<T> = StorageValue<_, u128, ValueQuery>;
     #[pallet::storage] // --- ITEM ( default_take )
     pub type DefaultTake<T> = StorageValue<_, u16, ValueQuery, DefaultDefaultTake<T>>;
     #[pallet::storage] // --- ITEM ( global_block_emission )
@@ -236,16 +289,25 @@ pub mod pallet {
     pub type TotalIssuance<T> = StorageValue<_, u128, ValueQuery, DefaultTotalIssuance<T>>;
     #[pallet::storage] // --- MAP ( hot ) --> stake | Returns the total amount of stake under a computekey.
     pub type TotalComputekeyStake<T: Config> =
-        StorageMap<_, Identity, T::AccountId, u64, ValueQuery, DefaultAccountTake<T>>;
+        StorageMap<_, Identity, T::AccountId, u128, ValueQuery, DefaultAccountTake<T>>;
     #[pallet::storage] // --- MAP ( cold ) --> stake | Returns the total amount of stake under a personalkey.
     pub type TotalPersonalkeyStake<T: Config> =
-        StorageMap<_, Identity, T::AccountId, u64, ValueQuery, DefaultAccountTake<T>>;
+        StorageMap<_, Identity, T::AccountId, u128, ValueQuery, DefaultAccountTake<T>>;
     #[pallet::storage] // --- MAP ( hot ) --> cold | Returns the controlling personalkey for a computekey.
     pub type Owner<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, ValueQuery, DefaultAccount<T>>;
     #[pallet::storage] // --- MAP ( hot ) --> take | Returns the computekey delegation take. And signals that this key is open for delegation.
     pub type Delegates<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, u16, ValueQuery, DefaultDefaultTake<T>>;
+    #[pallet::storage] // --- ITEM ( delegate_take_increase_delay )
+    pub type DelegateTakeIncreaseDelay<T> =
+        StorageValue<_, u64, ValueQuery, DefaultDelegateTakeIncreaseDelay<T>>;
+    #[pallet::storage] // --- MAP ( hot ) --> ( take, block ) | Returns the announced take increase for a delegate and the block it applies at.
+    pub type PendingDelegateTake<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (u16, u64), OptionQuery>;
+    #[pallet::storage] // --- MAP ( block ) --> Vec<hot> | Returns the delegates with a take increase announced to apply at the block.
+    pub type PendingDelegateTakeQueue<T: Config> =
+        StorageMap<_, Identity, u64, Vec<T::AccountId>, ValueQuery>;
     #[pallet::storage] // --- DMAP ( hot, cold ) --> stake | Returns the stake under a personalkey prefixed by computekey.
     pub type Stake<T: Config> = StorageDoubleMap<
         _,
@@ -253,10 +315,34 @@ pub mod pallet {
         T::AccountId,
         Identity,
         T::AccountId,
-        u64,
+        u128,
         ValueQuery,
         DefaultAccountTake<T>,
     >;
+    #[pallet::storage] // --- DMAP ( hot, cold ) --> reward_destination | Returns where emission earned by a position is paid. Unset means compounded as stake.
+    pub type RewardDestinations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Identity,
+        T::AccountId,
+        RewardDestination<T::AccountId>,
+        OptionQuery,
+    >;
+    #[pallet::storage] // --- MAP ( call_type ) --> fee | Returns the fee charged by the signed extension for a call type.
+    pub type TransactionFees<T> =
+        StorageMap<_, Identity, crate::CallType, u128, ValueQuery, DefaultTransactionFee<T>>;
+    #[pallet::storage] // --- ITEM ( unbonding_period )
+    pub type UnbondingPeriod<T> = StorageValue<_, u64, ValueQuery, DefaultUnbondingPeriod<T>>;
+    #[pallet::storage] // --- MAP ( cold ) --> Vec<( hot, amount, unlock_block )> | Returns the stake a personalkey is unbonding.
+    pub type Unbonding<T: Config> = StorageMap<
+        _,
+        Identity,
+        T::AccountId,
+        Vec<(T::AccountId, u128, u64)>,
+        ValueQuery,
+        DefaultUnbonding<T>,
+    >;
 
     // =====================================
     // ==== Difficulty / Registrations =====
@@ -402,6 +488,18 @@ pub mod pallet {
     pub fn DefaultBrainOwnerByTokenBalanceCut<T: Config>() -> u128 {
         T::InitialBrainOwnerByTokenBalanceCut::get()
     }
+    #[pallet::type_value]
+    pub fn DefaultTokenHolderThreshold<T: Config>() -> u16 {
+        T::InitialTokenHolderThreshold::get()
+    }
+    #[pallet::type_value]
+    pub fn DefaultMaxOwnerCut<T: Config>() -> u16 {
+        32_767 // 50 percent of the brain emission.
+    }
+    #[pallet::type_value]
+    pub fn DefaultMaxTokenHolderThreshold<T: Config>() -> u16 {
+        6_553 // 10% of the token supply.
+    }
 
     #[pallet::storage] // --- ITEM( total_number_of_existing_networks )
     pub type BrainLimit<T> = StorageValue<_, u16, ValueQuery, DefaultBrainLimit<T>>;
@@ -453,11 +551,28 @@ pub mod pallet {
         StorageValue<_, u64, ValueQuery, DefaultNetworkLockReductionInterval<T>>;
     #[pallet::storage] // ITEM( brain_owner_cut )
     pub type BrainOwnerCut<T> = StorageValue<_, u16, ValueQuery, DefaultBrainOwnerCut<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> owner_cut, falls back to the brain_owner_cut.
+    pub type OwnerCut<T> = StorageMap<_, Identity, u16, u16, OptionQuery>;
+    #[pallet::storage] // --- MAP ( netuid ) --> share of the token supply a holder needs to earn dividends.
+    pub type TokenHolderThreshold<T> =
+        StorageMap<_, Identity, u16, u16, ValueQuery, DefaultTokenHolderThreshold<T>>;
+    #[pallet::storage] // ITEM( max_owner_cut ) a brain owner can set.
+    pub type MaxOwnerCut<T> = StorageValue<_, u16, ValueQuery, DefaultMaxOwnerCut<T>>;
+    #[pallet::storage] // ITEM( max_token_holder_threshold ) a brain owner can set.
+    pub type MaxTokenHolderThreshold<T> =
+        StorageValue<_, u16, ValueQuery, DefaultMaxTokenHolderThreshold<T>>;
 
     #[pallet::storage] // Map from brain id to ERC20 token address
     pub type BrainContract<T: Config> =
         StorageMap<_, Identity, u16, T::AccountId, ValueQuery, DefaultBrainOwner<T>>;
+    #[pallet::storage] // --- MAP ( contract ) --> brain the ERC20 contract is bound to.
+    pub type ContractBrain<T: Config> =
+        StorageMap<_, Identity, T::AccountId, u16, OptionQuery>;
+
 
+    #[pallet::storage] // --- MAP ( contract ) --> account that deployed the contract, recorded by the EVM on create.
+    pub type ContractDeployer<T: Config> =
+        StorageMap<_, Identity, T::AccountId, T::AccountId, OptionQuery>;
 
     #[pallet::storage]
     pub type BrainOwnerByTokenBalanceCut<T: Config> = StorageDoubleMap<
@@ -468,6 +583,37 @@ pub mod pallet {
         Value = u128,
         QueryKind = ValueQuery
     >;
+    #[pallet::storage] // --- MAP ( contract ) --> token supply, tracked from mints and burns of the contract.
+    pub type BrainTokenSupply<T: Config> =
+        StorageMap<_, Identity, T::AccountId, u128, ValueQuery>;
+    #[pallet::storage] // --- ITEM ( contract, from, to, value ) of the Transfer logs of bound contracts the EVM emitted this block.
+    pub type PendingTokenTransfers<T: Config> =
+        StorageValue<_, Vec<(T::AccountId, T::AccountId, T::AccountId, sp_core::U256)>, ValueQuery>;
+    #[pallet::storage] // --- MAP ( position ) --> ( contract, from, to, value ) of a Transfer log waiting to be applied.
+    pub type TokenTransferQueue<T: Config> =
+        StorageMap<_, Identity, u64, (T::AccountId, T::AccountId, T::AccountId, sp_core::U256), OptionQuery>;
+    #[pallet::storage] // ITEM( position ) of the next queued Transfer log to apply.
+    pub type TokenTransferQueueHead<T> = StorageValue<_, u64, ValueQuery>;
+    #[pallet::storage] // ITEM( position ) the next Transfer log is queued at.
+    pub type TokenTransferQueueTail<T> = StorageValue<_, u64, ValueQuery>;
+    #[pallet::storage] // --- MAP ( contract ) --> block a Transfer log of the contract could not be applied at.
+    pub type BrainContractDesynced<T: Config> =
+        StorageMap<_, Identity, T::AccountId, u64, OptionQuery>;
+    #[pallet::storage] // --- MAP ( contract ) --> holder cut accrued per token, scaled by BRAIN_DIVIDEND_PRECISION.
+    pub type BrainDividendsPerToken<T: Config> =
+        StorageMap<_, Identity, T::AccountId, sp_core::U256, ValueQuery>;
+    #[pallet::storage] // --- MAP ( contract ) --> sum of the holder balances that earn dividends.
+    pub type BrainEligibleTokenSupply<T: Config> =
+        StorageMap<_, Identity, T::AccountId, u128, ValueQuery>;
+    #[pallet::storage] // --- DMAP ( contract, holder ) --> holder balance earning dividends, as of its last settlement.
+    pub type BrainDividendShares<T: Config> =
+        StorageDoubleMap<_, Identity, T::AccountId, Identity, T::AccountId, u128, ValueQuery>;
+    #[pallet::storage] // --- DMAP ( contract, holder ) --> dividends already accounted for the holder balance.
+    pub type BrainDividendDebt<T: Config> =
+        StorageDoubleMap<_, Identity, T::AccountId, Identity, T::AccountId, u128, ValueQuery>;
+    #[pallet::storage] // --- DMAP ( contract, holder ) --> dividends settled to the holder and not yet claimed.
+    pub type PendingBrainDividends<T: Config> =
+        StorageDoubleMap<_, Identity, T::AccountId, Identity, T::AccountId, u128, ValueQuery>;
 
     #[pallet::storage] // ITEM( network_rate_limit )
     pub type NetworkRateLimit<T> = StorageValue<_, u64, ValueQuery, DefaultNetworkRateLimit<T>>;
@@ -476,11 +622,11 @@ pub mod pallet {
     // ==== Brain Features =====
     // ==============================
     #[pallet::type_value]
-    pub fn DefaultEmissionValues<T: Config>() -> u64 {
+    pub fn DefaultEmissionValues<T: Config>() -> u128 {
         0
     }
     #[pallet::type_value]
-    pub fn DefaultPendingEmission<T: Config>() -> u64 {
+    pub fn DefaultPendingEmission<T: Config>() -> u128 {
         0
     }
     #[pallet::type_value]
@@ -500,18 +646,44 @@ pub mod pallet {
         0
     }
     #[pallet::type_value]
+    pub fn DefaultEmissionSplit<T: Config>() -> u16 {
+        T::InitialEmissionSplit::get()
+    }
+    #[pallet::type_value]
+    pub fn DefaultHalvingInterval<T: Config>() -> u64 {
+        T::InitialHalvingInterval::get()
+    }
+    #[pallet::type_value]
+    pub fn DefaultBrainOwnershipProposalExpiry<T: Config>() -> u64 {
+        T::InitialBrainOwnershipProposalExpiry::get()
+    }
+    #[pallet::type_value]
+    pub fn DefaultHyperparamChangeDelay<T: Config>() -> u64 {
+        T::InitialHyperparamChangeDelay::get()
+    }
+    #[pallet::type_value]
+    pub fn DefaultBrainMetadataDeposit<T: Config>() -> u128 {
+        T::InitialBrainMetadataDeposit::get()
+    }
+    #[pallet::type_value]
     pub fn DefaultTempo<T: Config>() -> u16 {
         T::InitialTempo::get()
     }
 
+    #[pallet::storage] // --- ITEM ( emission_split )
+    pub type EmissionSplit<T> = StorageValue<_, u16, ValueQuery, DefaultEmissionSplit<T>>;
+    #[pallet::storage] // --- ITEM ( halving_interval )
+    pub type HalvingInterval<T> = StorageValue<_, u64, ValueQuery, DefaultHalvingInterval<T>>;
+    #[pallet::storage] // --- ITEM ( block the halving interval was last set at, halvings before that block )
+    pub type HalvingAnchor<T> = StorageValue<_, (u64, u64), ValueQuery>;
     #[pallet::storage] // --- MAP ( netuid ) --> tempo
     pub type Tempo<T> = StorageMap<_, Identity, u16, u16, ValueQuery, DefaultTempo<T>>;
     #[pallet::storage] // --- MAP ( netuid ) --> emission_values
     pub type EmissionValues<T> =
-        StorageMap<_, Identity, u16, u64, ValueQuery, DefaultEmissionValues<T>>;
+        StorageMap<_, Identity, u16, u128, ValueQuery, DefaultEmissionValues<T>>;
     #[pallet::storage] // --- MAP ( netuid ) --> pending_emission
     pub type PendingEmission<T> =
-        StorageMap<_, Identity, u16, u64, ValueQuery, DefaultPendingEmission<T>>;
+        StorageMap<_, Identity, u16, u128, ValueQuery, DefaultPendingEmission<T>>;
     #[pallet::storage] // --- MAP ( netuid ) --> blocks_since_last_step.
     pub type BlocksSinceLastStep<T> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultBlocksSinceLastStep<T>>;
@@ -524,6 +696,32 @@ pub mod pallet {
     #[pallet::storage]
     pub type BrainLocked<T: Config> =
         StorageMap<_, Identity, u16, u128, ValueQuery, DefaultBrainLocked<T>>;
+    #[pallet::storage] // --- ITEM ( brain_ownership_proposal_expiry )
+    pub type BrainOwnershipProposalExpiry<T> =
+        StorageValue<_, u64, ValueQuery, DefaultBrainOwnershipProposalExpiry<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> ( proposed_owner, expiry_block ) | Returns the pending ownership transfer of a brain.
+    pub type PendingBrainOwner<T: Config> =
+        StorageMap<_, Identity, u16, (T::AccountId, u64), OptionQuery>;
+    #[pallet::storage] // --- DMAP ( netuid, account ) --> Vec<role> | Returns the admin roles the brain owner granted to an account.
+    pub type BrainRoles<T: Config> =
+        StorageDoubleMap<_, Identity, u16, Blake2_128Concat, T::AccountId, Vec<crate::BrainRole>, ValueQuery>;
+    #[pallet::storage] // --- ITEM ( hyperparam_change_delay )
+    pub type HyperparamChangeDelay<T> =
+        StorageValue<_, u64, ValueQuery, DefaultHyperparamChangeDelay<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> Vec<( effective_block, change )> | Returns the hyperparameter changes queued on a brain.
+    pub type PendingHyperparamChanges<T> =
+        StorageMap<_, Identity, u16, Vec<(u64, HyperparamChange)>, ValueQuery>;
+    #[pallet::storage] // --- MAP ( block ) --> Vec<netuid> | Returns the brains with a hyperparameter change queued to apply at the block.
+    pub type HyperparamChangeQueue<T> = StorageMap<_, Identity, u64, Vec<u16>, ValueQuery>;
+    #[pallet::storage] // --- ITEM ( brain_metadata_deposit )
+    pub type BrainMetadataDeposit<T> =
+        StorageValue<_, u128, ValueQuery, DefaultBrainMetadataDeposit<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> ( depositor, deposit, metadata ) | Returns the metadata of a brain and the deposit locked for it.
+    pub type BrainMetadataOf<T: Config> =
+        StorageMap<_, Identity, u16, (T::AccountId, u128, BrainMetadata), OptionQuery>;
+    #[pallet::storage] // --- MAP ( block ) --> Vec<emission_ledger_entry> | Returns where the emission of each brain went in a block, kept for EMISSION_LEDGER_LENGTH blocks.
+    pub type EmissionLedger<T> =
+        StorageMap<_, Identity, u64, Vec<EmissionLedgerEntry>, ValueQuery>;
 
     // =================================
     // ==== Brainport / Promo Endpoints =====
@@ -544,6 +742,88 @@ pub mod pallet {
         pub placeholder2: u8, // --- Brainport proto placeholder 1.
     }
 
+    // --- Struct for the emission ledger, one per brain and block.
+    #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct EmissionLedgerEntry {
+        pub netuid: u16,      // --- Brain the entry is for.
+        pub queued: u128,     // --- Emission due to the brain this block.
+        pub owner_cut: u128,  // --- Paid to the brain owner.
+        pub holder_cut: u128, // --- Accrued to the brain ERC20 token holders, paid out when they claim.
+        pub burned: u128,     // --- Not minted at all, e.g. root emission.
+        pub drained: u128,    // --- Paid out of the loaded emission tuples onto computekeys.
+    }
+
+    // --- Enum for the brain hyperparameter changes a brain owner queues, with the new value.
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub enum HyperparamChange {
+        ServingRateLimit(u64),
+        MinDifficulty(u64),
+        MaxDifficulty(u64),
+        WeightsVersionKey(u64),
+        WeightsSetRateLimit(u64),
+        MaxWeightLimit(u16),
+        ImmunityPeriod(u16),
+        MinAllowedWeights(u16),
+        ActivityCutoff(u16),
+        MaxAllowedValidators(u16),
+        Tempo(u16),
+        MinBurn(u128),
+        MaxBurn(u128),
+        CommitRevealWeightsEnabled(bool),
+        OwnerCut(u16),
+        TokenHolderThreshold(u16),
+    }
+
+    // --- Enum for the kind of data a brain works on, stored as NetworkModality.
+    #[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum BrainModality {
+        Text,
+        Image,
+        Audio,
+        Video,
+        Multimodal,
+        Other,
+    }
+
+    impl Default for BrainModality {
+        fn default() -> Self {
+            BrainModality::Text
+        }
+    }
+
+    // --- Struct for the metadata a brain owner publishes, bounded like the pallet_registry IdentityInfo.
+    #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct BrainMetadata {
+        pub name: BoundedVec<u8, ConstU32<64>>,          // --- Display name, UTF-8.
+        pub description: BoundedVec<u8, ConstU32<256>>, // --- Description of what the brain does, UTF-8.
+        pub repository: BoundedVec<u8, ConstU32<128>>,   // --- URL of the source repository.
+        pub logo_hash: Option<[u8; 32]>,                 // --- Hash of the logo image, fetched off chain.
+        pub modality: BrainModality,                     // --- Kind of data the brain works on.
+        pub links: BoundedVec<BoundedVec<u8, ConstU32<128>>, ConstU32<4>>, // --- Other URLs such as the website or docs.
+    }
+
+    // --- Enum for the destination of emission earned by a ( personalkey, computekey ) position.
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub enum RewardDestination<AccountId> {
+        Stake,              // --- Compound the reward onto the position stake.
+        Balance,            // --- Pay the reward to the personalkey free balance.
+        Account(AccountId), // --- Pay the reward to the free balance of another account.
+    }
+
+    impl<AccountId> Default for RewardDestination<AccountId> {
+        fn default() -> Self {
+            RewardDestination::Stake
+        }
+    }
+
+    // --- Enum for where the transaction fees charged by the BasednodeSignedExtension are sent.
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub enum TransactionFeeDestination<AccountId> {
+        Author,              // --- Pay the fee to the author of the block.
+        Burn,                // --- Burn the fee.
+        Treasury(AccountId), // --- Pay the fee to the treasury account.
+    }
+
     // --- Struct for Prometheus.
     pub type PrometheusInfoOf = PrometheusInfo;
     #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
@@ -601,6 +881,10 @@ pub mod pallet {
         100
     }
     #[pallet::type_value]
+    pub fn DefaultCommitRevealWeightsEnabled<T: Config>() -> bool {
+        false
+    }
+    #[pallet::type_value]
     pub fn DefaultBlockAtRegistration<T: Config>() -> u64 {
         0
     }
@@ -709,6 +993,15 @@ pub mod pallet {
     #[pallet::storage] // --- MAP ( netuid ) --> weights_set_rate_limit
     pub type WeightsSetRateLimit<T> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultWeightsSetRateLimit<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> commit_reveal_weights_enabled
+    pub type CommitRevealWeightsEnabled<T> =
+        StorageMap<_, Identity, u16, bool, ValueQuery, DefaultCommitRevealWeightsEnabled<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> consensus_mechanism | Returns the consensus mechanism the epoch of the brain runs.
+    pub type BrainConsensusMechanism<T> =
+        StorageMap<_, Identity, u16, crate::ConsensusMechanismType, ValueQuery>;
+    #[pallet::storage] // --- DMAP ( netuid, computekey ) --> ( commit_hash, commit_block ) | Returns the weights a computekey committed to and has not revealed yet.
+    pub type WeightCommits<T: Config> =
+        StorageDoubleMap<_, Identity, u16, Blake2_128Concat, T::AccountId, (T::Hash, u64), OptionQuery>;
     #[pallet::storage] // --- MAP ( netuid ) --> validator_prune_len
     pub type ValidatorPruneLen<T> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultValidatorPruneLen<T>>;
@@ -745,6 +1038,10 @@ pub mod pallet {
         vec![]
     }
     #[pallet::type_value]
+    pub fn EmptyU128Vec<T: Config>() -> Vec<u128> {
+        vec![]
+    }
+    #[pallet::type_value]
     pub fn EmptyBoolVec<T: Config>() -> Vec<bool> {
         vec![]
     }
@@ -767,9 +1064,15 @@ pub mod pallet {
     #[pallet::storage] // --- DMAP ( netuid, uid ) --> computekey
     pub(super) type Keys<T: Config> =
         StorageDoubleMap<_, Identity, u16, Identity, u16, T::AccountId, ValueQuery, DefaultKey<T>>;
-    #[pallet::storage] // --- DMAP ( netuid ) --> (computekey, se, ve)
-    pub(super) type LoadedEmission<T: Config> =
-        StorageMap<_, Identity, u16, Vec<(T::AccountId, u64, u64)>, OptionQuery>;
+    #[pallet::storage] // --- DMAP ( netuid, position ) --> (computekey, se, ve) | Loaded emission tuples, drained one at a time.
+    pub(super) type LoadedEmissionTuples<T: Config> =
+        StorageDoubleMap<_, Identity, u16, Identity, u32, (T::AccountId, u128, u128), OptionQuery>;
+    #[pallet::storage] // --- MAP ( netuid ) --> length | Returns the position after the last loaded emission tuple.
+    pub(super) type LoadedEmissionLength<T: Config> =
+        StorageMap<_, Identity, u16, u32, OptionQuery>;
+    #[pallet::storage] // --- MAP ( netuid ) --> cursor | Returns the index of the next loaded emission tuple to drain.
+    pub(super) type LoadedEmissionCursor<T: Config> =
+        StorageMap<_, Identity, u16, u32, ValueQuery>;
 
     #[pallet::storage] // --- DMAP ( netuid ) --> active
     pub(super) type Active<T: Config> =
@@ -791,7 +1094,7 @@ pub mod pallet {
         StorageMap<_, Identity, u16, Vec<u16>, ValueQuery, EmptyU16Vec<T>>;
     #[pallet::storage] // --- DMAP ( netuid ) --> emission
     pub(super) type Emission<T: Config> =
-        StorageMap<_, Identity, u16, Vec<u64>, ValueQuery, EmptyU64Vec<T>>;
+        StorageMap<_, Identity, u16, Vec<u128>, ValueQuery, EmptyU128Vec<T>>;
     #[pallet::storage] // --- DMAP ( netuid ) --> last_update
     pub(super) type LastUpdate<T: Config> =
         StorageMap<_, Identity, u16, Vec<u64>, ValueQuery, EmptyU64Vec<T>>;
@@ -838,9 +1141,20 @@ pub mod pallet {
         NetworkAdded(u16, u16), // --- Event created when a new network is added.
         NetworkCreateUpdate(u16, T::AccountId),
         NetworkRemoved(u16),    // --- Event created when a network is removed.
-        StakeAdded(T::AccountId, u64), // --- Event created when stake has been transfered from the a personalkey account onto the computekey staking account.
-        StakeRemoved(T::AccountId, u64), // --- Event created when stake has been removed from the computekey staking account onto the personalkey account.
+        StakeAdded(T::AccountId, u128), // --- Event created when stake has been transfered from the a personalkey account onto the computekey staking account.
+        StakeRemoved(T::AccountId, u128), // --- Event created when stake has been removed from the computekey staking account onto the personalkey account.
+        StakeWithdrawn(T::AccountId, u128), // --- Event created when unbonded stake has been released onto the personalkey account.
+        StakeMoved(T::AccountId, T::AccountId, T::AccountId, u128), // --- Event created when a personalkey moves stake from one computekey to another.
+        RewardDestinationSet(T::AccountId, T::AccountId, RewardDestination<T::AccountId>), // --- Event created when a personalkey sets the reward destination of its stake on a computekey.
+        TransactionFeeSet(crate::CallType, u128), // --- Event created when the transaction fee of a call type is set.
+        EmissionSplitSet(u16), // --- Event created when the percentage of block emission distributed by root weights is set.
+        HalvingIntervalSet(u64), // --- Event created when the number of blocks between emission halvings is set.
         WeightsSet(u16, u16), // ---- Event created when a caller successfully sets their weights on a brain.
+        WeightsCommitted(u16, T::AccountId), // ---- Event created when a computekey commits to the weights it will reveal on a brain.
+        BatchWeightsEntryFailed(u16, u16, DispatchError), // --- Event created when an entry of batch_set_weights is rejected, with its index, netuid and error.
+        BatchWeightsCompleted(u16, u16), // --- Event created when batch_set_weights has run every entry, with the number set and rejected.
+        CommitRevealWeightsEnabledSet(u16, bool), // --- Event created when commit-reveal weight setting is turned on or off for a brain.
+        ConsensusMechanismSet(u16, crate::ConsensusMechanismType), // --- Event created when the consensus mechanism of a brain is set.
         AgentRegistered(u16, u16, T::AccountId), // --- Event created when a new agent account has been registered to the chain.
         BulkAgentsRegistered(u16, u16), // --- Event created when multiple uids have been concurrently registered.
         BulkBalancesSet(u16, u16),       // --- FIXME: Not used yet
@@ -865,6 +1179,11 @@ pub mod pallet {
         EmissionValuesSet(), // --- Event created when emission ratios for all networks is set.
         DelegateAdded(T::AccountId, T::AccountId, u16), // --- Event created to signal that a computekey has become a delegate.
         DefaultTakeSet(u16), // --- Event created when the default take is set.
+        DelegateTakeDecreased(T::AccountId, T::AccountId, u16), // --- Event created when a delegate lowers its take.
+        DelegateTakeIncreaseAnnounced(T::AccountId, T::AccountId, u16, u64), // --- Event created when a delegate announces a take increase which applies at the given block.
+        DelegateTakeIncreased(T::AccountId, u16), // --- Event created when an announced take increase is applied to a delegate.
+        DelegateTakeIncreaseDelaySet(u64), // --- Event created when the delegate take increase delay is set.
+        UnbondingPeriodSet(u64), // --- Event created when the unbonding period is set.
         WeightsVersionKeySet(u16, u64), // --- Event created when weights version key is set for a network.
         MinDifficultySet(u16, u64), // --- Event created when setting min difficutly on a network.
         MaxDifficultySet(u16, u64), // --- Event created when setting max difficutly on a network.
@@ -889,6 +1208,23 @@ pub mod pallet {
         NetworkLockCostReductionIntervalSet(u64), // Event created when the lock cost reduction is set
         BrainOwnerContractCreated(u16, T::AccountId),
 		BrainContractBalanceChanged(T::AccountId, T::AccountId, T::AccountId, u128),
+        BrainContractDesynced(u16, T::AccountId), // --- Event created when a Transfer log of a brain contract could not be applied to its tracked balances.
+        BrainDividendsClaimed(T::AccountId, T::AccountId, u128), // --- Event created when a token holder claims its brain dividends.
+        OwnerCutSet(u16, u16), // --- Event created when the owner cut of a brain is set.
+        TokenHolderThresholdSet(u16, u16), // --- Event created when the dividend holding threshold of a brain is set.
+        MaxOwnerCutSet(u16), // --- Event created when the highest owner cut a brain owner can set is set.
+        MaxTokenHolderThresholdSet(u16), // --- Event created when the highest dividend holding threshold a brain owner can set is set.
+        BrainOwnershipProposed(u16, T::AccountId, T::AccountId, u64), // --- Event created when a brain owner proposes a new owner, who can accept until the given block.
+        BrainOwnershipTransferred(u16, T::AccountId, T::AccountId), // --- Event created when the proposed owner accepts the ownership of a brain.
+        BrainOwnershipProposalExpirySet(u64), // --- Event created when the number of blocks an ownership proposal stays valid is set.
+        BrainRoleGranted(u16, T::AccountId, crate::BrainRole), // --- Event created when a brain owner grants an admin role to an account.
+        BrainRoleRevoked(u16, T::AccountId, crate::BrainRole), // --- Event created when a brain owner revokes an admin role from an account.
+        HyperparamChangeScheduled(u16, HyperparamChange, u64), // --- Event created when a brain hyperparameter change is queued to apply at the given block.
+        HyperparamChangeApplied(u16, HyperparamChange), // --- Event created when a queued brain hyperparameter change is applied.
+        HyperparamChangeDelaySet(u64), // --- Event created when the delay of queued hyperparameter changes is set.
+        BrainMetadataSet(u16, T::AccountId), // --- Event created when a brain owner publishes the metadata of a brain.
+        BrainMetadataCleared(u16), // --- Event created when the metadata of a brain is removed and its deposit returned.
+        BrainMetadataDepositSet(u128), // --- Event created when the deposit locked for brain metadata is set.
         ComputekeySwapped{personalkey: T::AccountId, old_computekey: T::AccountId, new_computekey: T::AccountId} // Event created when a computekey is swapped
     }
 
@@ -917,7 +1253,7 @@ pub mod pallet {
         InvalidDifficulty, // ---- Thrown if the supplied pow hash block does not meet the network difficulty.
         InvalidSeal, // ---- Thrown if the supplied pow hash seal does not match the supplied work.
         MaxAllowedUIdsNotAllowed, // ---  Thrown if the vaule is invalid for MaxAllowedUids.
-        CouldNotConvertToBalance, // ---- Thrown when the dispatch attempts to convert between a u64 and T::balance but the call fails.
+        CouldNotConvertToBalance, // ---- Thrown when the dispatch attempts to convert between a u128 and T::balance but the call fails.
         StakeAlreadyAdded, // --- Thrown when the caller requests adding stake for a computekey to the total stake which already added.
         MaxWeightExceeded, // --- Thrown when the dispatch attempts to set weights on chain with where any normalized weight is more than MaxWeightLimit.
         StorageValueOutOfRange, // --- Thrown when the caller attempts to set a storage value outside of its allowed range.
@@ -926,6 +1262,8 @@ pub mod pallet {
         EmissionValuesDoesNotMatchNetworks, // --- Thrown when number or recieved emission rates does not match number of networks.
         InvalidEmissionValues, // --- Thrown when emission ratios are not valid (did not sum up to 10^9).
         AlreadyDelegate, // --- Thrown if the computekey attempts to become delegate when they are already.
+        DelegateTakeTooLow, // --- Thrown if a delegate take decrease is not below the current take.
+        DelegateTakeTooHigh, // --- Thrown if a delegate take increase is not above the current take or exceeds the default take.
         SettingWeightsTooFast, // --- Thrown if the computekey attempts to set weights twice within net_tempo/2 blocks.
         IncorrectNetworkVersionKey, // --- Thrown when a validator attempts to set weights from a validator with incorrect code base key.
         ServingRateLimitExceeded, // --- Thrown when an brainport or prometheus serving exceeds the rate limit for a registered agent.
@@ -951,6 +1289,31 @@ pub mod pallet {
         StakeTooLowForRoot, // --- Thrown when a computekey attempts to join the root brain with too little stake
         AllNetworksInImmunity, // --- Thrown when all brains are in the immunity period
         NotEnoughBalance,
+        NoUnbondedStake, // --- Thrown when a personalkey has no unlocked unbonding stake to withdraw.
+        TooManyUnbondingChunks, // --- Thrown when a personalkey removes stake with the most unbonding chunks already queued.
+        SameComputekey, // --- Thrown when stake is moved onto the computekey it is already on.
+        NoBrainDividendsToClaim, // --- Thrown when a token holder has no brain dividends to claim.
+        BrainTokenBalanceUnderflow, // --- Thrown when a brain token transfer moves more than the sender or the supply holds.
+        BrainTokenBalanceOverflow, // --- Thrown when a brain token transfer overflows the receiver balance or the supply.
+        NotContractDeployer, // --- Thrown when a contract is bound by an account that did not deploy it.
+        NotErc20Contract, // --- Thrown when the bound contract code does not implement the ERC20 interface.
+        BrainContractAlreadyBound, // --- Thrown when the brain or the contract is already bound.
+        BrainTokenStorageMismatch, // --- Thrown when the supply or the owner balance read from the contract storage is not a valid ERC20 state.
+        AlreadyBrainOwner, // --- Thrown when a brain owner proposes itself as the new owner.
+        NoBrainOwnershipProposal, // --- Thrown when the caller is not the proposed owner of the brain.
+        BrainOwnershipProposalExpired, // --- Thrown when the ownership proposal is accepted after it expired.
+        BrainRoleAlreadyGranted, // --- Thrown when an account is granted a brain role it already has.
+        BrainRoleNotGranted, // --- Thrown when a brain role is revoked from an account that does not have it.
+        NotEnoughBalanceForDeposit, // --- Thrown when the caller cannot pay the deposit for brain metadata.
+        NoBrainMetadata, // --- Thrown when metadata is cleared from a brain that has none.
+        CommitRevealEnabled, // --- Thrown when weights are set in plain text on a brain in commit-reveal mode.
+        CommitRevealDisabled, // --- Thrown when weights are committed or revealed on a brain not in commit-reveal mode.
+        NoWeightsCommit, // --- Thrown when a computekey reveals weights it did not commit to.
+        InvalidWeightsReveal, // --- Thrown when the revealed weights do not hash to the commit.
+        WeightsRevealTooEarly, // --- Thrown when weights are revealed in the epoch they were committed.
+        WeightsCommitExpired, // --- Thrown when weights are revealed after the epoch following the commit.
+        TooManyBatchEntries, // --- Thrown when batch_set_weights has more entries than there are brains.
+        DuplicateBatchEntry, // --- Thrown when batch_set_weights has two entries for the same brain.
     }
 
     // ==================
@@ -960,7 +1323,7 @@ pub mod pallet {
     #[pallet::genesis_config]
     #[cfg(feature = "std")]
     pub struct GenesisConfig<T: Config> {
-        pub stakes: Vec<(T::AccountId, Vec<(T::AccountId, (u64, u16))>)>,
+        pub stakes: Vec<(T::AccountId, Vec<(T::AccountId, (u128, u16))>)>,
         pub balances_issuance: u128,
     }
 
@@ -1083,9 +1446,7 @@ pub mod pallet {
                     );
 
                     // Update total issuance value
-					let s = *stake;
-					let stake_as_u128: u128 = (*stake).into();
-                    TotalIssuance::<T>::put(TotalIssuance::<T>::get().saturating_add(stake_as_u128));
+                    TotalIssuance::<T>::put(TotalIssuance::<T>::get().saturating_add(*stake));
 
                     Stake::<T>::insert(computekey.clone(), personalkey.clone(), stake);
 
@@ -1148,15 +1509,13 @@ pub mod pallet {
         fn on_initialize(_block_number: BlockNumberFor<T>) -> Weight {
             let block_step_result = Self::block_step();
             match block_step_result {
-                Ok(_) => {
-                    // --- If the block step was successful, return the weight.
+                Ok(block_step_weight) => {
+                    // --- If the block step was successful, return the weight of the stages it ran.
                     log::info!("Successfully ran block step.");
-                    return Weight::from_ref_time(110_634_229_000 as u64)
-                        .saturating_add(T::DbWeight::get().reads(8304 as u64))
-                        .saturating_add(T::DbWeight::get().writes(110 as u64));
+                    return block_step_weight;
                 }
                 Err(e) => {
-                    // --- If the block step was unsuccessful, return the weight anyway.
+                    // --- If the block step was unsuccessful, charge the worst case as the stages that ran are unknown.
                     log::error!("Error while stepping block: {:?}", e);
                     return Weight::from_ref_time(110_634_229_000 as u64)
                         .saturating_add(T::DbWeight::get().reads(8304 as u64))
@@ -1178,7 +1537,10 @@ pub mod pallet {
                 .saturating_add(migration::migrate_create_root_network::<T>())
                 .saturating_add(migration::migrate_transfer_ownership_to_foundation::<T>(hex))
                 .saturating_add(migration::migrate_delete_brain_3::<T>())
-                .saturating_add(migration::migrate_delete_brain_21::<T>());
+                .saturating_add(migration::migrate_delete_brain_21::<T>())
+                .saturating_add(migration::migrate_to_v6_u128_stake::<T>())
+                .saturating_add(migration::migrate_to_v7_seed_brain_token_supply::<T>())
+                .saturating_add(migration::migrate_to_v8_loaded_emission_tuples::<T>());
 
             return weight;
         }
@@ -1291,6 +1653,79 @@ pub mod pallet {
             Self::do_become_delegate(origin, computekey, Self::get_default_take())
         }
 
+        // --- Lowers the take of a delegate. The new take applies immediately and
+        // cancels any announced take increase.
+        //
+        // # Args:
+        //  * 'origin': (<T as frame_system::Config>Origin):
+        //      - The signature of the caller's personalkey.
+        //
+        //  * 'computekey' (T::AccountId):
+        //      - The delegate computekey (must be owned by the personalkey.)
+        //
+        //  * 'take' (u16):
+        //      - The new stake proportion that this computekey takes from delegations.
+        //
+        // # Event:
+        //  * DelegateTakeDecreased;
+        //      - On successfully lowering the delegate take.
+        //
+        // # Raises:
+        //  * 'NotDelegate':
+        //      - The computekey is not a delegate.
+        //
+        //  * 'NonAssociatedpersonalkey':
+        //      - The computekey is not owned by the calling personalkey.
+        //
+        //  * 'DelegateTakeTooLow':
+        //      - The new take is not below the current take.
+        //
+        #[pallet::call_index(66)]
+        #[pallet::weight((Weight::from_ref_time(20_000_000)
+        .saturating_add(T::DbWeight::get().reads(4))
+        .saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::No))]
+        pub fn decrease_take(origin: OriginFor<T>, computekey: T::AccountId, take: u16) -> DispatchResult {
+            Self::do_decrease_take(origin, computekey, take)
+        }
+
+        // --- Announces a take increase for a delegate. The new take is stored as pending
+        // and only applies once the delegate take increase delay has passed.
+        //
+        // # Args:
+        //  * 'origin': (<T as frame_system::Config>Origin):
+        //      - The signature of the caller's personalkey.
+        //
+        //  * 'computekey' (T::AccountId):
+        //      - The delegate computekey (must be owned by the personalkey.)
+        //
+        //  * 'take' (u16):
+        //      - The new stake proportion that this computekey takes from delegations.
+        //
+        // # Event:
+        //  * DelegateTakeIncreaseAnnounced;
+        //      - On successfully announcing the take increase.
+        //
+        // # Raises:
+        //  * 'NotDelegate':
+        //      - The computekey is not a delegate.
+        //
+        //  * 'NonAssociatedpersonalkey':
+        //      - The computekey is not owned by the calling personalkey.
+        //
+        //  * 'DelegateTakeTooHigh':
+        //      - The new take is not above the current take or exceeds the default take.
+        //
+        //  * 'TxRateLimitExceeded':
+        //      - Thrown if key has hit transaction rate limit
+        //
+        #[pallet::call_index(67)]
+        #[pallet::weight((Weight::from_ref_time(20_000_000)
+        .saturating_add(T::DbWeight::get().reads(5))
+        .saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::No))]
+        pub fn increase_take(origin: OriginFor<T>, computekey: T::AccountId, take: u16) -> DispatchResult {
+            Self::do_increase_take(origin, computekey, take)
+        }
+
         // --- Adds stake to a computekey. The call is made from the
         // personalkey account linked in the computekey.
         // Only the associated personalkey is allowed to make staking and
@@ -1304,7 +1739,7 @@ pub mod pallet {
         //  * 'computekey' (T::AccountId):
         //      - The associated computekey account.
         //
-        //  * 'amount_staked' (u64):
+        //  * 'amount_staked' (u128):
         //      - The amount of stake to be added to the computekey staking account.
         //
         // # Event:
@@ -1332,7 +1767,7 @@ pub mod pallet {
         pub fn add_stake(
             origin: OriginFor<T>,
             computekey: T::AccountId,
-            amount_staked: u64,
+            amount_staked: u128,
         ) -> DispatchResult {
             Self::do_add_stake(origin, computekey, amount_staked)
         }
@@ -1348,8 +1783,10 @@ pub mod pallet {
         //  * 'computekey' (T::AccountId):
         //      - The associated computekey account.
         //
-        //  * 'amount_unstaked' (u64):
-        //      - The amount of stake to be added to the computekey staking account.
+        //  * 'amount_unstaked' (u128):
+        //      - The amount of stake to be removed from the computekey staking account.
+        //      It is queued as unbonding and released by withdraw_unbonded once the
+        //      unbonding period has passed.
         //
         // # Event:
         //  * StakeRemoved;
@@ -1368,6 +1805,8 @@ pub mod pallet {
         //  * 'CouldNotConvertToBalance':
         //      - Thrown if we could not convert this amount to a balance.
         //
+        //  * 'TooManyUnbondingChunks':
+        //      - Thrown if the personalkey already has the most unbonding chunks queued.
         //
         #[pallet::call_index(3)]
         #[pallet::weight((Weight::from_ref_time(63_000_000)
@@ -1377,11 +1816,119 @@ pub mod pallet {
         pub fn remove_stake(
             origin: OriginFor<T>,
             computekey: T::AccountId,
-            amount_unstaked: u64,
+            amount_unstaked: u128,
         ) -> DispatchResult {
             Self::do_remove_stake(origin, computekey, amount_unstaked)
         }
 
+        // ---- Releases all unbonding stake of the calling personalkey whose unlock block
+        // has been reached onto the personalkey account.
+        //
+        // # Args:
+        //  * 'origin': (<T as frame_system::Config>Origin):
+        //      - The signature of the caller's personalkey.
+        //
+        // # Event:
+        //  * StakeWithdrawn;
+        //      - On successfully releasing the unbonded stake.
+        //
+        // # Raises:
+        //  * 'NoUnbondedStake':
+        //      - Thrown if none of the unbonding stake has unlocked yet.
+        //
+        //  * 'CouldNotConvertToBalance':
+        //      - Thrown if we could not convert this amount to a balance.
+        //
+        #[pallet::call_index(68)]
+        #[pallet::weight((Weight::from_ref_time(40_000_000)
+        .saturating_add(T::DbWeight::get().reads(3))
+        .saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::No))]
+        pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResult {
+            Self::do_withdraw_unbonded(origin)
+        }
+
+        // ---- Moves stake of the calling personalkey from one computekey to another in a
+        // single step. The stake never leaves the staking table, so the total stake is unchanged.
+        //
+        // # Args:
+        //  * 'origin': (<T as frame_system::Config>Origin):
+        //      - The signature of the caller's personalkey.
+        //
+        //  * 'origin_computekey' (T::AccountId):
+        //      - The computekey the stake is currently on.
+        //
+        //  * 'destination_computekey' (T::AccountId):
+        //      - The computekey the stake is moved onto.
+        //
+        //  * 'amount_moved' (u128):
+        //      - The amount of stake to move.
+        //
+        // # Event:
+        //  * StakeMoved;
+        //      - On successfully moving the stake.
+        //
+        // # Raises:
+        //  * 'NotRegistered':
+        //      - Thrown if either computekey account does not exist.
+        //
+        //  * 'SameComputekey':
+        //      - Thrown if the origin and destination computekey are the same.
+        //
+        //  * 'NonAssociatedpersonalkey':
+        //      - Thrown if the destination is neither a delegate nor owned by the personalkey.
+        //
+        //  * 'NotEnoughStaketoWithdraw':
+        //      - Thrown if there is not enough stake on the origin computekey to move this amount.
+        //
+        //  * 'TxRateLimitExceeded':
+        //      - Thrown if key has hit transaction rate limit
+        //
+        #[pallet::call_index(69)]
+        #[pallet::weight((Weight::from_ref_time(60_000_000)
+        .saturating_add(T::DbWeight::get().reads(10))
+        .saturating_add(T::DbWeight::get().writes(5)), DispatchClass::Normal, Pays::No))]
+        pub fn move_stake(
+            origin: OriginFor<T>,
+            origin_computekey: T::AccountId,
+            destination_computekey: T::AccountId,
+            amount_moved: u128,
+        ) -> DispatchResult {
+            Self::do_move_stake(origin, origin_computekey, destination_computekey, amount_moved)
+        }
+
+        // ---- Sets where the emission earned by the caller's stake on a computekey is paid:
+        // compounded as stake, paid to the personalkey free balance or paid to another account.
+        //
+        // # Args:
+        //  * 'origin': (<T as frame_system::Config>Origin):
+        //      - The signature of the caller's personalkey.
+        //
+        //  * 'computekey' (T::AccountId):
+        //      - The computekey the stake is on.
+        //
+        //  * 'destination' (RewardDestination<T::AccountId>):
+        //      - Where the rewards of this position are paid.
+        //
+        // # Event:
+        //  * RewardDestinationSet;
+        //      - On successfully setting the reward destination.
+        //
+        // # Raises:
+        //  * 'NotRegistered':
+        //      - Thrown if the computekey account does not exist.
+        //
+        #[pallet::call_index(71)]
+        #[pallet::weight((Weight::from_ref_time(20_000_000)
+        .saturating_add(T::DbWeight::get().reads(1))
+        .saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::No))]
+        pub fn set_reward_destination(
+            origin: OriginFor<T>,
+            computekey: T::AccountId,
+            destination: RewardDestination<T::AccountId>,
+        ) -> DispatchResult {
+            Self::do_set_reward_destination(origin, computekey, destination)
+        }
+
         // ---- Serves or updates brainport /promethteus information for the agent associated with the caller. If the caller is
         // already registered the metadata is updated. If the caller is not registered this call throws NotRegistered.
         //
@@ -1645,13 +2192,35 @@ pub mod pallet {
         }
 
 
-        // #[pallet::call_index(59)]
-        // #[pallet::weight((Weight::from_ref_time(85_000_000)
-        // .saturating_add(T::DbWeight::get().reads(16))
-        // .saturating_add(T::DbWeight::get().writes(28)), DispatchClass::Operational, Pays::No))]
-        // pub fn register_network(origin: OriginFor<T>) -> DispatchResult {
-        //     Self::user_add_network(origin)
-        // }
+        // ---- Registers a new brain owned by the caller, locking the network lock cost from its personalkey.
+        //
+        // # Args:
+        //  * 'origin': (<T as frame_system::Config>Origin):
+        //      - The personalkey of the new brain owner, must be signed.
+        //
+        // # Event:
+        //  * NetworkAdded;
+        //      - On successfully registering the brain.
+        //  * NetworkRemoved;
+        //      - When the brain limit is reached and a brain is pruned to make room.
+        //
+        // # Raises:
+        //  * 'TxRateLimitExceeded':
+        //      - Thrown if a brain was registered less than NetworkRateLimit blocks ago.
+        //
+        //  * 'NotEnoughBalanceToStake':
+        //      - Thrown if the personalkey can not pay the lock.
+        //
+        //  * 'AllNetworksInImmunity':
+        //      - Thrown if the brain limit is reached and every brain is still in its immunity period.
+        //
+        #[pallet::call_index(59)]
+        #[pallet::weight((Weight::from_ref_time(85_000_000)
+        .saturating_add(T::DbWeight::get().reads(16))
+        .saturating_add(T::DbWeight::get().writes(28)), DispatchClass::Normal, Pays::No))]
+        pub fn register_network(origin: OriginFor<T>) -> DispatchResult {
+            Self::user_add_network(origin)
+        }
 
         #[pallet::call_index(60)]
         #[pallet::weight((Weight::from_ref_time(91_000_000)
@@ -1670,13 +2239,33 @@ pub mod pallet {
             Err(Error::<T>::FaucetDisabled.into())
         }
 
-        // #[pallet::call_index(61)]
-        // #[pallet::weight((Weight::from_ref_time(70_000_000)
-        // .saturating_add(T::DbWeight::get().reads(5))
-        // .saturating_add(T::DbWeight::get().writes(31)), DispatchClass::Operational, Pays::No))]
-        // pub fn dissolve_network(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
-        //     Self::user_remove_network(origin, netuid)
-        // }
+        // ---- Dissolves a brain owned by the caller and returns its lock to the owner.
+        //
+        // # Args:
+        //  * 'origin': (<T as frame_system::Config>Origin):
+        //      - The brain owner, must be signed.
+        //
+        //  * 'netuid' (u16):
+        //      - The brain to dissolve.
+        //
+        // # Event:
+        //  * NetworkRemoved;
+        //      - On successfully dissolving the brain.
+        //
+        // # Raises:
+        //  * 'NetworkDoesNotExist':
+        //      - Thrown if the brain does not exist.
+        //
+        //  * 'NotBrainOwner':
+        //      - Thrown if the caller does not own the brain.
+        //
+        #[pallet::call_index(61)]
+        #[pallet::weight((Weight::from_ref_time(70_000_000)
+        .saturating_add(T::DbWeight::get().reads(5))
+        .saturating_add(T::DbWeight::get().writes(31)), DispatchClass::Normal, Pays::No))]
+        pub fn dissolve_network(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            Self::user_remove_network(origin, netuid)
+        }
 
         #[pallet::call_index(63)]
         #[pallet::weight((Weight::from_ref_time(85_000_000)
@@ -1696,14 +2285,403 @@ pub mod pallet {
             Self::brain_contract_created(origin, netuid, contract)
         }
 
-		// TODO(brain emissions) check / calculate weight and check if needed - sudo call doesn't
-		// need to pay with Pays::no
-        #[pallet::call_index(65)]
-        #[pallet::weight((Weight::from_ref_time(85_000_000)
-        .saturating_add(T::DbWeight::get().reads(16))
-        .saturating_add(T::DbWeight::get().writes(28)), DispatchClass::Operational, Pays::No))]
-        pub fn brain_contract_balance(origin: OriginFor<T>, contract: T::AccountId, from: T::AccountId, to: T::AccountId, balance: u128) -> DispatchResult {
-            Self::brain_contract_balance_change(origin, contract, from, to, balance)
+        // ---- Pays out the brain dividends accrued to the caller as a holder of the brain ERC20 contract.
+        //
+        // # Args:
+        //  * 'origin': (<T as frame_system::Config>Origin):
+        //      - The token holder, must be signed.
+        //
+        //  * 'contract' (T::AccountId):
+        //      - The brain ERC20 contract the dividends were accrued on.
+        //
+        // # Event:
+        //  * BrainDividendsClaimed;
+        //      - On successfully paying out the dividends.
+        //
+        // # Raises:
+        //  * 'NoBrainDividendsToClaim':
+        //      - Thrown if nothing has accrued to the caller since the last claim.
+        //
+        //  * 'CouldNotConvertToBalance':
+        //      - Thrown if the claimed amount could not be converted to a balance.
+        //
+        #[pallet::call_index(72)]
+        #[pallet::weight((Weight::from_ref_time(30_000_000)
+        .saturating_add(T::DbWeight::get().reads(5))
+        .saturating_add(T::DbWeight::get().writes(4)), DispatchClass::Normal, Pays::No))]
+        pub fn claim_brain_dividends(origin: OriginFor<T>, contract: T::AccountId) -> DispatchResult {
+            Self::do_claim_brain_dividends(origin, contract)
+        }
+
+        // ---- Binds an ERC20 contract deployed by the brain owner to the brain, the owner cut is then
+        // shared with the holders of the contract token.
+        //
+        // # Args:
+        //  * 'origin': (<T as frame_system::Config>Origin):
+        //      - The brain owner, must be signed and must have deployed the contract.
+        //
+        //  * 'netuid' (u16):
+        //      - The brain to bind the contract to.
+        //
+        //  * 'contract' (T::AccountId):
+        //      - The address of the ERC20 contract.
+        //
+        // # Event:
+        //  * BrainOwnerContractCreated;
+        //      - On successfully binding the contract.
+        //
+        // # Raises:
+        //  * 'NetworkDoesNotExist':
+        //      - Thrown if the brain does not exist.
+        //
+        //  * 'NotBrainOwner':
+        //      - Thrown if the caller does not own the brain.
+        //
+        //  * 'NotContractDeployer':
+        //      - Thrown if the caller did not deploy the contract.
+        //
+        //  * 'NotErc20Contract':
+        //      - Thrown if the contract code does not implement the ERC20 interface.
+        //
+        //  * 'BrainContractAlreadyBound':
+        //      - Thrown if the brain already has a contract or the contract is bound to another brain.
+        //
+        //  * 'BrainTokenStorageMismatch':
+        //      - Thrown if the contract storage reads a zero supply or an owner balance above the supply.
+        //
+        #[pallet::call_index(73)]
+        #[pallet::weight((Weight::from_ref_time(45_000_000)
+        .saturating_add(T::DbWeight::get().reads(10))
+        .saturating_add(T::DbWeight::get().writes(6)), DispatchClass::Normal, Pays::No))]
+        pub fn bind_brain_contract(origin: OriginFor<T>, netuid: u16, contract: T::AccountId) -> DispatchResult {
+            Self::do_bind_brain_contract(origin, netuid, contract)
+        }
+
+        // ---- Proposes a new owner for a brain of the caller, the new owner takes over once it accepts.
+        //
+        // # Args:
+        //  * 'origin': (<T as frame_system::Config>Origin):
+        //      - The brain owner, must be signed.
+        //
+        //  * 'netuid' (u16):
+        //      - The brain to hand over.
+        //
+        //  * 'new_owner' (T::AccountId):
+        //      - The account that can accept the ownership. A new proposal replaces the previous one.
+        //
+        // # Event:
+        //  * BrainOwnershipProposed;
+        //      - On successfully proposing the new owner.
+        //
+        // # Raises:
+        //  * 'NetworkDoesNotExist':
+        //      - Thrown if the brain does not exist.
+        //
+        //  * 'NotBrainOwner':
+        //      - Thrown if the caller does not own the brain.
+        //
+        //  * 'AlreadyBrainOwner':
+        //      - Thrown if the caller proposes itself.
+        //
+        #[pallet::call_index(74)]
+        #[pallet::weight((Weight::from_ref_time(20_000_000)
+        .saturating_add(T::DbWeight::get().reads(3))
+        .saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::No))]
+        pub fn propose_brain_owner(origin: OriginFor<T>, netuid: u16, new_owner: T::AccountId) -> DispatchResult {
+            Self::do_propose_brain_owner(origin, netuid, new_owner)
+        }
+
+        // ---- Accepts the ownership of a brain the caller was proposed for. The locked balance and the
+        // brain contract go with the brain to the new owner.
+        //
+        // # Args:
+        //  * 'origin': (<T as frame_system::Config>Origin):
+        //      - The proposed owner, must be signed.
+        //
+        //  * 'netuid' (u16):
+        //      - The brain to take over.
+        //
+        // # Event:
+        //  * BrainOwnershipTransferred;
+        //      - On successfully taking over the brain.
+        //
+        // # Raises:
+        //  * 'NoBrainOwnershipProposal':
+        //      - Thrown if the caller is not the proposed owner of the brain.
+        //
+        //  * 'BrainOwnershipProposalExpired':
+        //      - Thrown if the proposal expired.
+        //
+        #[pallet::call_index(75)]
+        #[pallet::weight((Weight::from_ref_time(25_000_000)
+        .saturating_add(T::DbWeight::get().reads(3))
+        .saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::No))]
+        pub fn accept_brain_ownership(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            Self::do_accept_brain_ownership(origin, netuid)
+        }
+
+        // ---- Grants an admin role on a brain to an account, so it can change the brain settings
+        // the role covers in pallet_admin_utils without the owner key.
+        //
+        // # Args:
+        //  * 'origin': (<T as frame_system::Config>Origin):
+        //      - The brain owner or root.
+        //
+        //  * 'netuid' (u16):
+        //      - The brain the role applies to.
+        //
+        //  * 'account' (T::AccountId):
+        //      - The account receiving the role.
+        //
+        //  * 'role' (BrainRole):
+        //      - The role to grant.
+        //
+        // # Event:
+        //  * BrainRoleGranted;
+        //      - On successfully granting the role.
+        //
+        // # Raises:
+        //  * 'NetworkDoesNotExist':
+        //      - Thrown if the brain does not exist.
+        //
+        //  * 'BrainRoleAlreadyGranted':
+        //      - Thrown if the account already has the role.
+        //
+        #[pallet::call_index(76)]
+        #[pallet::weight((Weight::from_ref_time(15_000_000)
+        .saturating_add(T::DbWeight::get().reads(3))
+        .saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::No))]
+        pub fn grant_brain_role(origin: OriginFor<T>, netuid: u16, account: T::AccountId, role: crate::BrainRole) -> DispatchResult {
+            Self::do_grant_brain_role(origin, netuid, account, role)
+        }
+
+        // ---- Revokes an admin role on a brain from an account.
+        //
+        // # Args:
+        //  * 'origin': (<T as frame_system::Config>Origin):
+        //      - The brain owner or root.
+        //
+        //  * 'netuid' (u16):
+        //      - The brain the role applies to.
+        //
+        //  * 'account' (T::AccountId):
+        //      - The account losing the role.
+        //
+        //  * 'role' (BrainRole):
+        //      - The role to revoke.
+        //
+        // # Event:
+        //  * BrainRoleRevoked;
+        //      - On successfully revoking the role.
+        //
+        // # Raises:
+        //  * 'NetworkDoesNotExist':
+        //      - Thrown if the brain does not exist.
+        //
+        //  * 'BrainRoleNotGranted':
+        //      - Thrown if the account does not have the role.
+        //
+        #[pallet::call_index(77)]
+        #[pallet::weight((Weight::from_ref_time(15_000_000)
+        .saturating_add(T::DbWeight::get().reads(3))
+        .saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::No))]
+        pub fn revoke_brain_role(origin: OriginFor<T>, netuid: u16, account: T::AccountId, role: crate::BrainRole) -> DispatchResult {
+            Self::do_revoke_brain_role(origin, netuid, account, role)
+        }
+
+        // ---- Publishes the name, description, repository, logo hash, modality and links of a brain.
+        // The first time, or after the brain changed owner, the brain metadata deposit is locked from
+        // the caller. Setting the modality also sets NetworkModality.
+        //
+        // # Args:
+        //  * 'origin': (<T as frame_system::Config>Origin):
+        //      - The brain owner.
+        //
+        //  * 'netuid' (u16):
+        //      - The brain the metadata describes.
+        //
+        //  * 'metadata' (BrainMetadata):
+        //      - The metadata to publish, replacing the current one.
+        //
+        // # Event:
+        //  * BrainMetadataSet;
+        //      - On successfully publishing the metadata.
+        //
+        // # Raises:
+        //  * 'NetworkDoesNotExist':
+        //      - Thrown if the brain does not exist.
+        //
+        //  * 'NotBrainOwner':
+        //      - Thrown if the caller does not own the brain.
+        //
+        //  * 'NotEnoughBalanceForDeposit':
+        //      - Thrown if the caller cannot pay the deposit.
+        //
+        //  * 'TxRateLimitExceeded':
+        //      - Thrown if the caller sent a rate limited transaction within the transaction rate limit.
+        //
+        #[pallet::call_index(78)]
+        #[pallet::weight((Weight::from_ref_time(20_000_000)
+        .saturating_add(T::DbWeight::get().reads(6))
+        .saturating_add(T::DbWeight::get().writes(5)), DispatchClass::Normal, Pays::No))]
+        pub fn set_brain_metadata(origin: OriginFor<T>, netuid: u16, metadata: BrainMetadata) -> DispatchResult {
+            Self::do_set_brain_metadata(origin, netuid, metadata)
+        }
+
+        // ---- Removes the metadata of a brain and returns the deposit to the account that paid it.
+        //
+        // # Args:
+        //  * 'origin': (<T as frame_system::Config>Origin):
+        //      - The brain owner or root.
+        //
+        //  * 'netuid' (u16):
+        //      - The brain to clear.
+        //
+        // # Event:
+        //  * BrainMetadataCleared;
+        //      - On successfully clearing the metadata.
+        //
+        // # Raises:
+        //  * 'NoBrainMetadata':
+        //      - Thrown if the brain has no metadata.
+        //
+        //  * 'TxRateLimitExceeded':
+        //      - Thrown if the owner sent a rate limited transaction within the transaction rate limit.
+        //
+        #[pallet::call_index(79)]
+        #[pallet::weight((Weight::from_ref_time(15_000_000)
+        .saturating_add(T::DbWeight::get().reads(4))
+        .saturating_add(T::DbWeight::get().writes(4)), DispatchClass::Normal, Pays::No))]
+        pub fn clear_brain_metadata(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            Self::do_clear_brain_metadata(origin, netuid)
+        }
+
+        // ---- Commits to the weights a computekey will reveal with reveal_weights, on brains in
+        // commit-reveal mode. Committing again replaces the unrevealed commit.
+        //
+        // # Args:
+        //  * 'origin': (<T as frame_system::Config>Origin):
+        //      - The signature of the calling computekey.
+        //
+        //  * 'netuid' (u16):
+        //      - The brain to set weights on.
+        //
+        //  * 'commit_hash' (T::Hash):
+        //      - The hash of ( computekey, netuid, uids, values, salt, version_key ).
+        //
+        // # Event:
+        //  * WeightsCommitted;
+        //      - On successfully storing the commit.
+        //
+        // # Raises:
+        //  * 'NetworkDoesNotExist':
+        //      - Attempting to commit weights on a non-existent network.
+        //
+        //  * 'CommitRevealDisabled':
+        //      - Attempting to commit weights on a brain not in commit-reveal mode.
+        //
+        //  * 'NotRegistered':
+        //      - Attempting to commit weights from a non registered account.
+        //
+        //  * 'SettingWeightsTooFast':
+        //      - Attempting to commit weights faster than the weights_set_rate_limit.
+        //
+        #[pallet::call_index(80)]
+        #[pallet::weight((Weight::from_ref_time(15_000_000)
+        .saturating_add(T::DbWeight::get().reads(7))
+        .saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::No))]
+        pub fn commit_weights(origin: OriginFor<T>, netuid: u16, commit_hash: T::Hash) -> DispatchResult {
+            Self::do_commit_weights(origin, netuid, commit_hash)
+        }
+
+        // ---- Reveals the weights committed with commit_weights. The reveal is only accepted in the epoch
+        // after the one of the commit, and must pass every check of set_weights.
+        //
+        // # Args:
+        //  * 'origin': (<T as frame_system::Config>Origin):
+        //      - The signature of the calling computekey.
+        //
+        //  * 'netuid' (u16):
+        //      - The brain to set weights on.
+        //
+        //  * 'uids' ( Vec<u16> ), 'values' ( Vec<u16> ), 'version_key' ( u64 ):
+        //      - The committed weights, as passed to set_weights.
+        //
+        //  * 'salt' ( Vec<u16> ):
+        //      - The salt hashed into the commit.
+        //
+        // # Event:
+        //  * WeightsSet;
+        //      - On successfully setting the weights on chain.
+        //
+        // # Raises:
+        //  * 'NoWeightsCommit':
+        //      - Attempting to reveal weights without a commit.
+        //
+        //  * 'InvalidWeightsReveal':
+        //      - Attempting to reveal weights that do not match the commit.
+        //
+        //  * 'WeightsRevealTooEarly':
+        //      - Attempting to reveal in the epoch of the commit.
+        //
+        //  * 'WeightsCommitExpired':
+        //      - Attempting to reveal after the epoch following the commit.
+        //
+        //  * The errors of set_weights.
+        //
+        #[pallet::call_index(81)]
+        #[pallet::weight((Weight::from_ref_time(10_151_000_000)
+        .saturating_add(T::DbWeight::get().reads(4105))
+        .saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::No))]
+        pub fn reveal_weights(
+            origin: OriginFor<T>,
+            netuid: u16,
+            uids: Vec<u16>,
+            values: Vec<u16>,
+            salt: Vec<u16>,
+            version_key: u64,
+        ) -> DispatchResult {
+            Self::do_reveal_weights(origin, netuid, uids, values, salt, version_key)
+        }
+
+        // ---- Sets the weights of the calling computekey on several brains in one extrinsic. Each entry
+        // goes through every check of set_weights on its own, rejected entries are reported through events
+        // and do not stop the others.
+        //
+        // # Args:
+        //  * 'origin': (<T as frame_system::Config>Origin):
+        //      - The signature of the calling computekey.
+        //
+        //  * 'weights' ( Vec<(u16, Vec<u16>, Vec<u16>, u64)> ):
+        //      - The ( netuid, dests, weights, version_key ) entries, each as passed to set_weights.
+        //
+        // # Event:
+        //  * WeightsSet;
+        //      - For each entry set on chain.
+        //
+        //  * BatchWeightsEntryFailed;
+        //      - For each rejected entry, with the error set_weights would have raised.
+        //
+        //  * BatchWeightsCompleted;
+        //      - Once every entry has run, with the number set and rejected.
+        //
+        // # Raises:
+        //  * 'TooManyBatchEntries':
+        //      - Attempting to set more entries than there are brains.
+        //
+        //  * 'DuplicateBatchEntry':
+        //      - Attempting to set two entries for the same brain.
+        //
+        #[pallet::call_index(82)]
+        #[pallet::weight((Weight::from_ref_time(10_151_000_000)
+        .saturating_add(T::DbWeight::get().reads(4104))
+        .saturating_add(T::DbWeight::get().writes(2))
+        .saturating_mul(weights.len() as u64), DispatchClass::Normal, Pays::No))]
+        pub fn batch_set_weights(
+            origin: OriginFor<T>,
+            weights: Vec<(u16, Vec<u16>, Vec<u16>, u64)>,
+        ) -> DispatchResult {
+            Self::do_batch_set_weights(origin, weights)
         }
     }
 
@@ -1723,14 +2701,43 @@ pub mod pallet {
     }
 }
 
+/************************************************************
+    BrainRole definition
+************************************************************/
+// Admin roles a brain owner grants to operational keys, each covers a group of the brain
+// settings pallet_admin_utils changes.
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BrainRole {
+    HyperparamAdmin, // Tunes the consensus, weights and serving hyperparameters of the brain.
+    RegistrationAdmin, // Opens and closes registrations and sets the burn range.
+    EmergencyPauser, // Can only close registrations.
+}
+
+/************************************************************
+    ConsensusMechanismType definition
+************************************************************/
+// The consensus mechanisms a brain can run its epoch with, see consensus.rs. Only root picks the
+// mechanism of a brain.
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConsensusMechanismType {
+    Yuma, // Stake-weighted median consensus with clipping and bonds.
+    StakeWeightedAverage, // Stake-weighted average of the weights, no clipping and no bonds.
+}
+impl Default for ConsensusMechanismType {
+    fn default() -> Self {
+        ConsensusMechanismType::Yuma
+    }
+}
+
 /************************************************************
     CallType definition
 ************************************************************/
-#[derive(Debug, PartialEq)]
+#[derive(Encode, Decode, TypeInfo, Clone, Copy, Debug, PartialEq, Eq)]
 pub enum CallType {
     SetWeights,
     AddStake,
     RemoveStake,
+    MoveStake,
     AddDelegate,
     Register,
     Serve,
@@ -1775,6 +2782,48 @@ where
     > {
         input.try_into().ok()
     }
+
+    // Returns the call type the fee schedule is looked up with, or None for calls outside this pallet.
+    pub fn get_call_type(call: &<T as frame_system::Config>::RuntimeCall) -> Option<CallType> {
+        match call.is_sub_type() {
+            Some(Call::add_stake { .. }) => Some(CallType::AddStake),
+            Some(Call::remove_stake { .. }) => Some(CallType::RemoveStake),
+            Some(Call::move_stake { .. }) => Some(CallType::MoveStake),
+            Some(Call::set_weights { .. }) => Some(CallType::SetWeights),
+            Some(Call::commit_weights { .. }) => Some(CallType::SetWeights),
+            Some(Call::reveal_weights { .. }) => Some(CallType::SetWeights),
+            Some(Call::batch_set_weights { .. }) => Some(CallType::SetWeights),
+            Some(Call::become_delegate { .. }) => Some(CallType::AddDelegate),
+            Some(Call::register { .. }) => Some(CallType::Register),
+            Some(Call::serve_brainport { .. }) => Some(CallType::Serve),
+            Some(Call::register_network { .. }) => Some(CallType::RegisterNetwork),
+            Some(Call::create_or_update_brain { netuid, owner }) => {
+                log::debug!("get_call_type create_or_update_brain netuid = {:?} owner = {:?}",
+                    netuid, owner);
+                Some(CallType::CreateOrUpdateNetwork)
+            }
+            Some(_) => Some(CallType::Other),
+            None => None,
+        }
+    }
+
+    // Returns the scheduled fee for the call, calls outside this pallet are not charged.
+    pub fn get_transaction_fee(call: &<T as frame_system::Config>::RuntimeCall) -> u128 {
+        match Self::get_call_type(call) {
+            Some(call_type) => Pallet::<T>::get_transaction_fee(&call_type),
+            None => 0,
+        }
+    }
+
+    // Returns the share of a withdrawn fee refunded for the weight the call did not use.
+    pub fn get_transaction_fee_refund(fee: u128, info: &DispatchInfo, post_info: &PostDispatchInfo) -> u128 {
+        let declared_weight: u64 = info.weight.ref_time();
+        let actual_weight: u64 = post_info.calc_actual_weight(info).ref_time();
+        if declared_weight == 0 {
+            return 0;
+        }
+        fee.saturating_sub(Perbill::from_rational(actual_weight, declared_weight) * fee)
+    }
 }
 
 impl<T: Config + Send + Sync + TypeInfo> sp_std::fmt::Debug for BasednodeSignedExtension<T> {
@@ -1793,7 +2842,7 @@ where
     type AccountId = T::AccountId;
     type Call = T::RuntimeCall;
     type AdditionalSigned = ();
-    type Pre = (CallType, u64, Self::AccountId);
+    type Pre = (CallType, u128, Self::AccountId);
 
     fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
         Ok(())
@@ -1806,8 +2855,14 @@ where
         _info: &DispatchInfoOf<Self::Call>,
         _len: usize,
     ) -> TransactionValidity {
+        // Reject calls whose scheduled fee the caller can not pay before they enter the pool.
+        if !Pallet::<T>::can_pay_transaction_fee(who, Self::get_transaction_fee(call)) {
+            return Err(InvalidTransaction::Payment.into());
+        }
         match call.is_sub_type() {
-            Some(Call::set_weights { netuid, .. }) => {
+            Some(Call::set_weights { netuid, .. })
+            | Some(Call::commit_weights { netuid, .. })
+            | Some(Call::reveal_weights { netuid, .. }) => {
                 let priority: u64 = Self::get_priority_set_weights(who, *netuid);
                 Ok(ValidTransaction {
                     priority: priority,
@@ -1815,6 +2870,20 @@ where
                     ..Default::default()
                 })
             }
+            Some(Call::batch_set_weights { weights }) => {
+                // A batch is only as urgent as its least overdue entry, so fresh entries cannot ride along
+                // with an overdue one.
+                let priority: u64 = weights
+                    .iter()
+                    .map(|(netuid, ..)| Self::get_priority_set_weights(who, *netuid))
+                    .min()
+                    .unwrap_or(0);
+                Ok(ValidTransaction {
+                    priority: priority,
+                    longevity: 1,
+                    ..Default::default()
+                })
+            }
             Some(Call::add_stake { .. }) => Ok(ValidTransaction {
                 priority: Self::get_priority_vanilla(),
                 ..Default::default()
@@ -1823,15 +2892,18 @@ where
                 priority: Self::get_priority_vanilla(),
                 ..Default::default()
             }),
+            Some(Call::move_stake { .. }) => Ok(ValidTransaction {
+                priority: Self::get_priority_vanilla(),
+                ..Default::default()
+            }),
             Some(Call::register { .. }) => Ok(ValidTransaction {
                 priority: Self::get_priority_vanilla(),
                 ..Default::default()
             }),
-            // Some(Call::register_network { .. }) => Ok(ValidTransaction {
-            //     priority: Self::get_priority_vanilla(),
-            //     ..Default::default()
-            // })
-            // ,
+            Some(Call::register_network { .. }) => Ok(ValidTransaction {
+                priority: Self::get_priority_vanilla(),
+                ..Default::default()
+            }),
             Some(Call::create_or_update_brain { .. }) => {
                 log::debug!("Call::create_or_update_brain!");
                 Ok(ValidTransaction {
@@ -1846,7 +2918,7 @@ where
         }
     }
 
-    // NOTE: Add later when we put in a pre and post dispatch step.
+    // Withdraws the scheduled fee of the call type from the caller before dispatch.
     fn pre_dispatch(
         self,
         who: &Self::AccountId,
@@ -1854,72 +2926,35 @@ where
         _info: &DispatchInfoOf<Self::Call>,
         _len: usize,
     ) -> Result<Self::Pre, TransactionValidityError> {
-        match call.is_sub_type() {
-            Some(Call::add_stake { .. }) => {
-                let transaction_fee = 100000;
-                Ok((CallType::AddStake, transaction_fee, who.clone()))
-            }
-            Some(Call::remove_stake { .. }) => {
-                let transaction_fee = 0;
-                Ok((CallType::RemoveStake, transaction_fee, who.clone()))
-            }
-            Some(Call::set_weights { .. }) => {
-                let transaction_fee = 0;
-                Ok((CallType::SetWeights, transaction_fee, who.clone()))
-            }
-            Some(Call::register { .. }) => {
-                let transaction_fee = 0;
-                Ok((CallType::Register, transaction_fee, who.clone()))
-            }
-            Some(Call::serve_brainport { .. }) => {
-                let transaction_fee = 0;
-                Ok((CallType::Serve, transaction_fee, who.clone()))
-            }
-            // Some(Call::register_network { .. }) => {
-            //     let transaction_fee = 0;
-            //     Ok((CallType::RegisterNetwork, transaction_fee, who.clone()))
-            // }
-            Some(Call::create_or_update_brain { netuid, owner }) => {
-                log::debug!("pre_dispatch create_or_update_brain netuid = {:?} owner = {:?}",
-                    netuid, owner);
-                let transaction_fee = 0;
-                Ok((CallType::CreateOrUpdateNetwork, transaction_fee, who.clone()))
-            }
-            _ => {
-                let transaction_fee = 0;
-                Ok((CallType::Other, transaction_fee, who.clone()))
-            }
+        let call_type: CallType = Self::get_call_type(call).unwrap_or_default();
+        let transaction_fee: u128 = Self::get_transaction_fee(call);
+        if !Pallet::<T>::withdraw_transaction_fee(who, transaction_fee) {
+            return Err(InvalidTransaction::Payment.into());
         }
+        Ok((call_type, transaction_fee, who.clone()))
     }
 
+    // Refunds the share of the fee for unused weight and routes the rest to the TransactionFeeDestination.
     fn post_dispatch(
         maybe_pre: Option<Self::Pre>,
-        _info: &DispatchInfoOf<Self::Call>,
-        _post_info: &PostDispatchInfoOf<Self::Call>,
+        info: &DispatchInfoOf<Self::Call>,
+        post_info: &PostDispatchInfoOf<Self::Call>,
         _len: usize,
         _result: &dispatch::DispatchResult,
     ) -> Result<(), TransactionValidityError> {
-        if let Some((call_type, _transaction_fee, _who)) = maybe_pre {
-            match call_type {
-                CallType::SetWeights => {
-                    log::debug!("Not Implemented!");
-                }
-                CallType::AddStake => {
-                    log::debug!("Not Implemented! Need to add potential transaction fees here.");
-                }
-                CallType::RemoveStake => {
-                    log::debug!("Not Implemented! Need to add potential transaction fees here.");
-                }
-                CallType::Register => {
-                    log::debug!("Not Implemented!");
-                }
-                CallType::CreateOrUpdateNetwork => {
-                    log::debug!("Not Implemented! CreateOrUpdateNetwork");
-                }
-                _ => {
-                    log::debug!("Not Implemented!");
-                }
+        if let Some((call_type, transaction_fee, who)) = maybe_pre {
+            if transaction_fee == 0 {
+                return Ok(());
             }
+            let refund: u128 = Self::get_transaction_fee_refund(transaction_fee, info, post_info);
+            log::debug!(
+                "post_dispatch {:?} who: {:?} fee: {:?} refund: {:?}",
+                call_type,
+                who,
+                transaction_fee,
+                refund
+            );
+            Pallet::<T>::settle_transaction_fee(&who, transaction_fee, refund);
         }
         Ok(())
     }
@@ -2001,3 +3036,39 @@ impl<T, H, P> CollectiveInterface<T, H, P> for () {
         Ok(true)
     }
 }
+
+/// Trait for reading the ERC20 Transfer logs emitted by the EVM
+pub trait TokenTransferLogs<AccountId> {
+    /// Get the ( contract, from, to, value ) of every Transfer log the EVM emitted in the previous block
+    fn transfers() -> Vec<(AccountId, AccountId, AccountId, sp_core::U256)>;
+}
+
+impl<T> TokenTransferLogs<T> for () {
+    fn transfers() -> Vec<(T, T, T, sp_core::U256)> {
+        Vec::new()
+    }
+}
+
+/// Trait for inspecting the ERC20 contracts brain owners bind to their brains
+pub trait BrainTokenInspector<AccountId> {
+    /// Check the code of the contract implements the ERC20 interface
+    fn is_erc20(contract: &AccountId) -> bool;
+    /// Get the total supply held in the storage of the contract
+    fn total_supply(contract: &AccountId) -> Option<u128>;
+    /// Get the balance of the holder held in the storage of the contract
+    fn balance_of(contract: &AccountId, holder: &AccountId) -> Option<u128>;
+}
+
+impl<T> BrainTokenInspector<T> for () {
+    fn is_erc20(_: &T) -> bool {
+        false
+    }
+
+    fn total_supply(_: &T) -> Option<u128> {
+        None
+    }
+
+    fn balance_of(_: &T, _: &T) -> Option<u128> {
+        None
+    }
+}
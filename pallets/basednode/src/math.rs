@@ -2,7 +2,7 @@ use frame_support::inherent::Vec;
 use frame_support::sp_std::vec;
 use sp_runtime::traits::CheckedAdd;
 use substrate_fixed::transcendental::exp;
-use substrate_fixed::types::{I32F32, I64F64};
+use substrate_fixed::types::{I32F32, I64F64, I96F32};
 
 #[allow(dead_code)]
 pub fn fixed(val: f32) -> I32F32 {
@@ -24,11 +24,26 @@ pub fn fixed64_to_u64(x: I64F64) -> u64 {
     x.to_num::<u64>()
 }
 
+#[allow(dead_code)]
+pub fn fixed96_to_u128(x: I96F32) -> u128 {
+    x.to_num::<u128>()
+}
+
 #[allow(dead_code)]
 pub fn fixed64_to_fixed32(x: I64F64) -> I32F32 {
     I32F32::from_num(x)
 }
 
+#[allow(dead_code)]
+pub fn fixed96_to_fixed32(x: I96F32) -> I32F32 {
+    I32F32::from_num(x)
+}
+
+#[allow(dead_code)]
+pub fn fixed96_to_fixed64(x: I96F32) -> I64F64 {
+    I64F64::from_num(x)
+}
+
 #[allow(dead_code)]
 pub fn fixed32_to_fixed64(x: I32F32) -> I64F64 {
     I64F64::from_num(x)
@@ -59,6 +74,16 @@ pub fn vec_fixed64_to_fixed32(vec: Vec<I64F64>) -> Vec<I32F32> {
     vec.into_iter().map(|e| fixed64_to_fixed32(e)).collect()
 }
 
+#[allow(dead_code)]
+pub fn vec_fixed96_to_fixed32(vec: Vec<I96F32>) -> Vec<I32F32> {
+    vec.into_iter().map(|e| fixed96_to_fixed32(e)).collect()
+}
+
+#[allow(dead_code)]
+pub fn vec_fixed96_to_fixed64(vec: Vec<I96F32>) -> Vec<I64F64> {
+    vec.into_iter().map(|e| fixed96_to_fixed64(e)).collect()
+}
+
 #[allow(dead_code)]
 pub fn vec_fixed32_to_fixed64(vec: Vec<I32F32>) -> Vec<I64F64> {
     vec.into_iter().map(|e| fixed32_to_fixed64(e)).collect()
@@ -69,6 +94,11 @@ pub fn vec_fixed64_to_u64(vec: Vec<I64F64>) -> Vec<u64> {
     vec.into_iter().map(|e| fixed64_to_u64(e)).collect()
 }
 
+#[allow(dead_code)]
+pub fn vec_fixed96_to_u128(vec: Vec<I96F32>) -> Vec<u128> {
+    vec.into_iter().map(|e| fixed96_to_u128(e)).collect()
+}
+
 #[allow(dead_code)]
 pub fn vec_u16_proportions_to_fixed(vec: Vec<u16>) -> Vec<I32F32> {
     vec.into_iter()
@@ -278,6 +308,19 @@ pub fn inplace_normalize_64(x: &mut Vec<I64F64>) {
     }
 }
 
+// Normalizes (sum to 1 except 0) the I96F32 input vector directly in-place.
+// Used for u128 stake values which do not fit into the I64F64 integer part.
+#[allow(dead_code)]
+pub fn inplace_normalize_96(x: &mut Vec<I96F32>) {
+    let x_sum: I96F32 = x.iter().sum();
+    if x_sum == I96F32::from_num(0) {
+        return;
+    }
+    for i in 0..x.len() {
+        x[i] = x[i] / x_sum;
+    }
+}
+
 /// Returns x / y for input vectors x and y, if y == 0 return 0.
 #[allow(dead_code)]
 pub fn vecdiv(x: &Vec<I32F32>, y: &Vec<I32F32>) -> Vec<I32F32> {
@@ -1842,6 +1885,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_math_inplace_normalize_96() {
+        let epsilon: I32F32 = I32F32::from_num(0.0001);
+        let mut x1: Vec<I96F32> = vec![
+            I96F32::from_num(1_000_000_000_000_000_000_000_u128),
+            I96F32::from_num(10_000_000_000_000_000_000_000_u128),
+            I96F32::from_num(30_000_000_000_000_000_000_000_u128),
+        ];
+        inplace_normalize_96(&mut x1);
+        assert_vec_compare(
+            &vec_fixed96_to_fixed32(x1),
+            &vec![
+                I32F32::from_num(0.0243902437),
+                I32F32::from_num(0.243902439),
+                I32F32::from_num(0.7317073171),
+            ],
+            epsilon,
+        );
+        let mut x2: Vec<I96F32> = vec![I96F32::from_num(0), I96F32::from_num(0)];
+        inplace_normalize_96(&mut x2);
+        assert_eq!(x2, vec![I96F32::from_num(0), I96F32::from_num(0)]);
+    }
+
     #[test]
     fn test_math_vecdiv() {
         let x: Vec<I32F32> = vec_to_fixed(&vec![]);
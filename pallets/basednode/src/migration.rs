@@ -1,7 +1,7 @@
 use super::*;
 use frame_support::{
     inherent::Vec,
-    pallet_prelude::{Identity, OptionQuery},
+    pallet_prelude::{Blake2_128Concat, Identity, OptionQuery, ValueQuery},
     storage_alias,
     traits::{Get, GetStorageVersion, StorageVersion},
     weights::Weight,
@@ -22,6 +22,45 @@ pub mod deprecated_loaded_emission_format {
         StorageMap<Pallet<T>, Identity, u16, Vec<(AccountIdOf<T>, u64)>, OptionQuery>;
 }
 
+pub mod deprecated_u64_loaded_emission_format {
+    use super::*;
+
+    type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+
+    #[storage_alias]
+    pub(super) type LoadedEmission<T: Config> =
+        StorageMap<Pallet<T>, Identity, u16, Vec<(AccountIdOf<T>, u64, u64)>, OptionQuery>;
+}
+
+// Loaded emission before migrate_to_v8_loaded_emission_tuples stored the tuples by position.
+pub mod deprecated_vec_loaded_emission_format {
+    use super::*;
+
+    type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+
+    #[storage_alias]
+    pub(super) type LoadedEmission<T: Config> =
+        StorageMap<Pallet<T>, Identity, u16, Vec<(AccountIdOf<T>, u128, u128)>, OptionQuery>;
+}
+
+// Stake storage before migrate_to_v6_u128_stake widened it to u128.
+pub mod deprecated_u64_stake_format {
+    use super::*;
+
+    type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+
+    #[storage_alias]
+    pub(super) type TotalStake<T: Config> = StorageValue<Pallet<T>, u64, ValueQuery>;
+
+    #[storage_alias]
+    pub(super) type TotalPersonalkeyStake<T: Config> =
+        StorageMap<Pallet<T>, Identity, AccountIdOf<T>, u64, ValueQuery>;
+
+    #[storage_alias]
+    pub(super) type Stake<T: Config> =
+        StorageDoubleMap<Pallet<T>, Blake2_128Concat, AccountIdOf<T>, Identity, AccountIdOf<T>, u64, ValueQuery>;
+}
+
 pub fn migrate_transfer_ownership_to_foundation<T: Config>(personalkey: [u8; 32]) -> Weight {
     let new_storage_version = 3;
 
@@ -319,7 +358,7 @@ pub fn migrate_to_v1_separate_emission<T: Config>() -> Weight {
         }
 
         // Translate the old storage values into the new format.
-        LoadedEmission::<T>::translate::<Vec<(AccountIdOf<T>, u64)>, _>(
+        deprecated_u64_loaded_emission_format::LoadedEmission::<T>::translate::<Vec<(AccountIdOf<T>, u64)>, _>(
             |netuid: u16,
              netuid_emissions: Vec<(AccountIdOf<T>, u64)>|
              -> Option<Vec<(AccountIdOf<T>, u64, u64)>> {
@@ -372,38 +411,39 @@ pub fn migrate_to_v2_fixed_total_stake<T: Config>() -> Weight {
         // Stake and TotalComputekeyStake are known to be accurate
         // TotalPersonalkeyStake is known to be inaccurate
         // TotalStake is known to be inaccurate
+        // Stake is still u64 here, migrate_to_v6_u128_stake widens it afterwards.
 
-        TotalStake::<T>::put(0); // Set to 0
+        deprecated_u64_stake_format::TotalStake::<T>::put(0); // Set to 0
         weight.saturating_accrue(T::DbWeight::get().writes(1));
 
         // We iterate over TotalPersonalkeyStake keys and set them to 0
-        let total_personalkey_stake_keys = TotalPersonalkeyStake::<T>::iter_keys().collect::<Vec<_>>();
+        let total_personalkey_stake_keys = deprecated_u64_stake_format::TotalPersonalkeyStake::<T>::iter_keys().collect::<Vec<_>>();
         for personalkey in total_personalkey_stake_keys {
             weight.saturating_accrue(T::DbWeight::get().reads(1));
-            TotalPersonalkeyStake::<T>::insert(personalkey, 0); // Set to 0
+            deprecated_u64_stake_format::TotalPersonalkeyStake::<T>::insert(personalkey, 0); // Set to 0
             weight.saturating_accrue(T::DbWeight::get().writes(1));
         }
 
         // Now we iterate over the entire stake map, and sum each personalkey stake
         //   We also track TotalStake
-        for (_, personalkey, stake) in Stake::<T>::iter() {
+        for (_, personalkey, stake) in deprecated_u64_stake_format::Stake::<T>::iter() {
             weight.saturating_accrue(T::DbWeight::get().reads(1));
             // Get the current personalkey stake
-            let mut total_personalkey_stake = TotalPersonalkeyStake::<T>::get(personalkey.clone());
+            let mut total_personalkey_stake = deprecated_u64_stake_format::TotalPersonalkeyStake::<T>::get(personalkey.clone());
             weight.saturating_accrue(T::DbWeight::get().reads(1));
             // Add the stake to the personalkey stake
             total_personalkey_stake = total_personalkey_stake.saturating_add(stake);
             // Update the personalkey stake
-            TotalPersonalkeyStake::<T>::insert(personalkey, total_personalkey_stake);
+            deprecated_u64_stake_format::TotalPersonalkeyStake::<T>::insert(personalkey, total_personalkey_stake);
             weight.saturating_accrue(T::DbWeight::get().writes(1));
 
             // Get the current total stake
-            let mut total_stake = TotalStake::<T>::get();
+            let mut total_stake = deprecated_u64_stake_format::TotalStake::<T>::get();
             weight.saturating_accrue(T::DbWeight::get().reads(1));
             // Add the stake to the total stake
             total_stake = total_stake.saturating_add(stake);
             // Update the total stake
-            TotalStake::<T>::put(total_stake);
+            deprecated_u64_stake_format::TotalStake::<T>::put(total_stake);
             weight.saturating_accrue(T::DbWeight::get().writes(1));
         }
 
@@ -420,3 +460,167 @@ pub fn migrate_to_v2_fixed_total_stake<T: Config>() -> Weight {
         Weight::zero()
     }
 }
+
+const LOG_TARGET_2: &str = "u128stakemigration";
+
+pub fn migrate_to_v6_u128_stake<T: Config>() -> Weight {
+    let new_storage_version = 6;
+
+    // Setup migration weight
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // Grab current version
+    let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+    // Only runs if we haven't already updated version past above new_storage_version.
+    if onchain_version < new_storage_version {
+        info!(target: LOG_TARGET_2, ">>> Migrating stake storage from u64 to u128 {:?}", onchain_version);
+
+        // Stake amounts are widened as is, the unit does not change.
+        let _ = TotalStake::<T>::translate::<u64, _>(|old: Option<u64>| {
+            old.map(|total_stake| total_stake as u128)
+        });
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+
+        TotalComputekeyStake::<T>::translate::<u64, _>(|_computekey, stake: u64| {
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+            Some(stake as u128)
+        });
+
+        TotalPersonalkeyStake::<T>::translate::<u64, _>(|_personalkey, stake: u64| {
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+            Some(stake as u128)
+        });
+
+        Stake::<T>::translate::<u64, _>(|_computekey, _personalkey, stake: u64| {
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+            Some(stake as u128)
+        });
+
+        deprecated_vec_loaded_emission_format::LoadedEmission::<T>::translate::<Vec<(AccountIdOf<T>, u64, u64)>, _>(
+            |netuid: u16,
+             netuid_emissions: Vec<(AccountIdOf<T>, u64, u64)>|
+             -> Option<Vec<(AccountIdOf<T>, u128, u128)>> {
+                info!(target: LOG_TARGET_2, "     Do migration of loaded emission for netuid: {:?}...", netuid);
+
+                let new_netuid_emissions = netuid_emissions
+                    .into_iter()
+                    .map(|(computekey, server_emission, validator_emission)| {
+                        (computekey, server_emission as u128, validator_emission as u128)
+                    })
+                    .collect();
+
+                // One read (old) and write (new) per netuid
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+
+                Some(new_netuid_emissions)
+            },
+        );
+
+        // The emission of a block is above u64::MAX, so the emission path is widened with the stake.
+        EmissionValues::<T>::translate::<u64, _>(|_netuid, emission: u64| {
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+            Some(emission as u128)
+        });
+
+        PendingEmission::<T>::translate::<u64, _>(|_netuid, emission: u64| {
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+            Some(emission as u128)
+        });
+
+        Emission::<T>::translate::<Vec<u64>, _>(|_netuid, emission: Vec<u64>| {
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+            Some(emission.into_iter().map(|uid_emission| uid_emission as u128).collect())
+        });
+
+        // Update storage version.
+        StorageVersion::new(new_storage_version).put::<Pallet<T>>(); // Update to version so we don't run this again.
+        // One write to storage version
+        weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+        weight
+    } else {
+        info!(target: LOG_TARGET_2, "Migration to v6 already done!");
+        Weight::zero()
+    }
+}
+
+const LOG_TARGET_3: &str = "braintokensupplymigration";
+
+pub fn migrate_to_v7_seed_brain_token_supply<T: Config>() -> Weight {
+    let new_storage_version = 7;
+
+    // Setup migration weight
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // Grab current version
+    let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+    // Only runs if we haven't already updated version past above new_storage_version.
+    if onchain_version < new_storage_version {
+        info!(target: LOG_TARGET_3, ">>> Seeding the token supply of bound brain contracts {:?}", onchain_version);
+
+        // Contracts bound before the supply was tracked accrue no holder cut until it is read
+        // from the storage of the contract. Their Transfer logs are only recorded once the brain
+        // can be looked up by contract.
+        let contracts: Vec<(u16, T::AccountId)> = BrainContract::<T>::iter().collect();
+        weight.saturating_accrue(T::DbWeight::get().reads(contracts.len() as u64));
+        for (netuid, contract) in contracts.iter() {
+            info!(target: LOG_TARGET_3, "     Seeding brain contract of netuid: {:?}...", netuid);
+            ContractBrain::<T>::insert(contract, *netuid);
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
+            weight.saturating_accrue(Pallet::<T>::seed_brain_token_balances(*netuid, contract));
+        }
+
+        // Update storage version.
+        StorageVersion::new(new_storage_version).put::<Pallet<T>>(); // Update to version so we don't run this again.
+        // One write to storage version
+        weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+        weight
+    } else {
+        info!(target: LOG_TARGET_3, "Migration to v7 already done!");
+        Weight::zero()
+    }
+}
+
+const LOG_TARGET_4: &str = "loadedemissiontuplesmigration";
+
+pub fn migrate_to_v8_loaded_emission_tuples<T: Config>() -> Weight {
+    let new_storage_version = 8;
+
+    // Setup migration weight
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // Grab current version
+    let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+    // Only runs if we haven't already updated version past above new_storage_version.
+    if onchain_version < new_storage_version {
+        info!(target: LOG_TARGET_4, ">>> Storing the loaded emission tuples by position {:?}", onchain_version);
+
+        // Tuples keep their index as position, so the cursor still points at the next one to drain.
+        let loaded: Vec<(u16, Vec<(AccountIdOf<T>, u128, u128)>)> =
+            deprecated_vec_loaded_emission_format::LoadedEmission::<T>::drain().collect();
+        for (netuid, tuples) in loaded {
+            info!(target: LOG_TARGET_4, "     Do migration of loaded emission for netuid: {:?}...", netuid);
+            let length: u32 = tuples.len() as u32;
+            let cursor: u32 = LoadedEmissionCursor::<T>::get(netuid).min(length);
+            for (position, tuple) in tuples.into_iter().enumerate().skip(cursor as usize) {
+                LoadedEmissionTuples::<T>::insert(netuid, position as u32, tuple);
+            }
+            LoadedEmissionLength::<T>::insert(netuid, length);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, (length - cursor) as u64 + 2));
+        }
+
+        // Update storage version.
+        StorageVersion::new(new_storage_version).put::<Pallet<T>>(); // Update to version so we don't run this again.
+        // One write to storage version
+        weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+        weight
+    } else {
+        info!(target: LOG_TARGET_4, "Migration to v8 already done!");
+        Weight::zero()
+    }
+}
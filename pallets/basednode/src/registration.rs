@@ -747,6 +747,17 @@ impl<T: Config> Pallet<T> {
             weight.saturating_accrue(T::DbWeight::get().writes(2));
         }
 
+        if let Some(pending_take) = PendingDelegateTake::<T>::take(old_computekey) {
+            PendingDelegateTake::<T>::insert(new_computekey, pending_take);
+            PendingDelegateTakeQueue::<T>::mutate(pending_take.1, |computekeys| {
+                for computekey in computekeys.iter_mut().filter(|computekey| **computekey == *old_computekey) {
+                    *computekey = new_computekey.clone();
+                }
+            });
+
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 3));
+        }
+
         if let Ok(last_tx) = LastTxBlock::<T>::try_get(old_computekey) {
             LastTxBlock::<T>::remove(old_computekey);
             LastTxBlock::<T>::insert(new_computekey, last_tx);
@@ -754,7 +765,7 @@ impl<T: Config> Pallet<T> {
             weight.saturating_accrue(T::DbWeight::get().writes(2));
         }
 
-        let mut personalkey_stake: Vec<(T::AccountId, u64)> = vec![];
+        let mut personalkey_stake: Vec<(T::AccountId, u128)> = vec![];
         for (personalkey, stake_amount) in Stake::<T>::iter_prefix(old_computekey) {
             personalkey_stake.push((personalkey.clone(), stake_amount));
         }
@@ -763,8 +774,27 @@ impl<T: Config> Pallet<T> {
         weight.saturating_accrue(T::DbWeight::get().writes(personalkey_stake.len() as u64));
 
         for (personalkey, stake_amount) in personalkey_stake {
-            Stake::<T>::insert(new_computekey, personalkey, stake_amount);
+            Stake::<T>::insert(new_computekey, personalkey.clone(), stake_amount);
             weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+            // Point unbonding chunks of this staker at the new computekey.
+            Unbonding::<T>::mutate_exists(personalkey, |maybe_chunks| {
+                if let Some(chunks) = maybe_chunks {
+                    for (computekey, _, _) in chunks.iter_mut() {
+                        if *computekey == *old_computekey {
+                            *computekey = new_computekey.clone();
+                        }
+                    }
+                }
+            });
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+
+            // Carry the reward destination of this staker over to the new computekey.
+            if let Some(destination) = RewardDestinations::<T>::take(old_computekey, &personalkey) {
+                RewardDestinations::<T>::insert(new_computekey, &personalkey, destination);
+                weight.saturating_accrue(T::DbWeight::get().writes(1));
+            }
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
         }
 
         let mut netuid_is_member: Vec<u16> = vec![];
@@ -800,20 +830,20 @@ impl<T: Config> Pallet<T> {
 
                 weight.saturating_accrue(T::DbWeight::get().writes(1));
 
-                LoadedEmission::<T>::mutate(netuid, |emission_exists| {
-                    match emission_exists {
-                        Some(emissions) => {
-                            if let Some(emission) = emissions.get_mut(uid as usize) {
-                                let (_, se, ve) = emission;
-                                *emission = (new_computekey.clone(), *se, *ve);
-
+                // Loaded tuples are stored behind earlier epochs, so their position is not the uid.
+                let length: u32 = LoadedEmissionLength::<T>::get(netuid).unwrap_or(0);
+                let cursor: u32 = LoadedEmissionCursor::<T>::get(netuid).min(length);
+                for position in cursor..length {
+                    LoadedEmissionTuples::<T>::mutate_exists(netuid, position, |emission_exists| {
+                        if let Some((computekey, _, _)) = emission_exists {
+                            if *computekey == *old_computekey {
+                                *computekey = new_computekey.clone();
                             }
                         }
-                        None => {}
-                    }
-                });
+                    });
+                }
 
-                weight.saturating_accrue(T::DbWeight::get().writes(1));
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(2 + (length - cursor) as u64, (length - cursor) as u64));
             }
         }
 
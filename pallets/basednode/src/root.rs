@@ -17,6 +17,7 @@
 
 use super::*;
 use crate::math::*;
+use sp_core::U256;
 use frame_support::dispatch::{DispatchResultWithPostInfo, Pays};
 use frame_support::inherent::Vec;
 use frame_support::sp_std::vec;
@@ -24,11 +25,13 @@ use frame_support::storage::{IterableStorageDoubleMap, IterableStorageMap};
 use frame_support::traits::Get;
 use frame_support::weights::Weight;
 use frame_system::ensure_root;
-use substrate_fixed::types::{I32F32, I64F64};
+use substrate_fixed::types::{I32F32, I64F64, I96F32};
 
-const BLOCKS_PER_YEAR: u64 = (365 * 24 * 60 * 60) / 10; // 10s per block, cant use
-                                                        // MILLISECS_PER_BLOCK runtime constant
-                                                        // here
+// Queued Transfer logs of brain contracts applied per block, the rest wait for the next blocks.
+pub const MAX_TOKEN_TRANSFERS_PER_BLOCK: u64 = 256;
+
+// Precision the brain dividend index is kept with, 10^36 keeps it exact for 18 decimal tokens.
+const BRAIN_DIVIDEND_PRECISION: u128 = 1_000_000_000_000_000_000_000_000_000_000_000_000;
 
 impl<T: Config> Pallet<T> {
     // Retrieves the unique identifier (UID) for the root network.
@@ -98,9 +101,9 @@ impl<T: Config> Pallet<T> {
     // This function retrieves the emission value for the given brain.
     //
     // # Returns:
-    // * 'u64': The emission value for the given brain.
+    // * 'u128': The emission value for the given brain.
     //
-    pub fn get_brain_emission_value(netuid: u16) -> u64 {
+    pub fn get_brain_emission_value(netuid: u16) -> u128 {
         EmissionValues::<T>::get(netuid)
     }
 
@@ -155,7 +158,7 @@ impl<T: Config> Pallet<T> {
     // Sets the emission values for each netuid
     //
     //
-    pub fn set_emission_values(netuids: &Vec<u16>, emission: Vec<u64>) -> Result<(), &'static str> {
+    pub fn set_emission_values(netuids: &Vec<u16>, emission: Vec<u128>) -> Result<(), &'static str> {
         log::debug!(
             "set_emission_values: netuids: {:?} emission:{:?}",
             netuids,
@@ -237,9 +240,21 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::NetworkRateLimitSet(limit));
     }
 
+    // Returns the number of halvings at a block: the halvings before the interval was last set plus the
+    // intervals passed since. Blocks before the anchor count the halvings at the anchor.
+    pub fn get_halvings(block_number: u64) -> u64 {
+        let (anchor_block, anchor_halvings) = Self::get_halving_anchor();
+        anchor_halvings.saturating_add(
+            block_number.saturating_sub(anchor_block) / Self::get_halving_interval().max(1),
+        )
+    }
+
     // Calculate the current halving factor
     pub fn get_current_halving_factor(current_block: u64) -> u128 {
-        let halvings = current_block / BLOCKS_PER_YEAR;
+        let halvings = Self::get_halvings(current_block);
+        if halvings >= 128 {
+            return u128::MAX; // Past this point the emission is zero.
+        }
         1u128 << halvings // This is equivalent to 2^halvings
     }
 
@@ -249,6 +264,17 @@ impl<T: Config> Pallet<T> {
         Self::get_block_emission() / halving_factor
     }
 
+    // Projects the block emission at any block number with the on chain emission schedule.
+    // Returns ( total, weighted by root, equal per brain floor ).
+    pub fn project_block_emission(block_number: u64) -> (u128, u128, u128) {
+        let total_block_emission: u128 = Self::current_block_emission(block_number);
+        let weighted_emission: u128 =
+            total_block_emission / 100 * Self::get_emission_split() as u128;
+        let floor_emission: u128 =
+            total_block_emission / 100 * (100 - Self::get_emission_split()) as u128;
+        (total_block_emission, weighted_emission, floor_emission)
+    }
+
     // Computes and sets emission values for the root network which determine the emission for all brains.
     //
     // This function is responsible for calculating emission based on network weights, stake values,
@@ -283,20 +309,13 @@ impl<T: Config> Pallet<T> {
             return Err("No networks to validate emission values.");
         }
 
-        // --- 4. Determines the total block emission across all the brainworks. This is the
-        // value which will be distributed based on the computation below.
-        let total_block_emission: I64F64 = I64F64::from_num(Self::current_block_emission(Self::get_current_block_as_u64()));
+        // --- 4. Determines the total block emission across all the brainworks and divides it between
+        // the root weighted share and the equal per brain floor. The block emission does not fit
+        // into I64F64, so the shares stay u128 and are only scaled in a 96-bit fixed point below.
+        let (total_block_emission, block_emission, emission_minimum): (u128, u128, u128) =
+            Self::project_block_emission(Self::get_current_block_as_u64());
         log::debug!("total_block_emission:\n{:?}\n", total_block_emission);
 
-        // --- 4.5 Divide emissions to 80% and 20%
-        let block_emission = total_block_emission
-            .saturating_div(100.into())
-            .saturating_mul(80.into());
-
-        let emission_minimum = total_block_emission
-            .saturating_div(100.into())
-            .saturating_mul(20.into());
-
         // --- 5. A collection of all registered computekeys on the root network. Computekeys
         // pairs with network UIDs and stake values.
         let mut computekeys: Vec<(u16, T::AccountId)> = vec![];
@@ -308,12 +327,14 @@ impl<T: Config> Pallet<T> {
         log::debug!("computekeys:\n{:?}\n", computekeys);
 
         // --- 6. Retrieves and stores the stake value associated with each computekey on the root network.
-        // Stakes are stored in a 64-bit fixed point representation for precise calculations.
-        let mut stake_i64: Vec<I64F64> = vec![I64F64::from_num(0.0); n as usize];
+        // Stakes are normalized in a 96-bit fixed point representation, u128 stake does not fit into I64F64,
+        // and are then stored in a 64-bit fixed point representation for precise calculations.
+        let mut stake_i96: Vec<I96F32> = vec![I96F32::from_num(0.0); n as usize];
         for (uid_i, computekey) in computekeys.iter() {
-            stake_i64[*uid_i as usize] = I64F64::from_num(Self::get_total_stake_for_computekey(computekey));
+            stake_i96[*uid_i as usize] = I96F32::from_num(Self::get_total_stake_for_computekey(computekey));
         }
-        inplace_normalize_64(&mut stake_i64);
+        inplace_normalize_96(&mut stake_i96);
+        let stake_i64: Vec<I64F64> = vec_fixed96_to_fixed64(stake_i96);
         log::debug!("S:\n{:?}\n", &stake_i64);
 
         // --- 8. Retrieves the network weights in a 2D Vector format. Weights have shape
@@ -379,28 +400,28 @@ impl<T: Config> Pallet<T> {
         inplace_normalize_64(&mut weighted_emission);
         log::debug!("Ei64:\n{:?}\n", &weighted_emission);
 
-        // -- 11. Converts the normalized 64-bit fixed point rank values to u64 for the final emission calculation.
-        let emission_as_based: Vec<I64F64> = weighted_emission
+        // -- 11. Scales the normalized 64-bit fixed point rank values by the weighted block emission.
+        let emission_as_based: Vec<I96F32> = weighted_emission
             .iter()
-            .map(|v: &I64F64| *v * block_emission)
+            .map(|v: &I64F64| I96F32::from_num(*v).saturating_mul(I96F32::from_num(block_emission)))
             .collect();
 
-        // --- 12. Converts the normalized 64-bit fixed point rank values to u64 for the final emission calculation.
-        let mut emission_u64: Vec<u64> = vec_fixed64_to_u64(emission_as_based);
-        log::debug!("Eu64:\n{:?}\n", &emission_u64);
+        // --- 12. Converts the 96-bit fixed point emission values to u128 for the final emission calculation.
+        let mut emission_u128: Vec<u128> = vec_fixed96_to_u128(emission_as_based);
+        log::debug!("Eu128:\n{:?}\n", &emission_u128);
 
         // --- 13. Set the emission values for each brain directly.
         let netuids: Vec<u16> = Self::get_all_brain_netuids();
-        log::debug!("netuids: {:?} values: {:?}", netuids, emission_u64);
+        log::debug!("netuids: {:?} values: {:?}", netuids, emission_u128);
 
         // --- 14. always distribute 2%0 of the block emissions evenly among all active brains
         // (regardless of weights)
-        let brain_slice = fixed64_to_u64(emission_minimum.saturating_div(I64F64::from_num(emission_u64.len())));
-        for idx in 0..emission_u64.len() {
-            emission_u64[idx] += brain_slice;
+        let brain_slice: u128 = emission_minimum / emission_u128.len().max(1) as u128;
+        for idx in 0..emission_u128.len() {
+            emission_u128[idx] = emission_u128[idx].saturating_add(brain_slice);
         }
 
-        return Self::set_emission_values(&netuids, emission_u64);
+        return Self::set_emission_values(&netuids, emission_u128);
     }
 
     // Registers a user's computekey to the root network.
@@ -473,7 +494,7 @@ impl<T: Config> Pallet<T> {
         } else {
             // --- 13.1.1 The network is full. Perform replacement.
             // Find the agent with the lowest stake value to replace.
-            let mut lowest_stake: u64 = u64::MAX;
+            let mut lowest_stake: u128 = u128::MAX;
             let mut lowest_uid: u16 = 0;
 
             // Iterate over all keys in the root network to find the agent with the lowest stake.
@@ -482,7 +503,7 @@ impl<T: Config> Pallet<T> {
                     root_netuid,
                 )
             {
-                let stake_i: u64 = Self::get_total_stake_for_computekey(&computekey_i);
+                let stake_i: u128 = Self::get_total_stake_for_computekey(&computekey_i);
                 if stake_i < lowest_stake {
                     lowest_stake = stake_i;
                     lowest_uid = uid_i;
@@ -602,18 +623,20 @@ impl<T: Config> Pallet<T> {
             .into())
     }
 
-    // Facilitates user registration of a new brain.
-	// NOTE: should be deleted in next apiVersion - handled by create_or_update_network_ownership
+    // Facilitates user registration of a new brain. The network lock cost is locked from the
+    // personalkey of the caller and returned when the brain is dissolved or pruned.
     //
     // # Args:
     //  * 'origin': ('T::RuntimeOrigin'): The calling origin. Must be signed.
     //
     // # Event:
     //  * 'NetworkAdded': Emitted when a new network is successfully added.
+    //  * 'NetworkRemoved': Emitted when a network is pruned to make room for the new one.
     //
     // # Raises:
     //  * 'TxRateLimitExceeded': If the rate limit for network registration is exceeded.
     //  * 'NotEnoughBalanceToStake': If there isn't enough balance to stake for network registration.
+    //  * 'AllNetworksInImmunity': If the brain limit is reached and no network can be pruned.
     //  * 'BalanceWithdrawalError': If an error occurs during balance withdrawal for network registration.
     //
     pub fn user_add_network(origin: T::RuntimeOrigin) -> dispatch::DispatchResult {
@@ -659,6 +682,7 @@ impl<T: Config> Pallet<T> {
 
                 Self::remove_network(netuid_to_prune);
                 log::debug!("remove_network: {:?}", netuid_to_prune,);
+                Self::deposit_event(Event::NetworkRemoved(netuid_to_prune));
                 netuid_to_prune
             }
         };
@@ -693,8 +717,7 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
-    // Facilitates the removal of a user's brain.
-	// NOTE: should be deleted in next apiVersion - handled by create_or_update_network_ownership
+    // Facilitates the removal of a user's brain, the lock is returned to the owner.
     //
     // # Args:
     //  * 'origin': ('T::RuntimeOrigin'): The calling origin. Must be signed.
@@ -881,7 +904,12 @@ impl<T: Config> Pallet<T> {
         NetworkLastRegistered::<T>::set(current_block_number);
 
         let owner_clone = owner.clone();
+        PendingBrainOwner::<T>::remove(netuid);
         if network_exists {
+            // A new owner starts without the admins of the previous owner.
+            if BrainOwner::<T>::get(netuid) != owner {
+                let _ = BrainRoles::<T>::clear_prefix(netuid, u32::max_value(), None);
+            }
             BrainOwner::<T>::set(netuid, owner);
             log::debug!("network {:?} exitst, changing owner to: {:?}", netuid, &owner_clone);
         } else {
@@ -901,6 +929,277 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    // Proposes a new owner for a brain, the proposal can be accepted until it expires.
+    //
+    // # Args:
+    //  * 'origin': ('T::RuntimeOrigin'): The brain owner, must be signed.
+    //  * 'netuid': ('u16'): Brain ID
+    //  * 'new_owner': ('T::AccountId'): The account that can accept the ownership.
+    //
+    // # Event:
+    //  * 'BrainOwnershipProposed': Emitted when the new owner is proposed.
+    //
+    // # Raises:
+    //  * 'NetworkDoesNotExist': If the brain does not exist.
+    //  * 'NotBrainOwner': If the caller does not own the brain.
+    //  * 'AlreadyBrainOwner': If the caller proposes itself.
+    //
+    pub fn do_propose_brain_owner(origin: T::RuntimeOrigin, netuid: u16, new_owner: T::AccountId) -> dispatch::DispatchResult {
+        let owner = ensure_signed(origin)?;
+        ensure!(
+            Self::if_brain_exist(netuid),
+            Error::<T>::NetworkDoesNotExist
+        );
+        ensure!(
+            BrainOwner::<T>::get(netuid) == owner,
+            Error::<T>::NotBrainOwner
+        );
+        ensure!(new_owner != owner, Error::<T>::AlreadyBrainOwner);
+
+        let expires_at: u64 = Self::get_current_block_as_u64()
+            .saturating_add(Self::get_brain_ownership_proposal_expiry());
+        PendingBrainOwner::<T>::insert(netuid, (new_owner.clone(), expires_at));
+
+        log::info!(
+            "BrainOwnershipProposed( netuid:{:?}, owner:{:?}, new_owner:{:?}, expires_at:{:?} )",
+            netuid,
+            &owner,
+            &new_owner,
+            expires_at
+        );
+        Self::deposit_event(Event::BrainOwnershipProposed(netuid, owner, new_owner, expires_at));
+        Ok(())
+    }
+
+    // Hands a brain over to the proposed owner. The locked balance and the contract are stored per
+    // brain, so the new owner gets the lock back on dissolve and keeps the bound contract.
+    //
+    // # Args:
+    //  * 'origin': ('T::RuntimeOrigin'): The proposed owner, must be signed.
+    //  * 'netuid': ('u16'): Brain ID
+    //
+    // # Event:
+    //  * 'BrainOwnershipTransferred': Emitted when the brain is handed over.
+    //
+    // # Raises:
+    //  * 'NoBrainOwnershipProposal': If the caller is not the proposed owner.
+    //  * 'BrainOwnershipProposalExpired': If the proposal expired.
+    //
+    pub fn do_accept_brain_ownership(origin: T::RuntimeOrigin, netuid: u16) -> dispatch::DispatchResult {
+        let new_owner = ensure_signed(origin)?;
+
+        let (proposed_owner, expires_at) =
+            PendingBrainOwner::<T>::get(netuid).ok_or(Error::<T>::NoBrainOwnershipProposal)?;
+        ensure!(proposed_owner == new_owner, Error::<T>::NoBrainOwnershipProposal);
+        ensure!(
+            Self::get_current_block_as_u64() <= expires_at,
+            Error::<T>::BrainOwnershipProposalExpired
+        );
+
+        let old_owner: T::AccountId = BrainOwner::<T>::get(netuid);
+        PendingBrainOwner::<T>::remove(netuid);
+        BrainOwner::<T>::insert(netuid, &new_owner);
+        // The new owner starts without the admins of the previous owner.
+        let _ = BrainRoles::<T>::clear_prefix(netuid, u32::max_value(), None);
+
+        log::info!(
+            "BrainOwnershipTransferred( netuid:{:?}, old_owner:{:?}, new_owner:{:?} )",
+            netuid,
+            &old_owner,
+            &new_owner
+        );
+        Self::deposit_event(Event::BrainOwnershipTransferred(netuid, old_owner, new_owner));
+        Ok(())
+    }
+
+    // Grants an admin role on a brain to an account.
+    //
+    // # Args:
+    //  * 'origin': ('T::RuntimeOrigin'): The brain owner or root.
+    //  * 'netuid': ('u16'): Brain ID
+    //  * 'account': ('T::AccountId'): The account receiving the role.
+    //  * 'role': ('BrainRole'): The role to grant.
+    //
+    // # Event:
+    //  * 'BrainRoleGranted': Emitted when the role is granted.
+    //
+    // # Raises:
+    //  * 'NetworkDoesNotExist': If the brain does not exist.
+    //  * 'BrainRoleAlreadyGranted': If the account already has the role.
+    //
+    pub fn do_grant_brain_role(origin: T::RuntimeOrigin, netuid: u16, account: T::AccountId, role: BrainRole) -> dispatch::DispatchResult {
+        Self::ensure_brain_owner_or_root(origin, netuid)?;
+        ensure!(
+            Self::if_brain_exist(netuid),
+            Error::<T>::NetworkDoesNotExist
+        );
+
+        BrainRoles::<T>::try_mutate(netuid, &account, |roles| -> dispatch::DispatchResult {
+            ensure!(!roles.contains(&role), Error::<T>::BrainRoleAlreadyGranted);
+            roles.push(role);
+            Ok(())
+        })?;
+
+        log::info!("BrainRoleGranted( netuid:{:?}, account:{:?}, role:{:?} )", netuid, &account, role);
+        Self::deposit_event(Event::BrainRoleGranted(netuid, account, role));
+        Ok(())
+    }
+
+    // Revokes an admin role on a brain from an account.
+    //
+    // # Args:
+    //  * 'origin': ('T::RuntimeOrigin'): The brain owner or root.
+    //  * 'netuid': ('u16'): Brain ID
+    //  * 'account': ('T::AccountId'): The account losing the role.
+    //  * 'role': ('BrainRole'): The role to revoke.
+    //
+    // # Event:
+    //  * 'BrainRoleRevoked': Emitted when the role is revoked.
+    //
+    // # Raises:
+    //  * 'NetworkDoesNotExist': If the brain does not exist.
+    //  * 'BrainRoleNotGranted': If the account does not have the role.
+    //
+    pub fn do_revoke_brain_role(origin: T::RuntimeOrigin, netuid: u16, account: T::AccountId, role: BrainRole) -> dispatch::DispatchResult {
+        Self::ensure_brain_owner_or_root(origin, netuid)?;
+        ensure!(
+            Self::if_brain_exist(netuid),
+            Error::<T>::NetworkDoesNotExist
+        );
+
+        let mut roles: Vec<BrainRole> = BrainRoles::<T>::get(netuid, &account);
+        ensure!(roles.contains(&role), Error::<T>::BrainRoleNotGranted);
+        roles.retain(|granted| *granted != role);
+        if roles.is_empty() {
+            BrainRoles::<T>::remove(netuid, &account);
+        } else {
+            BrainRoles::<T>::insert(netuid, &account, roles);
+        }
+
+        log::info!("BrainRoleRevoked( netuid:{:?}, account:{:?}, role:{:?} )", netuid, &account, role);
+        Self::deposit_event(Event::BrainRoleRevoked(netuid, account, role));
+        Ok(())
+    }
+
+    // Publishes the metadata of a brain. The brain metadata deposit is locked from the caller unless
+    // the caller already paid the deposit held for the brain, in which case the metadata is only replaced.
+    // A deposit paid by a previous owner is returned to it.
+    //
+    // # Args:
+    //  * 'origin': ('T::RuntimeOrigin'): The brain owner.
+    //  * 'netuid': ('u16'): Brain ID
+    //  * 'metadata': ('BrainMetadata'): The metadata to publish.
+    //
+    // # Event:
+    //  * 'BrainMetadataSet': Emitted when the metadata is published.
+    //
+    // # Raises:
+    //  * 'NetworkDoesNotExist': If the brain does not exist.
+    //  * 'NotBrainOwner': If the caller does not own the brain.
+    //  * 'NotEnoughBalanceForDeposit': If the caller cannot pay the deposit.
+    //  * 'TxRateLimitExceeded': If the caller sent a rate limited transaction within the transaction rate limit.
+    //
+    pub fn do_set_brain_metadata(origin: T::RuntimeOrigin, netuid: u16, metadata: BrainMetadata) -> dispatch::DispatchResult {
+        let personalkey = ensure_signed(origin)?;
+        ensure!(
+            Self::if_brain_exist(netuid),
+            Error::<T>::NetworkDoesNotExist
+        );
+        ensure!(
+            BrainOwner::<T>::get(netuid) == personalkey,
+            Error::<T>::NotBrainOwner
+        );
+        let block: u64 = Self::get_current_block_as_u64();
+        ensure!(
+            !Self::exceeds_tx_rate_limit(Self::get_last_tx_block(&personalkey), block),
+            Error::<T>::TxRateLimitExceeded
+        );
+
+        let deposit: u128 = match BrainMetadataOf::<T>::get(netuid) {
+            Some((depositor, deposit, _)) if depositor == personalkey => deposit,
+            current => {
+                let deposit: u128 = Self::get_brain_metadata_deposit();
+                let deposit_as_balance = Self::u128_to_balance(deposit);
+                ensure!(
+                    deposit_as_balance.is_some(),
+                    Error::<T>::CouldNotConvertToBalance
+                );
+                ensure!(
+                    Self::can_remove_balance_from_personalkey_account(&personalkey, deposit_as_balance.unwrap()),
+                    Error::<T>::NotEnoughBalanceForDeposit
+                );
+                ensure!(
+                    Self::remove_balance_from_personalkey_account(&personalkey, deposit_as_balance.unwrap()) == true,
+                    Error::<T>::BalanceWithdrawalError
+                );
+                if let Some((depositor, old_deposit, _)) = current {
+                    Self::return_brain_metadata_deposit(&depositor, old_deposit);
+                }
+                deposit
+            }
+        };
+
+        NetworkModality::<T>::insert(netuid, metadata.modality as u16);
+        BrainMetadataOf::<T>::insert(netuid, (personalkey.clone(), deposit, metadata));
+        Self::set_last_tx_block(&personalkey, block);
+
+        log::info!("BrainMetadataSet( netuid:{:?}, owner:{:?} )", netuid, &personalkey);
+        Self::deposit_event(Event::BrainMetadataSet(netuid, personalkey));
+        Ok(())
+    }
+
+    // Removes the metadata of a brain and returns its deposit.
+    //
+    // # Args:
+    //  * 'origin': ('T::RuntimeOrigin'): The brain owner or root.
+    //  * 'netuid': ('u16'): Brain ID
+    //
+    // # Event:
+    //  * 'BrainMetadataCleared': Emitted when the metadata is removed.
+    //
+    // # Raises:
+    //  * 'NoBrainMetadata': If the brain has no metadata.
+    //  * 'TxRateLimitExceeded': If the owner sent a rate limited transaction within the transaction rate limit.
+    //
+    pub fn do_clear_brain_metadata(origin: T::RuntimeOrigin, netuid: u16) -> dispatch::DispatchResult {
+        Self::ensure_brain_owner_or_root(origin.clone(), netuid)?;
+        ensure!(
+            BrainMetadataOf::<T>::contains_key(netuid),
+            Error::<T>::NoBrainMetadata
+        );
+
+        // Root is not rate limited.
+        if let Ok(personalkey) = ensure_signed(origin) {
+            let block: u64 = Self::get_current_block_as_u64();
+            ensure!(
+                !Self::exceeds_tx_rate_limit(Self::get_last_tx_block(&personalkey), block),
+                Error::<T>::TxRateLimitExceeded
+            );
+            Self::set_last_tx_block(&personalkey, block);
+        }
+
+        Self::remove_brain_metadata(netuid);
+        NetworkModality::<T>::insert(netuid, 0);
+
+        log::info!("BrainMetadataCleared( netuid:{:?} )", netuid);
+        Self::deposit_event(Event::BrainMetadataCleared(netuid));
+        Ok(())
+    }
+
+    // Removes the metadata of a brain, if any, and returns the deposit to the account that paid it.
+    //
+    pub fn remove_brain_metadata(netuid: u16) {
+        if let Some((depositor, deposit, _)) = BrainMetadataOf::<T>::take(netuid) {
+            Self::return_brain_metadata_deposit(&depositor, deposit);
+        }
+    }
+
+    fn return_brain_metadata_deposit(depositor: &T::AccountId, deposit: u128) {
+        if let Some(deposit_as_balance) = Self::u128_to_balance(deposit) {
+            Self::add_balance_to_personalkey_account(depositor, deposit_as_balance);
+        }
+    }
+
     // Facilitates storing of ERC2 contract creation by brain owner
     //
     // # Args:
@@ -920,6 +1219,7 @@ impl<T: Config> Pallet<T> {
         let _ = ensure_root(origin)?;
 
         Self::set_brain_contract_created(netuid, &contract);
+        Self::seed_brain_token_balances(netuid, &contract);
         log::info!(
             "BrainOwnerContractCreated( netuid:{:?}, contract:{:?} )",
             netuid,
@@ -928,62 +1228,460 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::BrainOwnerContractCreated(netuid, contract.clone()));
         Ok(())
     }
-	//
-    // Facilitates storage of Brain linked ERC20 balance change
+
+    // Seeds the token supply and the holder balances of a brain contract from the storage of the
+    // contract, so the holder cut accrues on tokens minted before the contract was bound. The brain
+    // owner and every holder already tracked for the contract are read, which also clears a desync.
+    //
+    // # Returns:
+    //  * The weight of the balances read and written.
+    //
+    pub fn seed_brain_token_balances(netuid: u16, contract: &T::AccountId) -> Weight {
+        let mut weight: Weight = T::DbWeight::get().reads_writes(2, 1);
+        BrainContractDesynced::<T>::remove(contract);
+        if let Some(supply) = T::BrainTokenInspector::total_supply(contract) {
+            BrainTokenSupply::<T>::insert(contract, supply);
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
+        }
+        let supply: u128 = BrainTokenSupply::<T>::get(contract);
+
+        let mut holders: Vec<T::AccountId> =
+            BrainOwnerByTokenBalanceCut::<T>::iter_key_prefix(contract).collect();
+        let owner: T::AccountId = BrainOwner::<T>::get(netuid);
+        if !holders.contains(&owner) {
+            holders.push(owner);
+        }
+
+        for holder in holders.iter() {
+            let balance: u128 = T::BrainTokenInspector::balance_of(contract, holder)
+                .unwrap_or_else(|| BrainOwnerByTokenBalanceCut::<T>::get(contract, holder))
+                .min(supply);
+            Self::settle_brain_dividends(contract, holder);
+            if balance == 0 {
+                BrainOwnerByTokenBalanceCut::<T>::remove(contract, holder);
+            } else {
+                BrainOwnerByTokenBalanceCut::<T>::insert(contract, holder, balance);
+            }
+            Self::reset_brain_dividend_shares(netuid, contract, holder);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(8, 6));
+        }
+        weight
+    }
+
+    // Records an ERC20 Transfer log the EVM emitted, called by the EVM runner after every successful
+    // execution. Logs of contracts not bound to a brain are dropped, the rest are applied from the next block.
+    //
+    // # Returns:
+    //  * Whether the log was recorded, the runner charges `token_transfer_weight` as gas for each one.
+    //
+    pub fn note_token_transfer(contract: &T::AccountId, from: &T::AccountId, to: &T::AccountId, value: U256) -> bool {
+        if Self::get_contract_brain(contract).is_none() {
+            return false;
+        }
+        PendingTokenTransfers::<T>::append((contract.clone(), from.clone(), to.clone(), value));
+        true
+    }
+
+    // Returns the weight of recording, queueing and applying one Transfer log of a bound contract.
+    // Recording reads the brain of the contract and appends the log, queueing moves it to the
+    // transfer queue and applying it takes it from the queue and updates the tracked balances.
+    pub fn token_transfer_weight() -> Weight {
+        T::DbWeight::get().reads_writes(12, 13)
+    }
+
+    // Returns and clears the Transfer logs recorded since the last call.
+    pub fn take_token_transfers() -> Vec<(T::AccountId, T::AccountId, T::AccountId, U256)> {
+        PendingTokenTransfers::<T>::take()
+    }
+
+    // Returns the block a Transfer log of the contract last failed to apply at, if its tracked
+    // balances are out of sync with the contract. Seeding the contract again clears it.
+    pub fn get_brain_contract_desynced(contract: &T::AccountId) -> Option<u64> {
+        BrainContractDesynced::<T>::get(contract)
+    }
+
+    // Queues the ERC20 Transfer logs the EVM emitted in the previous block and applies up to
+    // MAX_TOKEN_TRANSFERS_PER_BLOCK queued logs to the balances of the brain contracts, in the order
+    // they were emitted. The rest stay queued for the next blocks. Transfers of contracts no longer
+    // bound to a brain are ignored, a transfer that cannot be applied marks the contract as desynced.
+    //
+    // # Event:
+    //  * 'BrainContractDesynced': Emitted when a transfer could not be applied.
+    //
+    // # Returns:
+    //  * The weight of the logs queued and the transfers applied.
+    //
+    pub fn sync_brain_token_transfers() -> Weight {
+        let transfers = T::TokenTransferLogs::transfers();
+        let mut weight: Weight = T::DbWeight::get().reads_writes(3, 1);
+
+        // --- 1. Queue the logs of the previous block behind the ones still waiting.
+        let mut head: u64 = TokenTransferQueueHead::<T>::get();
+        let mut tail: u64 = TokenTransferQueueTail::<T>::get();
+        if !transfers.is_empty() {
+            weight.saturating_accrue(T::DbWeight::get().writes(transfers.len() as u64 + 1));
+            for transfer in transfers {
+                TokenTransferQueue::<T>::insert(tail, transfer);
+                tail = tail.saturating_add(1);
+            }
+            TokenTransferQueueTail::<T>::put(tail);
+        }
+        if head == tail {
+            return weight;
+        }
+
+        // --- 2. Apply the oldest queued logs, up to the per block limit.
+        let end: u64 = head.saturating_add(MAX_TOKEN_TRANSFERS_PER_BLOCK).min(tail);
+        while head < end {
+            let queued = TokenTransferQueue::<T>::take(head);
+            head = head.saturating_add(1);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 1));
+            let (contract, from, to, value) = match queued {
+                Some(transfer) => transfer,
+                None => continue,
+            };
+            let netuid = match Self::get_contract_brain(&contract) {
+                Some(netuid) => netuid,
+                None => continue,
+            };
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(10, 10));
+            let applied = if value > U256::from(u128::MAX) {
+                Err(Error::<T>::BrainTokenBalanceOverflow.into())
+            } else {
+                Self::apply_brain_token_transfer(netuid, &contract, &from, &to, value.as_u128())
+            };
+            if let Err(e) = applied {
+                log::warn!(
+                    "BrainContractDesynced( contract:{:?}, from:{:?}, to:{:?}, value:{:?} ): {:?}",
+                    &contract,
+                    &from,
+                    &to,
+                    value,
+                    e
+                );
+                BrainContractDesynced::<T>::insert(&contract, Self::get_current_block_as_u64());
+                weight.saturating_accrue(T::DbWeight::get().writes(1));
+                Self::deposit_event(Event::BrainContractDesynced(netuid, contract));
+            }
+        }
+        TokenTransferQueueHead::<T>::put(head);
+        weight.saturating_accrue(T::DbWeight::get().writes(1));
+        weight
+    }
+
+    // Returns the number of Transfer logs waiting to be applied.
+    pub fn get_queued_token_transfers() -> u64 {
+        TokenTransferQueueTail::<T>::get().saturating_sub(TokenTransferQueueHead::<T>::get())
+    }
+
+    // Updates the tracked balances of the two holders of a Transfer log of a brain contract. The balances
+    // and the supply are read again from the storage of the contract, so the tracked values match it
+    // whatever happened in between. When they can not be read the transfer is applied to the tracked
+    // values instead: transfers from the zero address are mints and transfers to it are burns.
     //
     // # Args:
-    //  * 'origin': ('T::RuntimeOrigin'): The calling origin. Must be signed.
-    //  * 'contract': ('T::AccountId'): Contract owner
+    //  * 'netuid': ('u16'): The brain the contract is bound to.
+    //  * 'contract': ('T::AccountId'): Contract address
     //  * 'from': ('T::AccountId'): From address
     //  * 'to': ('T::AccountId'): To address
     //  * 'balance': ('u128'): Transfer balance
     //
     // # Event:
-    //  * 'BrainContractBalanceChanged': Emitted when a new network is successfully added.
+    //  * 'BrainContractBalanceChanged': Emitted when the balances are updated.
     //
     // # Raises:
-    //  * 'TxRateLimitExceeded': If the rate limit for network registration is exceeded.
-    //  * 'NotEnoughBalanceToStake': If there isn't enough balance to stake for network registration.
-    //  * 'BalanceWithdrawalError': If an error occurs during balance withdrawal for network registration.
-    //
-    pub fn brain_contract_balance_change(origin: T::RuntimeOrigin, contract: T::AccountId, from: T::AccountId, to: T::AccountId, balance: u128) -> dispatch::DispatchResult {
-        let _ = ensure_root(origin)?;
-        let mut contract_activated = false;
-        for (_, stored_contract) in BrainContract::<T>::iter() {
-            if contract == stored_contract {
-                contract_activated = true;
-                break;
+    //  * 'BrainTokenBalanceUnderflow': If the sender or the supply holds less than the transfer.
+    //  * 'BrainTokenBalanceOverflow': If the receiver or the supply would overflow.
+    //
+    pub fn apply_brain_token_transfer(netuid: u16, contract: &T::AccountId, from: &T::AccountId, to: &T::AccountId, balance: u128) -> dispatch::DispatchResult {
+        let zero_address: T::AccountId = DefaultAccount::<T>::get();
+        let is_mint: bool = *from == zero_address;
+        let is_burn: bool = *to == zero_address;
+
+        // Check all the arithmetic before anything is written.
+        let tracked_supply: u128 = BrainTokenSupply::<T>::get(contract);
+        let supply: u128 = match T::BrainTokenInspector::total_supply(contract) {
+            Some(supply) => supply,
+            None if is_mint => tracked_supply.checked_add(balance).ok_or(Error::<T>::BrainTokenBalanceOverflow)?,
+            None if is_burn => tracked_supply.checked_sub(balance).ok_or(Error::<T>::BrainTokenBalanceUnderflow)?,
+            None => tracked_supply,
+        };
+        let from_balance: Option<u128> = if is_mint {
+            None
+        } else {
+            match T::BrainTokenInspector::balance_of(contract, from) {
+                Some(from_balance) => Some(from_balance),
+                // A transfer to oneself leaves the balance as it is.
+                None if from == to => None,
+                None => Some(
+                    BrainOwnerByTokenBalanceCut::<T>::get(contract, from)
+                        .checked_sub(balance)
+                        .ok_or(Error::<T>::BrainTokenBalanceUnderflow)?,
+                ),
+            }
+        };
+        let to_balance: Option<u128> = if is_burn || from == to {
+            None
+        } else {
+            match T::BrainTokenInspector::balance_of(contract, to) {
+                Some(to_balance) => Some(to_balance),
+                None => Some(
+                    BrainOwnerByTokenBalanceCut::<T>::get(contract, to)
+                        .checked_add(balance)
+                        .ok_or(Error::<T>::BrainTokenBalanceOverflow)?,
+                ),
             }
+        };
+
+        // Settle the dividends earned on the balances before they change.
+        Self::settle_brain_dividends(contract, from);
+        Self::settle_brain_dividends(contract, to);
+
+        BrainTokenSupply::<T>::insert(contract, supply);
+        if let Some(from_balance) = from_balance {
+            BrainOwnerByTokenBalanceCut::<T>::insert(contract, from, from_balance);
+        }
+        if let Some(to_balance) = to_balance {
+            BrainOwnerByTokenBalanceCut::<T>::insert(contract, to, to_balance);
         }
 
-        if contract_activated {
-            // TODO(brain emission) could this be atomic?
-            let has_from = BrainOwnerByTokenBalanceCut::<T>::contains_key(contract.clone(), from.clone());
-            if has_from {
-                let previous_from = BrainOwnerByTokenBalanceCut::<T>::get(contract.clone(), from.clone());
-                BrainOwnerByTokenBalanceCut::<T>::set(contract.clone(), from.clone(), previous_from - balance);
-            } else {
-                // TODO(brain emission) should not happen?
-                BrainOwnerByTokenBalanceCut::<T>::insert(contract.clone(), from.clone(), 0);
-            }
+        Self::reset_brain_dividend_shares(netuid, contract, from);
+        Self::reset_brain_dividend_shares(netuid, contract, to);
 
-            let has_to = BrainOwnerByTokenBalanceCut::<T>::contains_key(contract.clone(), to.clone());
-            if has_to {
-                let previous_to = BrainOwnerByTokenBalanceCut::<T>::get(contract.clone(), to.clone());
-                BrainOwnerByTokenBalanceCut::<T>::set(contract.clone(), to.clone(), previous_to + balance);
-            } else {
-                BrainOwnerByTokenBalanceCut::<T>::insert(contract.clone(), to.clone(), balance);
-            }
+        log::debug!(
+            "BrainContractBalanceChanged( contract:{:?}, from:{:?}, to: {:?}, balance: {:?} )",
+            contract,
+            from,
+            to,
+            balance
+        );
+        Self::deposit_event(Event::BrainContractBalanceChanged(contract.clone(), from.clone(), to.clone(), balance));
+        Ok(())
+    }
+
+    // Records the account that deployed an EVM contract, called by the EVM when a contract is created.
+    pub fn note_contract_created(deployer: &T::AccountId, contract: &T::AccountId) {
+        ContractDeployer::<T>::insert(contract, deployer);
+    }
+
+    // Returns the account that deployed the EVM contract, if it was deployed through the EVM.
+    pub fn get_contract_deployer(contract: &T::AccountId) -> Option<T::AccountId> {
+        ContractDeployer::<T>::get(contract)
+    }
 
-            log::debug!(
-                "BrainContractBalanceChanged( contract:{:?}, from:{:?}, to: {:?}, balance: {:?} )",
+    // Binds an ERC20 contract the brain owner deployed to the brain. The token supply and the
+    // balance of the owner are seeded from the storage of the contract, the balances a later
+    // Transfer log touches are read from it again.
+    //
+    // # Args:
+    //  * 'origin': ('T::RuntimeOrigin'): The brain owner, must have deployed the contract.
+    //  * 'netuid': ('u16'): The brain to bind the contract to.
+    //  * 'contract': ('T::AccountId'): Contract address
+    //
+    // # Event:
+    //  * 'BrainOwnerContractCreated': Emitted when the contract is bound.
+    //
+    // # Raises:
+    //  * 'NetworkDoesNotExist': If the brain does not exist.
+    //  * 'NotBrainOwner': If the caller does not own the brain.
+    //  * 'NotContractDeployer': If the caller did not deploy the contract.
+    //  * 'NotErc20Contract': If the contract code does not implement the ERC20 interface.
+    //  * 'BrainContractAlreadyBound': If the brain or the contract is already bound.
+    //  * 'BrainTokenStorageMismatch': If the contract storage reads a zero supply or an owner balance above it.
+    //
+    pub fn do_bind_brain_contract(origin: T::RuntimeOrigin, netuid: u16, contract: T::AccountId) -> dispatch::DispatchResult {
+        // --- 1. Ensure the caller owns an existing brain.
+        let owner = ensure_signed(origin)?;
+        ensure!(
+            Self::if_brain_exist(netuid),
+            Error::<T>::NetworkDoesNotExist
+        );
+        ensure!(
+            BrainOwner::<T>::get(netuid) == owner,
+            Error::<T>::NotBrainOwner
+        );
+
+        // --- 2. Ensure the owner deployed the contract and it is an ERC20 token.
+        ensure!(
+            Self::get_contract_deployer(&contract) == Some(owner.clone()),
+            Error::<T>::NotContractDeployer
+        );
+        ensure!(
+            T::BrainTokenInspector::is_erc20(&contract),
+            Error::<T>::NotErc20Contract
+        );
+
+        // --- 3. Ensure neither the brain nor the contract is bound yet.
+        ensure!(
+            !BrainContract::<T>::contains_key(netuid)
+                && Self::get_contract_brain(&contract).is_none(),
+            Error::<T>::BrainContractAlreadyBound
+        );
+
+        // --- 4. Ensure the supply and the owner balance read from the contract storage are a valid ERC20
+        // state, a contract with another storage layout reads a zero supply or balances above it.
+        let supply: u128 = T::BrainTokenInspector::total_supply(&contract).unwrap_or(0);
+        let owner_balance: u128 = T::BrainTokenInspector::balance_of(&contract, &owner).unwrap_or(0);
+        ensure!(
+            supply > 0 && owner_balance <= supply,
+            Error::<T>::BrainTokenStorageMismatch
+        );
+
+        // --- 5. Bind the contract and seed the supply and the balance the owner minted before it was bound.
+        Self::set_brain_contract_created(netuid, &contract);
+        Self::seed_brain_token_balances(netuid, &contract);
+
+        log::info!(
+            "BrainOwnerContractCreated( netuid:{:?}, contract:{:?}, supply:{:?} )",
+            netuid,
+            &contract,
+            supply
+        );
+        Self::deposit_event(Event::BrainOwnerContractCreated(netuid, contract));
+        Ok(())
+    }
+
+    // Returns the brain the ERC20 contract is bound to.
+    pub fn get_contract_brain(contract: &T::AccountId) -> Option<u16> {
+        ContractBrain::<T>::get(contract)
+    }
+
+    // Returns the part of the holder balance that earns brain dividends.
+    // Holders below the brain token holder threshold do not take part in the holder cut.
+    pub fn get_eligible_token_balance(netuid: u16, contract: &T::AccountId, holder: &T::AccountId) -> u128 {
+        let balance: u128 = BrainOwnerByTokenBalanceCut::<T>::get(contract, holder);
+        let threshold: U256 = U256::from(BrainTokenSupply::<T>::get(contract))
+            .saturating_mul(U256::from(Self::get_token_holder_threshold(netuid)))
+            / U256::from(u16::MAX);
+        if balance == 0 || U256::from(balance) < threshold {
+            return 0;
+        }
+        balance
+    }
+
+    // Returns the dividends the shares have earned at the given dividend index.
+    fn dividends_for_shares(shares: u128, dividends_per_token: U256) -> u128 {
+        let dividends: U256 = U256::from(shares)
+            .saturating_mul(dividends_per_token)
+            / U256::from(BRAIN_DIVIDEND_PRECISION);
+        if dividends > U256::from(u128::MAX) {
+            return u128::MAX;
+        }
+        dividends.as_u128()
+    }
+
+    // Adds the holder cut of a block to the contract dividend index.
+    // The cut is expressed for the whole token supply, each eligible holder earns it pro-rata
+    // to its balance. Returns the part of the cut that was accrued to eligible holders.
+    pub fn accrue_brain_dividends(contract: &T::AccountId, holders_cut: u128) -> u128 {
+        let token_supply: u128 = BrainTokenSupply::<T>::get(contract);
+        let eligible_supply: u128 = BrainEligibleTokenSupply::<T>::get(contract);
+        if token_supply == 0 || eligible_supply == 0 || holders_cut == 0 {
+            return 0;
+        }
+        let dividends_per_token: U256 = U256::from(holders_cut)
+            .saturating_mul(U256::from(BRAIN_DIVIDEND_PRECISION))
+            / U256::from(token_supply);
+        BrainDividendsPerToken::<T>::mutate(contract, |index| {
+            *index = index.saturating_add(dividends_per_token)
+        });
+        Self::dividends_for_shares(eligible_supply, dividends_per_token)
+    }
+
+    // Returns the dividends earned by the holder shares since its last settlement.
+    fn unsettled_brain_dividends(contract: &T::AccountId, holder: &T::AccountId) -> u128 {
+        let earned: u128 = Self::dividends_for_shares(
+            BrainDividendShares::<T>::get(contract, holder),
+            BrainDividendsPerToken::<T>::get(contract),
+        );
+        earned.saturating_sub(BrainDividendDebt::<T>::get(contract, holder))
+    }
+
+    // Moves the dividends earned by the holder since its last settlement into its pending dividends.
+    pub fn settle_brain_dividends(contract: &T::AccountId, holder: &T::AccountId) {
+        let owed: u128 = Self::unsettled_brain_dividends(contract, holder);
+        if owed > 0 {
+            PendingBrainDividends::<T>::mutate(contract, holder, |pending| {
+                *pending = pending.saturating_add(owed)
+            });
+        }
+    }
+
+    // Snapshots the holder balance that earns dividends from now on and marks the dividends
+    // on it as accounted for. Must be called after settling.
+    //
+    // Changes to the token supply or the holder threshold apply to a holder from its next
+    // balance change or claim.
+    pub fn reset_brain_dividend_shares(netuid: u16, contract: &T::AccountId, holder: &T::AccountId) {
+        let previous_shares: u128 = BrainDividendShares::<T>::get(contract, holder);
+        let shares: u128 = Self::get_eligible_token_balance(netuid, contract, holder);
+        BrainEligibleTokenSupply::<T>::mutate(contract, |supply| {
+            *supply = supply.saturating_sub(previous_shares).saturating_add(shares)
+        });
+
+        let debt: u128 = Self::dividends_for_shares(shares, BrainDividendsPerToken::<T>::get(contract));
+        if shares == 0 {
+            BrainDividendShares::<T>::remove(contract, holder);
+            BrainDividendDebt::<T>::remove(contract, holder);
+        } else {
+            BrainDividendShares::<T>::insert(contract, holder, shares);
+            BrainDividendDebt::<T>::insert(contract, holder, debt);
+        }
+    }
+
+    // Returns the dividends the holder can claim on the contract right now.
+    pub fn get_claimable_brain_dividends(contract: &T::AccountId, holder: &T::AccountId) -> u128 {
+        PendingBrainDividends::<T>::get(contract, holder)
+            .saturating_add(Self::unsettled_brain_dividends(contract, holder))
+    }
+
+    // Pays the brain dividends accrued to the holder onto its account.
+    //
+    // # Args:
+    //  * 'origin': ('T::RuntimeOrigin'): The token holder. Must be signed.
+    //  * 'contract': ('T::AccountId'): The brain ERC20 contract.
+    //
+    // # Event:
+    //  * 'BrainDividendsClaimed': Emitted when the dividends are paid out.
+    //
+    // # Raises:
+    //  * 'NoBrainDividendsToClaim': If nothing has accrued to the holder since its last claim.
+    //
+    pub fn do_claim_brain_dividends(origin: T::RuntimeOrigin, contract: T::AccountId) -> dispatch::DispatchResult {
+        let holder = ensure_signed(origin)?;
+
+        let amount: u128 = Self::get_claimable_brain_dividends(&contract, &holder);
+        ensure!(amount > 0, Error::<T>::NoBrainDividendsToClaim);
+        let amount_as_balance = Self::u128_to_balance(amount);
+        ensure!(
+            amount_as_balance.is_some(),
+            Error::<T>::CouldNotConvertToBalance
+        );
+
+        Self::settle_brain_dividends(&contract, &holder);
+        if let Some(netuid) = Self::get_contract_brain(&contract) {
+            Self::reset_brain_dividend_shares(netuid, &contract, &holder);
+        } else {
+            BrainDividendDebt::<T>::insert(
                 &contract,
-                &from,
-                &to,
-                balance
+                &holder,
+                Self::dividends_for_shares(
+                    BrainDividendShares::<T>::get(&contract, &holder),
+                    BrainDividendsPerToken::<T>::get(&contract),
+                ),
             );
-            Self::deposit_event(Event::BrainContractBalanceChanged(contract.clone(), from.clone(), to.clone(), balance));
         }
+        PendingBrainDividends::<T>::remove(&contract, &holder);
+        Self::add_balance_to_personalkey_account(&holder, amount_as_balance.unwrap());
+        TotalIssuance::<T>::put(TotalIssuance::<T>::get().saturating_add(amount));
+
+        log::info!(
+            "BrainDividendsClaimed( contract:{:?}, holder:{:?}, amount:{:?} )",
+            &contract,
+            &holder,
+            amount
+        );
+        Self::deposit_event(Event::BrainDividendsClaimed(contract, holder, amount));
         Ok(())
     }
 
@@ -1048,6 +1746,19 @@ impl<T: Config> Pallet<T> {
         // --- 10. Erase network parameters.
         Tempo::<T>::remove(netuid);
         Kappa::<T>::remove(netuid);
+        OwnerCut::<T>::remove(netuid);
+        TokenHolderThreshold::<T>::remove(netuid);
+        if let Ok(contract) = BrainContract::<T>::try_get(netuid) {
+            ContractBrain::<T>::remove(contract);
+        }
+        BrainContract::<T>::remove(netuid);
+        PendingBrainOwner::<T>::remove(netuid);
+        let _ = BrainRoles::<T>::clear_prefix(netuid, u32::max_value(), None);
+        PendingHyperparamChanges::<T>::remove(netuid);
+        Self::remove_brain_metadata(netuid);
+        CommitRevealWeightsEnabled::<T>::remove(netuid);
+        let _ = WeightCommits::<T>::clear_prefix(netuid, u32::max_value(), None);
+        BrainConsensusMechanism::<T>::remove(netuid);
         Difficulty::<T>::remove(netuid);
         MaxAllowedUids::<T>::remove(netuid);
         ImmunityPeriod::<T>::remove(netuid);
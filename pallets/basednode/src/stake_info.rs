@@ -9,7 +9,9 @@ use sp_core::hexdisplay::AsBytesRef;
 pub struct StakeInfo<T: Config> {
     computekey: T::AccountId,
     personalkey: T::AccountId,
-    stake: Compact<u64>,
+    stake: Compact<u128>,
+    unbonding: Vec<(Compact<u128>, Compact<u64>)>, // Vec of ( amount, unlock_block ) removed from this computekey and not yet withdrawn
+    reward_destination: RewardDestination<T::AccountId>, // Where the emission earned by this stake is paid
 }
 
 impl<T: Config> Pallet<T> {
@@ -23,13 +25,22 @@ impl<T: Config> Pallet<T> {
         let mut stake_info: Vec<(T::AccountId, Vec<StakeInfo<T>>)> = Vec::new();
         for personalkey_ in personalkeys {
             let mut stake_info_for_personalkey: Vec<StakeInfo<T>> = Vec::new();
+            let unbonding_for_personalkey = Self::get_unbonding_for_personalkey(&personalkey_);
 
             for (computekey, personalkey, stake) in <Stake<T>>::iter() {
                 if personalkey == personalkey_ {
+                    let unbonding: Vec<(Compact<u128>, Compact<u64>)> = unbonding_for_personalkey
+                        .iter()
+                        .filter(|(unbonding_computekey, _, _)| *unbonding_computekey == computekey)
+                        .map(|(_, amount, unlock_block)| (Compact(*amount), Compact(*unlock_block)))
+                        .collect();
+                    let reward_destination = Self::get_reward_destination(&personalkey, &computekey);
                     stake_info_for_personalkey.push(StakeInfo {
                         computekey,
                         personalkey,
                         stake: stake.into(),
+                        unbonding,
+                        reward_destination,
                     });
                 }
             }
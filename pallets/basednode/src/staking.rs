@@ -1,5 +1,11 @@
 use super::*;
 use frame_support::storage::IterableStorageDoubleMap;
+use frame_support::inherent::Vec;
+use frame_support::traits::{FindAuthor, Get, Imbalance};
+use frame_support::weights::Weight;
+
+// The most unbonding chunks a personalkey can have queued, which bounds the work of withdraw_unbonded.
+pub const MAX_UNBONDING_CHUNKS: usize = 32;
 
 impl<T: Config> Pallet<T> {
     // ---- The implementation for the extrinsic become_delegate: signals that this computekey allows delegated stake.
@@ -86,6 +92,208 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    // ---- The implementation for the extrinsic decrease_take: lowers the take of a delegate immediately.
+    //
+    // # Args:
+    // 	* 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    // 		- The signature of the caller's personalkey.
+    //
+    // 	* 'computekey' (T::AccountId):
+    // 		- The delegate computekey (must be owned by the personalkey.)
+    //
+    // 	* 'take' (u16):
+    // 		- The new stake proportion that this computekey takes from delegations.
+    //
+    // # Event:
+    // 	* DelegateTakeDecreased;
+    // 		- On successfully lowering the delegate take.
+    //
+    // # Raises:
+    // 	* 'NotDelegate':
+    // 		- The computekey is not a delegate.
+    //
+    // 	* 'NonAssociatedpersonalkey':
+    // 		- The computekey is not owned by the calling personalkey.
+    //
+    // 	* 'DelegateTakeTooLow':
+    // 		- The new take is not below the current take.
+    //
+    pub fn do_decrease_take(
+        origin: T::RuntimeOrigin,
+        computekey: T::AccountId,
+        take: u16,
+    ) -> dispatch::DispatchResult {
+        // --- 1. We check the personalkey signuture.
+        let personalkey = ensure_signed(origin)?;
+        log::info!(
+            "do_decrease_take( origin:{:?} computekey:{:?}, take:{:?} )",
+            personalkey,
+            computekey,
+            take
+        );
+
+        // --- 2. Ensure the computekey is a delegate.
+        ensure!(
+            Self::computekey_is_delegate(&computekey),
+            Error::<T>::NotDelegate
+        );
+
+        // --- 3. Ensure that the personalkey is the owner.
+        ensure!(
+            Self::personalkey_owns_computekey(&personalkey, &computekey),
+            Error::<T>::NonAssociatedpersonalkey
+        );
+
+        // --- 4. Ensure the new take is lower than the current one.
+        ensure!(
+            take < Self::get_delegate_take(&computekey),
+            Error::<T>::DelegateTakeTooLow
+        );
+
+        // --- 5. Apply the new take and drop any announced increase.
+        Self::delegate_computekey(&computekey, take);
+        PendingDelegateTake::<T>::remove(&computekey);
+
+        // --- 6. Emit the take event.
+        log::info!(
+            "DelegateTakeDecreased( personalkey:{:?}, computekey:{:?}, take:{:?} )",
+            personalkey,
+            computekey,
+            take
+        );
+        Self::deposit_event(Event::DelegateTakeDecreased(personalkey, computekey, take));
+
+        // --- 7. Ok and return.
+        Ok(())
+    }
+
+    // ---- The implementation for the extrinsic increase_take: announces a take increase which applies
+    // after the delegate take increase delay.
+    //
+    // # Args:
+    // 	* 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    // 		- The signature of the caller's personalkey.
+    //
+    // 	* 'computekey' (T::AccountId):
+    // 		- The delegate computekey (must be owned by the personalkey.)
+    //
+    // 	* 'take' (u16):
+    // 		- The new stake proportion that this computekey takes from delegations.
+    //
+    // # Event:
+    // 	* DelegateTakeIncreaseAnnounced;
+    // 		- On successfully announcing the take increase.
+    //
+    // # Raises:
+    // 	* 'NotDelegate':
+    // 		- The computekey is not a delegate.
+    //
+    // 	* 'NonAssociatedpersonalkey':
+    // 		- The computekey is not owned by the calling personalkey.
+    //
+    // 	* 'DelegateTakeTooHigh':
+    // 		- The new take is not above the current take or exceeds the default take.
+    //
+    // 	* 'TxRateLimitExceeded':
+    // 		- Thrown if key has hit transaction rate limit
+    //
+    pub fn do_increase_take(
+        origin: T::RuntimeOrigin,
+        computekey: T::AccountId,
+        take: u16,
+    ) -> dispatch::DispatchResult {
+        // --- 1. We check the personalkey signuture.
+        let personalkey = ensure_signed(origin)?;
+        log::info!(
+            "do_increase_take( origin:{:?} computekey:{:?}, take:{:?} )",
+            personalkey,
+            computekey,
+            take
+        );
+
+        // --- 2. Ensure the computekey is a delegate.
+        ensure!(
+            Self::computekey_is_delegate(&computekey),
+            Error::<T>::NotDelegate
+        );
+
+        // --- 3. Ensure that the personalkey is the owner.
+        ensure!(
+            Self::personalkey_owns_computekey(&personalkey, &computekey),
+            Error::<T>::NonAssociatedpersonalkey
+        );
+
+        // --- 4. Ensure the new take is above the current one and does not exceed the default take.
+        ensure!(
+            take > Self::get_delegate_take(&computekey) && take <= Self::get_default_take(),
+            Error::<T>::DelegateTakeTooHigh
+        );
+
+        // --- 5. Ensure we don't exceed tx rate limit
+        let block: u64 = Self::get_current_block_as_u64();
+        ensure!(
+            !Self::exceeds_tx_rate_limit(Self::get_last_tx_block(&personalkey), block),
+            Error::<T>::TxRateLimitExceeded
+        );
+
+        // --- 6. Store the increase as pending, replacing any earlier announcement. Increases are applied
+        // by block_step, so at the earliest in the next block.
+        let apply_at_block: u64 = block.saturating_add(Self::get_delegate_take_increase_delay().max(1));
+        PendingDelegateTake::<T>::insert(&computekey, (take, apply_at_block));
+        PendingDelegateTakeQueue::<T>::append(apply_at_block, &computekey);
+
+        // Set last block for rate limiting
+        Self::set_last_tx_block(&personalkey, block);
+
+        // --- 7. Emit the announcement event.
+        log::info!(
+            "DelegateTakeIncreaseAnnounced( personalkey:{:?}, computekey:{:?}, take:{:?}, apply_at_block:{:?} )",
+            personalkey,
+            computekey,
+            take,
+            apply_at_block
+        );
+        Self::deposit_event(Event::DelegateTakeIncreaseAnnounced(
+            personalkey,
+            computekey,
+            take,
+            apply_at_block,
+        ));
+
+        // --- 8. Ok and return.
+        Ok(())
+    }
+
+    // Applies the delegate take increases announced to apply at this block. Returns the weight used.
+    //
+    pub fn apply_pending_delegate_takes(block_number: u64) -> Weight {
+        let mut weight: Weight = T::DbWeight::get().reads_writes(1, 1);
+        let queued: Vec<T::AccountId> = PendingDelegateTakeQueue::<T>::take(block_number);
+
+        for computekey in queued {
+            weight.saturating_accrue(T::DbWeight::get().reads(1));
+            // Increases replaced by a later announcement or cancelled by a decrease are skipped.
+            let take: u16 = match PendingDelegateTake::<T>::get(&computekey) {
+                Some((take, apply_at_block)) if apply_at_block == block_number => take,
+                _ => continue,
+            };
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            PendingDelegateTake::<T>::remove(&computekey);
+            // The delegate may have been removed while the increase was pending.
+            if !Self::computekey_is_delegate(&computekey) {
+                continue;
+            }
+            Self::delegate_computekey(&computekey, take);
+            log::info!(
+                "DelegateTakeIncreased( computekey:{:?}, take:{:?} )",
+                computekey,
+                take
+            );
+            Self::deposit_event(Event::DelegateTakeIncreased(computekey, take));
+        }
+        weight
+    }
+
     // ---- The implementation for the extrinsic add_stake: Adds stake to a computekey account.
     //
     // # Args:
@@ -95,7 +303,7 @@ impl<T: Config> Pallet<T> {
     // 	* 'computekey' (T::AccountId):
     // 		- The associated computekey account.
     //
-    // 	* 'stake_to_be_added' (u64):
+    // 	* 'stake_to_be_added' (u128):
     // 		- The amount of stake to be added to the computekey staking account.
     //
     // # Event:
@@ -121,7 +329,7 @@ impl<T: Config> Pallet<T> {
     pub fn do_add_stake(
         origin: T::RuntimeOrigin,
         computekey: T::AccountId,
-        stake_to_be_added: u64,
+        stake_to_be_added: u128,
     ) -> dispatch::DispatchResult {
         // --- 1. We check that the transaction is signed by the caller and retrieve the T::AccountId personalkey information.
         let personalkey = ensure_signed(origin)?;
@@ -132,8 +340,8 @@ impl<T: Config> Pallet<T> {
             stake_to_be_added
         );
 
-        // --- 2. We convert the stake u64 into a balancer.
-        let stake_as_balance = Self::u64_to_balance(stake_to_be_added);
+        // --- 2. We convert the stake u128 into a balancer.
+        let stake_as_balance = Self::u128_to_balance(stake_to_be_added);
         ensure!(
             stake_as_balance.is_some(),
             Error::<T>::CouldNotConvertToBalance
@@ -197,7 +405,7 @@ impl<T: Config> Pallet<T> {
     // 	* 'computekey' (T::AccountId):
     // 		- The associated computekey account.
     //
-    // 	* 'stake_to_be_added' (u64):
+    // 	* 'stake_to_be_added' (u128):
     // 		- The amount of stake to be added to the computekey staking account.
     //
     // # Event:
@@ -220,11 +428,13 @@ impl<T: Config> Pallet<T> {
     // 	* 'TxRateLimitExceeded':
     // 		- Thrown if key has hit transaction rate limit
     //
+    // 	* 'TooManyUnbondingChunks':
+    // 		- Thrown if the personalkey already has the most unbonding chunks queued.
     //
     pub fn do_remove_stake(
         origin: T::RuntimeOrigin,
         computekey: T::AccountId,
-        stake_to_be_removed: u64,
+        stake_to_be_removed: u128,
     ) -> dispatch::DispatchResult {
         // --- 1. We check the transaction is signed by the caller and retrieve the T::AccountId personalkey information.
         let personalkey = ensure_signed(origin)?;
@@ -259,8 +469,8 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NotEnoughStaketoWithdraw
         );
 
-        // --- 5. Ensure that we can conver this u64 to a balance.
-        let stake_to_be_added_as_currency = Self::u64_to_balance(stake_to_be_removed);
+        // --- 5. Ensure that we can conver this u128 to a balance.
+        let stake_to_be_added_as_currency = Self::u128_to_balance(stake_to_be_removed);
         ensure!(
             stake_to_be_added_as_currency.is_some(),
             Error::<T>::CouldNotConvertToBalance
@@ -273,11 +483,23 @@ impl<T: Config> Pallet<T> {
             Error::<T>::TxRateLimitExceeded
         );
 
-        // --- 7. We remove the balance from the computekey.
+        // --- 6a. Ensure there is room to queue the stake as unbonding.
+        let unbonding_period: u64 = Self::get_unbonding_period();
+        let unlock_block: u64 = block.saturating_add(unbonding_period);
+        ensure!(
+            unbonding_period == 0 || Self::can_add_unbonding_chunk(&personalkey, &computekey, unlock_block),
+            Error::<T>::TooManyUnbondingChunks
+        );
+
+        // --- 7. We remove the balance from the computekey. From here on it no longer counts towards the computekey stake.
         Self::decrease_stake_on_personalkey_computekey_account(&personalkey, &computekey, stake_to_be_removed);
 
-        // --- 8. We add the balancer to the personalkey.  If the above fails we will not credit this personalkey.
-        Self::add_balance_to_personalkey_account(&personalkey, stake_to_be_added_as_currency.unwrap());
+        // --- 8. We queue the stake as unbonding, or credit the personalkey right away when there is no unbonding period.
+        if unbonding_period == 0 {
+            Self::add_balance_to_personalkey_account(&personalkey, stake_to_be_added_as_currency.unwrap());
+        } else {
+            Self::add_unbonding_chunk(&personalkey, &computekey, stake_to_be_removed, unlock_block);
+        }
 
         // Set last block for rate limiting
         Self::set_last_tx_block(&personalkey, block);
@@ -294,6 +516,294 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    // ---- The implementation for the extrinsic move_stake: Moves stake of a personalkey from one computekey onto another.
+    //
+    // # Args:
+    // 	* 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    // 		- The signature of the caller's personalkey.
+    //
+    // 	* 'origin_computekey' (T::AccountId):
+    // 		- The computekey the stake is currently on.
+    //
+    // 	* 'destination_computekey' (T::AccountId):
+    // 		- The computekey the stake is moved onto.
+    //
+    // 	* 'stake_to_be_moved' (u128):
+    // 		- The amount of stake to move.
+    //
+    // # Event:
+    // 	* StakeMoved;
+    // 		- On successfully moving the stake.
+    //
+    // # Raises:
+    // 	* 'NotRegistered':
+    // 		- Thrown if either computekey account does not exist.
+    //
+    // 	* 'SameComputekey':
+    // 		- Thrown if the origin and destination computekey are the same.
+    //
+    // 	* 'NonAssociatedpersonalkey':
+    // 		- Thrown if the destination is neither a delegate nor owned by the personalkey.
+    //
+    // 	* 'NotEnoughStaketoWithdraw':
+    // 		- Thrown if there is not enough stake on the origin computekey to move this amount.
+    //
+    // 	* 'TxRateLimitExceeded':
+    // 		- Thrown if key has hit transaction rate limit
+    //
+    pub fn do_move_stake(
+        origin: T::RuntimeOrigin,
+        origin_computekey: T::AccountId,
+        destination_computekey: T::AccountId,
+        stake_to_be_moved: u128,
+    ) -> dispatch::DispatchResult {
+        // --- 1. We check the transaction is signed by the caller and retrieve the T::AccountId personalkey information.
+        let personalkey = ensure_signed(origin)?;
+        log::info!(
+            "do_move_stake( origin:{:?} origin_computekey:{:?}, destination_computekey:{:?}, stake_to_be_moved:{:?} )",
+            personalkey,
+            origin_computekey,
+            destination_computekey,
+            stake_to_be_moved
+        );
+
+        // --- 2. Ensure that both computekey accounts exist.
+        ensure!(
+            Self::computekey_account_exists(&origin_computekey)
+                && Self::computekey_account_exists(&destination_computekey),
+            Error::<T>::NotRegistered
+        );
+
+        // --- 3. Ensure the stake actually changes computekey.
+        ensure!(
+            origin_computekey != destination_computekey,
+            Error::<T>::SameComputekey
+        );
+
+        // --- 4. Ensure that the destination allows delegation or is owned by the calling personalkey.
+        ensure!(
+            Self::computekey_is_delegate(&destination_computekey)
+                || Self::personalkey_owns_computekey(&personalkey, &destination_computekey),
+            Error::<T>::NonAssociatedpersonalkey
+        );
+
+        // --- 5. Ensure that the personalkey has enough stake on the origin computekey.
+        ensure!(
+            stake_to_be_moved > 0,
+            Error::<T>::NotEnoughStaketoWithdraw
+        );
+        ensure!(
+            Self::has_enough_stake(&personalkey, &origin_computekey, stake_to_be_moved),
+            Error::<T>::NotEnoughStaketoWithdraw
+        );
+
+        // --- 6. Ensure we don't exceed tx rate limit
+        let block: u64 = Self::get_current_block_as_u64();
+        ensure!(
+            !Self::exceeds_tx_rate_limit(Self::get_last_tx_block(&personalkey), block),
+            Error::<T>::TxRateLimitExceeded
+        );
+
+        // --- 7. Move the stake between the computekeys.
+        Self::move_stake_on_personalkey_computekey_accounts(
+            &personalkey,
+            &origin_computekey,
+            &destination_computekey,
+            stake_to_be_moved,
+        );
+
+        // Set last block for rate limiting
+        Self::set_last_tx_block(&personalkey, block);
+
+        // --- 8. Emit the move event.
+        log::info!(
+            "StakeMoved( personalkey:{:?}, origin_computekey:{:?}, destination_computekey:{:?}, stake_to_be_moved:{:?} )",
+            personalkey,
+            origin_computekey,
+            destination_computekey,
+            stake_to_be_moved
+        );
+        Self::deposit_event(Event::StakeMoved(
+            personalkey,
+            origin_computekey,
+            destination_computekey,
+            stake_to_be_moved,
+        ));
+
+        // --- 9. Done and ok.
+        Ok(())
+    }
+
+    // ---- The implementation for the extrinsic set_reward_destination: Sets where the emission of a position is paid.
+    //
+    // # Args:
+    // 	* 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    // 		- The signature of the caller's personalkey.
+    //
+    // 	* 'computekey' (T::AccountId):
+    // 		- The computekey the stake is on.
+    //
+    // 	* 'destination' (RewardDestination<T::AccountId>):
+    // 		- Where the rewards of this position are paid.
+    //
+    // # Event:
+    // 	* RewardDestinationSet;
+    // 		- On successfully setting the reward destination.
+    //
+    // # Raises:
+    // 	* 'NotRegistered':
+    // 		- Thrown if the computekey account does not exist.
+    //
+    pub fn do_set_reward_destination(
+        origin: T::RuntimeOrigin,
+        computekey: T::AccountId,
+        destination: RewardDestination<T::AccountId>,
+    ) -> dispatch::DispatchResult {
+        // --- 1. We check the transaction is signed by the caller and retrieve the T::AccountId personalkey information.
+        let personalkey = ensure_signed(origin)?;
+        log::info!(
+            "do_set_reward_destination( origin:{:?} computekey:{:?}, destination:{:?} )",
+            personalkey,
+            computekey,
+            destination
+        );
+
+        // --- 2. Ensure that the computekey account exists.
+        ensure!(
+            Self::computekey_account_exists(&computekey),
+            Error::<T>::NotRegistered
+        );
+
+        // --- 3. Store the destination. Compounding is the default so it is not stored.
+        if destination == RewardDestination::Stake {
+            RewardDestinations::<T>::remove(&computekey, &personalkey);
+        } else {
+            RewardDestinations::<T>::insert(&computekey, &personalkey, destination.clone());
+        }
+
+        // --- 4. Emit the event.
+        Self::deposit_event(Event::RewardDestinationSet(personalkey, computekey, destination));
+
+        // --- 5. Done and ok.
+        Ok(())
+    }
+
+    // Returns where the emission earned by the cold - hot position is paid.
+    //
+    pub fn get_reward_destination(
+        personalkey: &T::AccountId,
+        computekey: &T::AccountId,
+    ) -> RewardDestination<T::AccountId> {
+        return RewardDestinations::<T>::get(computekey, personalkey).unwrap_or_default();
+    }
+
+    // ---- The implementation for the extrinsic withdraw_unbonded: releases unlocked unbonding stake onto the personalkey.
+    //
+    // # Args:
+    // 	* 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    // 		- The signature of the caller's personalkey.
+    //
+    // # Event:
+    // 	* StakeWithdrawn;
+    // 		- On successfully releasing the unbonded stake.
+    //
+    // # Raises:
+    // 	* 'NoUnbondedStake':
+    // 		- Thrown if none of the unbonding stake has unlocked yet.
+    //
+    // 	* 'CouldNotConvertToBalance':
+    // 		- Unable to convert the unlocked stake to a balance.
+    //
+    pub fn do_withdraw_unbonded(origin: T::RuntimeOrigin) -> dispatch::DispatchResult {
+        // --- 1. We check the transaction is signed by the caller and retrieve the T::AccountId personalkey information.
+        let personalkey = ensure_signed(origin)?;
+        log::info!("do_withdraw_unbonded( origin:{:?} )", personalkey);
+
+        // --- 2. Split the queue into unlocked and still unbonding chunks.
+        let block: u64 = Self::get_current_block_as_u64();
+        let (unlocked, unbonding): (Vec<_>, Vec<_>) = Unbonding::<T>::get(&personalkey)
+            .into_iter()
+            .partition(|(_, _, unlock_block)| *unlock_block <= block);
+        let amount: u128 = unlocked
+            .iter()
+            .fold(0u128, |total, (_, chunk, _)| total.saturating_add(*chunk));
+        ensure!(amount > 0, Error::<T>::NoUnbondedStake);
+
+        // --- 3. Ensure that we can conver this u128 to a balance.
+        let amount_as_currency = Self::u128_to_balance(amount);
+        ensure!(
+            amount_as_currency.is_some(),
+            Error::<T>::CouldNotConvertToBalance
+        );
+
+        // --- 4. Keep the remaining chunks and credit the personalkey.
+        if unbonding.is_empty() {
+            Unbonding::<T>::remove(&personalkey);
+        } else {
+            Unbonding::<T>::insert(&personalkey, unbonding);
+        }
+        Self::add_balance_to_personalkey_account(&personalkey, amount_as_currency.unwrap());
+
+        // --- 5. Emit the withdraw event.
+        log::info!(
+            "StakeWithdrawn( personalkey:{:?}, amount:{:?} )",
+            personalkey,
+            amount
+        );
+        Self::deposit_event(Event::StakeWithdrawn(personalkey, amount));
+
+        // --- 6. Done and ok.
+        Ok(())
+    }
+
+    // Queues stake removed from the computekey as unbonding until the unlock block.
+    // Chunks for the same computekey unlocking in the same block are merged.
+    //
+    pub fn add_unbonding_chunk(
+        personalkey: &T::AccountId,
+        computekey: &T::AccountId,
+        amount: u128,
+        unlock_block: u64,
+    ) {
+        Unbonding::<T>::mutate(personalkey, |chunks| {
+            match chunks
+                .iter_mut()
+                .find(|(ck, _, block)| ck == computekey && *block == unlock_block)
+            {
+                Some((_, chunk, _)) => *chunk = chunk.saturating_add(amount),
+                None => chunks.push((computekey.clone(), amount, unlock_block)),
+            }
+        });
+    }
+
+    // Returns true if the chunk merges into a queued one or the personalkey has fewer than MAX_UNBONDING_CHUNKS queued.
+    //
+    pub fn can_add_unbonding_chunk(
+        personalkey: &T::AccountId,
+        computekey: &T::AccountId,
+        unlock_block: u64,
+    ) -> bool {
+        let chunks: Vec<(T::AccountId, u128, u64)> = Unbonding::<T>::get(personalkey);
+        chunks.len() < MAX_UNBONDING_CHUNKS
+            || chunks
+                .iter()
+                .any(|(ck, _, block)| ck == computekey && *block == unlock_block)
+    }
+
+    // Returns the unbonding chunks ( computekey, amount, unlock_block ) of a personalkey.
+    //
+    pub fn get_unbonding_for_personalkey(personalkey: &T::AccountId) -> Vec<(T::AccountId, u128, u64)> {
+        return Unbonding::<T>::get(personalkey);
+    }
+
+    // Returns the total amount of stake the personalkey is unbonding.
+    //
+    pub fn get_total_unbonding_for_personalkey(personalkey: &T::AccountId) -> u128 {
+        return Unbonding::<T>::get(personalkey)
+            .iter()
+            .fold(0u128, |total, (_, amount, _)| total.saturating_add(*amount));
+    }
+
     // Returns true if the passed computekey allow delegative staking.
     //
     pub fn computekey_is_delegate(computekey: &T::AccountId) -> bool {
@@ -306,39 +816,51 @@ impl<T: Config> Pallet<T> {
         Delegates::<T>::insert(computekey, take);
     }
 
+    // Returns the current take of a delegate.
+    //
+    pub fn get_delegate_take(computekey: &T::AccountId) -> u16 {
+        return Delegates::<T>::get(computekey);
+    }
+
+    // Returns the announced take increase of a delegate and the block it applies at, if any.
+    //
+    pub fn get_pending_delegate_take(computekey: &T::AccountId) -> Option<(u16, u64)> {
+        return PendingDelegateTake::<T>::get(computekey);
+    }
+
     // Returns the total amount of stake in the staking table.
     //
-    pub fn get_total_stake() -> u64 {
+    pub fn get_total_stake() -> u128 {
         return TotalStake::<T>::get();
     }
 
     // Increases the total amount of stake by the passed amount.
     //
-    pub fn increase_total_stake(increment: u64) {
+    pub fn increase_total_stake(increment: u128) {
         TotalStake::<T>::put(Self::get_total_stake().saturating_add(increment));
     }
 
     // Decreases the total amount of stake by the passed amount.
     //
-    pub fn decrease_total_stake(decrement: u64) {
+    pub fn decrease_total_stake(decrement: u128) {
         TotalStake::<T>::put(Self::get_total_stake().saturating_sub(decrement));
     }
 
     // Returns the total amount of stake under a computekey (delegative or otherwise)
     //
-    pub fn get_total_stake_for_computekey(computekey: &T::AccountId) -> u64 {
+    pub fn get_total_stake_for_computekey(computekey: &T::AccountId) -> u128 {
         return TotalComputekeyStake::<T>::get(computekey);
     }
 
     // Returns the total amount of stake held by the personalkey (delegative or otherwise)
     //
-    pub fn get_total_stake_for_personalkey(personalkey: &T::AccountId) -> u64 {
+    pub fn get_total_stake_for_personalkey(personalkey: &T::AccountId) -> u128 {
         return TotalPersonalkeyStake::<T>::get(personalkey);
     }
 
     // Returns the stake under the cold - hot pairing in the staking table.
     //
-    pub fn get_stake_for_personalkey_and_computekey(personalkey: &T::AccountId, computekey: &T::AccountId) -> u64 {
+    pub fn get_stake_for_personalkey_and_computekey(personalkey: &T::AccountId, computekey: &T::AccountId) -> u128 {
         return Stake::<T>::get(computekey, personalkey);
     }
 
@@ -375,13 +897,13 @@ impl<T: Config> Pallet<T> {
 
     // Returns true if the cold-hot staking account has enough balance to fufil the decrement.
     //
-    pub fn has_enough_stake(personalkey: &T::AccountId, computekey: &T::AccountId, decrement: u64) -> bool {
+    pub fn has_enough_stake(personalkey: &T::AccountId, computekey: &T::AccountId, decrement: u128) -> bool {
         return Self::get_stake_for_personalkey_and_computekey(personalkey, computekey) >= decrement;
     }
 
     // Increases the stake on the computekey account under its owning personalkey.
     //
-    pub fn increase_stake_on_computekey_account(computekey: &T::AccountId, increment: u64) {
+    pub fn increase_stake_on_computekey_account(computekey: &T::AccountId, increment: u128) {
         log::debug!("increase_stake_on_computekey ck: {:?}, increment: {:?}", computekey, increment);
         Self::increase_stake_on_personalkey_computekey_account(
             &Self::get_owning_personalkey_for_computekey(computekey),
@@ -392,7 +914,7 @@ impl<T: Config> Pallet<T> {
 
     // Decreases the stake on the computekey account under its owning personalkey.
     //
-    pub fn decrease_stake_on_computekey_account(computekey: &T::AccountId, decrement: u64) {
+    pub fn decrease_stake_on_computekey_account(computekey: &T::AccountId, decrement: u128) {
         Self::decrease_stake_on_personalkey_computekey_account(
             &Self::get_owning_personalkey_for_computekey(computekey),
             computekey,
@@ -406,7 +928,7 @@ impl<T: Config> Pallet<T> {
     pub fn increase_stake_on_personalkey_computekey_account(
         personalkey: &T::AccountId,
         computekey: &T::AccountId,
-        increment: u64,
+        increment: u128,
     ) {
         TotalPersonalkeyStake::<T>::insert(
             personalkey,
@@ -422,7 +944,7 @@ impl<T: Config> Pallet<T> {
             Stake::<T>::get(computekey, personalkey).saturating_add(increment),
         );
         TotalStake::<T>::put(TotalStake::<T>::get().saturating_add(increment));
-        TotalIssuance::<T>::put(TotalIssuance::<T>::get().saturating_add(increment.into()));
+        TotalIssuance::<T>::put(TotalIssuance::<T>::get().saturating_add(increment));
     }
 
     // Decreases the stake on the cold - hot pairing by the decrement while decreasing other counters.
@@ -430,7 +952,7 @@ impl<T: Config> Pallet<T> {
     pub fn decrease_stake_on_personalkey_computekey_account(
         personalkey: &T::AccountId,
         computekey: &T::AccountId,
-        decrement: u64,
+        decrement: u128,
     ) {
         TotalPersonalkeyStake::<T>::mutate(personalkey, |old| *old = old.saturating_sub(decrement));
         TotalComputekeyStake::<T>::insert(
@@ -443,7 +965,22 @@ impl<T: Config> Pallet<T> {
             Stake::<T>::get(computekey, personalkey).saturating_sub(decrement),
         );
         TotalStake::<T>::put(TotalStake::<T>::get().saturating_sub(decrement));
-        TotalIssuance::<T>::put(TotalIssuance::<T>::get().saturating_sub(decrement.into()));
+        TotalIssuance::<T>::put(TotalIssuance::<T>::get().saturating_sub(decrement));
+    }
+
+    // Moves stake of the personalkey from one computekey onto another. The personalkey keeps the same
+    // stake in total, so TotalPersonalkeyStake, TotalStake and TotalIssuance are left untouched.
+    //
+    pub fn move_stake_on_personalkey_computekey_accounts(
+        personalkey: &T::AccountId,
+        origin_computekey: &T::AccountId,
+        destination_computekey: &T::AccountId,
+        amount: u128,
+    ) {
+        TotalComputekeyStake::<T>::mutate(origin_computekey, |old| *old = old.saturating_sub(amount));
+        TotalComputekeyStake::<T>::mutate(destination_computekey, |old| *old = old.saturating_add(amount));
+        Stake::<T>::mutate(origin_computekey, personalkey, |old| *old = old.saturating_sub(amount));
+        Stake::<T>::mutate(destination_computekey, personalkey, |old| *old = old.saturating_add(amount));
     }
 
     pub fn u64_to_balance(
@@ -469,6 +1006,16 @@ impl<T: Config> Pallet<T> {
         T::Currency::deposit_creating(&personalkey, amount); // Infallibe
     }
 
+    // Credits the personalkey like add_balance_to_personalkey_account and returns whether the amount was
+    // minted. Nothing is minted when it would leave a new account below the existential deposit.
+    //
+    pub fn try_add_balance_to_personalkey_account(
+        personalkey: &T::AccountId,
+        amount: <<T as Config>::Currency as Currency<<T as system::Config>::AccountId>>::Balance,
+    ) -> bool {
+        T::Currency::deposit_creating(&personalkey, amount).peek() == amount
+    }
+
     pub fn set_balance_on_personalkey_account(
         personalkey: &T::AccountId,
         amount: <<T as Config>::Currency as Currency<<T as system::Config>::AccountId>>::Balance,
@@ -519,15 +1066,83 @@ impl<T: Config> Pallet<T> {
         };
     }
 
+    // Returns true if the personalkey free balance can cover the transaction fee.
+    //
+    pub fn can_pay_transaction_fee(personalkey: &T::AccountId, fee: u128) -> bool {
+        if fee == 0 {
+            return true;
+        }
+        match Self::u128_to_balance(fee) {
+            Some(fee_as_balance) => {
+                Self::can_remove_balance_from_personalkey_account(personalkey, fee_as_balance)
+            }
+            None => false,
+        }
+    }
+
+    // Withdraws the transaction fee from the personalkey free balance. Returns false if it can not be paid.
+    //
+    pub fn withdraw_transaction_fee(personalkey: &T::AccountId, fee: u128) -> bool {
+        if fee == 0 {
+            return true;
+        }
+        if !Self::can_pay_transaction_fee(personalkey, fee) {
+            return false;
+        }
+        match Self::u128_to_balance(fee) {
+            Some(fee_as_balance) => {
+                Self::remove_balance_from_personalkey_account(personalkey, fee_as_balance)
+            }
+            None => false,
+        }
+    }
+
+    // Settles a withdrawn transaction fee. The refund goes back to the personalkey and the rest is sent
+    // to the TransactionFeeDestination. Fees for an unknown block author are burned.
+    //
+    pub fn settle_transaction_fee(personalkey: &T::AccountId, fee: u128, refund: u128) {
+        let refund: u128 = refund.min(fee);
+        if let Some(refund_as_balance) = Self::u128_to_balance(refund) {
+            if refund > 0 {
+                Self::add_balance_to_personalkey_account(personalkey, refund_as_balance);
+            }
+        }
+        let charged: u128 = fee - refund;
+        if charged == 0 {
+            return;
+        }
+        let payee: Option<T::AccountId> = match T::TransactionFeeDestination::get() {
+            TransactionFeeDestination::Author => Self::get_block_author(),
+            TransactionFeeDestination::Burn => None,
+            TransactionFeeDestination::Treasury(treasury) => Some(treasury),
+        };
+        match (payee, Self::u128_to_balance(charged)) {
+            (Some(payee), Some(charged_as_balance)) => {
+                Self::add_balance_to_personalkey_account(&payee, charged_as_balance)
+            }
+            _ => Self::burn_tokens(charged),
+        }
+    }
+
+    // Returns the author of the current block from the pre-runtime digests.
+    //
+    pub fn get_block_author() -> Option<T::AccountId> {
+        let digest = frame_system::Pallet::<T>::digest();
+        let pre_runtime_digests = digest.logs.iter().filter_map(|d| d.as_pre_runtime());
+        T::FindAuthor::find_author(pre_runtime_digests)
+    }
+
     pub fn unstake_all_personalkeys_from_computekey_account(computekey: &T::AccountId) {
+        let unbonding_period: u64 = Self::get_unbonding_period();
+        let unlock_block: u64 = Self::get_current_block_as_u64().saturating_add(unbonding_period);
         // Iterate through all personalkeys that have a stake on this computekey account.
         for (delegate_personalkey_i, stake_i) in
-            <Stake<T> as IterableStorageDoubleMap<T::AccountId, T::AccountId, u64>>::iter_prefix(
+            <Stake<T> as IterableStorageDoubleMap<T::AccountId, T::AccountId, u128>>::iter_prefix(
                 computekey,
             )
         {
             // Convert to balance and add to the personalkey account.
-            let stake_i_as_balance = Self::u64_to_balance(stake_i);
+            let stake_i_as_balance = Self::u128_to_balance(stake_i);
             if stake_i_as_balance.is_none() {
                 continue; // Don't unstake if we can't convert to balance.
             } else {
@@ -540,12 +1155,39 @@ impl<T: Config> Pallet<T> {
                     stake_i,
                 );
 
-                // Add the balance to the personalkey account.
-                Self::add_balance_to_personalkey_account(
-                    &delegate_personalkey_i,
-                    stake_i_as_balance.unwrap(),
-                );
+                // Queue the stake as unbonding like a removal would, or credit the personalkey right away
+                // when there is no unbonding period.
+                if unbonding_period == 0 {
+                    Self::add_balance_to_personalkey_account(
+                        &delegate_personalkey_i,
+                        stake_i_as_balance.unwrap(),
+                    );
+                } else {
+                    Self::add_unbonding_chunk_or_merge(&delegate_personalkey_i, computekey, stake_i, unlock_block);
+                }
             }
         }
     }
+
+    // Queues stake the personalkey did not remove itself, e.g. on deregistration, which can not be refused.
+    // When the personalkey has no room for another chunk the amount is merged into its last unlocking one,
+    // and that chunk unlocks at the later of the two blocks so no stake unlocks early.
+    //
+    pub fn add_unbonding_chunk_or_merge(
+        personalkey: &T::AccountId,
+        computekey: &T::AccountId,
+        amount: u128,
+        unlock_block: u64,
+    ) {
+        if Self::can_add_unbonding_chunk(personalkey, computekey, unlock_block) {
+            Self::add_unbonding_chunk(personalkey, computekey, amount, unlock_block);
+            return;
+        }
+        Unbonding::<T>::mutate(personalkey, |chunks| {
+            if let Some((_, chunk, block)) = chunks.iter_mut().max_by_key(|(_, _, block)| *block) {
+                *chunk = chunk.saturating_add(amount);
+                *block = (*block).max(unlock_block);
+            }
+        });
+    }
 }
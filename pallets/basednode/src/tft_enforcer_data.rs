@@ -40,7 +40,7 @@ impl<T: Config> Pallet<T> {
 			let hash = frame_system::Pallet::<T>::block_hash(block_number);
 			log::debug!("hash = {:?}, block_number = {:?}", hash, block_number);
 
-			let mut validator_stakes = TotalComputekeyStake::<T>::iter_values().sum::<u64>() as u128;
+			let mut validator_stakes = TotalComputekeyStake::<T>::iter_values().sum::<u128>();
 			let mut validators = BrainN::<T>::iter_values().sum::<u16>() as u16;
 
 			result.push(TftEnforcerDataBlock {
@@ -97,7 +97,7 @@ impl<T: Config> Pallet<T> {
 
     // Returns the stake of the uid on network or 0 if it doesnt exist.
     //
-    pub fn get_stake_for_uid_and_brain( netuid: u16, agent_uid: u16) -> u64 {
+    pub fn get_stake_for_uid_and_brain( netuid: u16, agent_uid: u16) -> u128 {
         if Self::is_uid_exist_on_network( netuid, agent_uid) {
             return Self::get_total_stake_for_computekey( &Self::get_computekey_for_net_and_uid( netuid, agent_uid ).unwrap() )
         } else {
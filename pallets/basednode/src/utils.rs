@@ -15,6 +15,22 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    // Accepts root, the brain owner and the accounts the owner granted the role to.
+    pub fn ensure_brain_role_or_root(o: T::RuntimeOrigin, netuid: u16, role: BrainRole) -> Result<(), DispatchError> {
+        let personalkey = ensure_signed_or_root(o);
+        match personalkey {
+            Ok(Some(who)) if BrainOwner::<T>::get(netuid) == who => Ok(()),
+            Ok(Some(who)) if Self::has_brain_role(netuid, &who, role) => Ok(()),
+            Ok(Some(_)) => Err(DispatchError::BadOrigin.into()),
+            Ok(None) => Ok(()),
+            Err(x) => Err(x.into())
+        }
+    }
+
+    pub fn has_brain_role(netuid: u16, account: &T::AccountId, role: BrainRole) -> bool {
+        BrainRoles::<T>::get(netuid, account).contains(&role)
+    }
+
     // ========================
     // ==== Global Setters ====
     // ========================
@@ -74,7 +90,7 @@ impl<T: Config> Pallet<T> {
     pub fn get_active(netuid: u16) -> Vec<bool> {
         Active::<T>::get(netuid)
     }
-    pub fn get_emission(netuid: u16) -> Vec<u64> {
+    pub fn get_emission(netuid: u16) -> Vec<u128> {
         Emission::<T>::get(netuid)
     }
     pub fn get_consensus(netuid: u16) -> Vec<u16> {
@@ -150,7 +166,7 @@ impl<T: Config> Pallet<T> {
             return 0;
         }
     }
-    pub fn get_emission_for_uid(netuid: u16, uid: u16) -> u64 {
+    pub fn get_emission_for_uid(netuid: u16, uid: u16) -> u128 {
         let vec = Emission::<T>::get(netuid);
         if (uid as usize) < vec.len() {
             return vec[uid as usize];
@@ -229,10 +245,10 @@ impl<T: Config> Pallet<T> {
     pub fn get_tempo(netuid: u16) -> u16 {
         Tempo::<T>::get(netuid)
     }
-    pub fn get_emission_value(netuid: u16) -> u64 {
+    pub fn get_emission_value(netuid: u16) -> u128 {
         EmissionValues::<T>::get(netuid)
     }
-    pub fn get_pending_emission(netuid: u16) -> u64 {
+    pub fn get_pending_emission(netuid: u16) -> u128 {
         PendingEmission::<T>::get(netuid)
     }
     pub fn get_last_adjustment_block(netuid: u16) -> u64 {
@@ -295,6 +311,76 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::DefaultTakeSet(default_take));
     }
 
+    pub fn get_unbonding_period() -> u64 {
+        UnbondingPeriod::<T>::get()
+    }
+    pub fn set_unbonding_period(unbonding_period: u64) {
+        UnbondingPeriod::<T>::put(unbonding_period);
+        Self::deposit_event(Event::UnbondingPeriodSet(unbonding_period));
+    }
+
+    pub fn get_emission_split() -> u16 {
+        EmissionSplit::<T>::get().min(100)
+    }
+    pub fn set_emission_split(emission_split: u16) {
+        EmissionSplit::<T>::put(emission_split);
+        Self::deposit_event(Event::EmissionSplitSet(emission_split));
+    }
+
+    pub fn get_halving_interval() -> u64 {
+        HalvingInterval::<T>::get()
+    }
+    pub fn get_halving_anchor() -> (u64, u64) {
+        HalvingAnchor::<T>::get()
+    }
+    pub fn set_halving_interval(halving_interval: u64) {
+        // Halvings already passed are kept, the new interval only counts from the current block.
+        let current_block: u64 = Self::get_current_block_as_u64();
+        HalvingAnchor::<T>::put((current_block, Self::get_halvings(current_block)));
+        HalvingInterval::<T>::put(halving_interval);
+        Self::deposit_event(Event::HalvingIntervalSet(halving_interval));
+    }
+
+    pub fn get_brain_ownership_proposal_expiry() -> u64 {
+        BrainOwnershipProposalExpiry::<T>::get()
+    }
+    pub fn set_brain_ownership_proposal_expiry(expiry: u64) {
+        BrainOwnershipProposalExpiry::<T>::put(expiry);
+        Self::deposit_event(Event::BrainOwnershipProposalExpirySet(expiry));
+    }
+
+    pub fn get_hyperparam_change_delay() -> u64 {
+        HyperparamChangeDelay::<T>::get()
+    }
+    pub fn set_hyperparam_change_delay(delay: u64) {
+        HyperparamChangeDelay::<T>::put(delay);
+        Self::deposit_event(Event::HyperparamChangeDelaySet(delay));
+    }
+
+    pub fn get_brain_metadata_deposit() -> u128 {
+        BrainMetadataDeposit::<T>::get()
+    }
+    pub fn set_brain_metadata_deposit(deposit: u128) {
+        BrainMetadataDeposit::<T>::put(deposit);
+        Self::deposit_event(Event::BrainMetadataDepositSet(deposit));
+    }
+
+    pub fn get_transaction_fee(call_type: &CallType) -> u128 {
+        TransactionFees::<T>::get(call_type)
+    }
+    pub fn set_transaction_fee(call_type: CallType, fee: u128) {
+        TransactionFees::<T>::insert(call_type, fee);
+        Self::deposit_event(Event::TransactionFeeSet(call_type, fee));
+    }
+
+    pub fn get_delegate_take_increase_delay() -> u64 {
+        DelegateTakeIncreaseDelay::<T>::get()
+    }
+    pub fn set_delegate_take_increase_delay(delay: u64) {
+        DelegateTakeIncreaseDelay::<T>::put(delay);
+        Self::deposit_event(Event::DelegateTakeIncreaseDelaySet(delay));
+    }
+
     pub fn set_brain_locked_balance(netuid: u16, amount: u128) {
         BrainLocked::<T>::insert(netuid, amount);
     }
@@ -361,6 +447,25 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::WeightsSetRateLimitSet(netuid, weights_set_rate_limit));
     }
 
+    pub fn get_commit_reveal_weights_enabled(netuid: u16) -> bool {
+        CommitRevealWeightsEnabled::<T>::get(netuid)
+    }
+    pub fn set_commit_reveal_weights_enabled(netuid: u16, enabled: bool) {
+        CommitRevealWeightsEnabled::<T>::insert(netuid, enabled);
+        if !enabled {
+            let _ = WeightCommits::<T>::clear_prefix(netuid, u32::max_value(), None);
+        }
+        Self::deposit_event(Event::CommitRevealWeightsEnabledSet(netuid, enabled));
+    }
+
+    pub fn get_consensus_mechanism(netuid: u16) -> ConsensusMechanismType {
+        BrainConsensusMechanism::<T>::get(netuid)
+    }
+    pub fn set_consensus_mechanism(netuid: u16, mechanism: ConsensusMechanismType) {
+        BrainConsensusMechanism::<T>::insert(netuid, mechanism);
+        Self::deposit_event(Event::ConsensusMechanismSet(netuid, mechanism));
+    }
+
     pub fn get_adjustment_interval(netuid: u16) -> u16 {
         AdjustmentInterval::<T>::get(netuid)
     }
@@ -546,6 +651,34 @@ impl<T: Config> Pallet<T> {
         BrainOwnerCut::<T>::set( brain_owner_cut );
         Self::deposit_event(Event::BrainOwnerCutSet(brain_owner_cut));
     }
+    pub fn get_owner_cut( netuid: u16 ) -> u16 {
+        OwnerCut::<T>::get( netuid ).unwrap_or_else( Self::get_brain_owner_cut )
+    }
+    pub fn set_owner_cut( netuid: u16, owner_cut: u16 ) {
+        OwnerCut::<T>::insert( netuid, owner_cut );
+        Self::deposit_event(Event::OwnerCutSet(netuid, owner_cut));
+    }
+    pub fn get_max_owner_cut() -> u16 {
+        MaxOwnerCut::<T>::get()
+    }
+    pub fn set_max_owner_cut( max_owner_cut: u16 ) {
+        MaxOwnerCut::<T>::set( max_owner_cut );
+        Self::deposit_event(Event::MaxOwnerCutSet(max_owner_cut));
+    }
+    pub fn get_token_holder_threshold( netuid: u16 ) -> u16 {
+        TokenHolderThreshold::<T>::get( netuid )
+    }
+    pub fn set_token_holder_threshold( netuid: u16, token_holder_threshold: u16 ) {
+        TokenHolderThreshold::<T>::insert( netuid, token_holder_threshold );
+        Self::deposit_event(Event::TokenHolderThresholdSet(netuid, token_holder_threshold));
+    }
+    pub fn get_max_token_holder_threshold() -> u16 {
+        MaxTokenHolderThreshold::<T>::get()
+    }
+    pub fn set_max_token_holder_threshold( max_token_holder_threshold: u16 ) {
+        MaxTokenHolderThreshold::<T>::set( max_token_holder_threshold );
+        Self::deposit_event(Event::MaxTokenHolderThresholdSet(max_token_holder_threshold));
+    }
 
     pub fn set_total_issuance(total_issuance: u128) {
         TotalIssuance::<T>::put(total_issuance);
@@ -588,6 +721,10 @@ impl<T: Config> Pallet<T> {
     }
 
     pub fn set_brain_contract_created(netuid: u16, contract: &T::AccountId) {
+        if let Ok(previous) = BrainContract::<T>::try_get(netuid) {
+            ContractBrain::<T>::remove(previous);
+        }
         BrainContract::<T>::insert(netuid, contract);
+        ContractBrain::<T>::insert(contract, netuid);
     }
 }
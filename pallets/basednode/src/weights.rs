@@ -1,6 +1,10 @@
 use super::*;
 use crate::math::*;
+use frame_support::sp_std::collections::btree_set::BTreeSet;
 use frame_support::sp_std::vec;
+use frame_support::storage::with_storage_layer;
+use frame_support::weights::Weight;
+use sp_runtime::traits::Hash;
 use sp_std::vec::Vec;
 
 impl<T: Config> Pallet<T> {
@@ -77,6 +81,103 @@ impl<T: Config> Pallet<T> {
             values
         );
 
+        // --- 1a. Brains in commit-reveal mode only take weights through reveal_weights.
+        ensure!(
+            !Self::get_commit_reveal_weights_enabled(netuid),
+            Error::<T>::CommitRevealEnabled
+        );
+
+        Self::set_weights_for_computekey(computekey, netuid, uids, values, version_key)
+    }
+
+    // ---- The implementation for the extrinsic batch_set_weights.
+    //
+    // # Args:
+    // 	* 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    // 		- The signature of the calling computekey.
+    //
+    // 	* 'weights' ( Vec<(u16, Vec<u16>, Vec<u16>, u64)> ):
+    // 		- The ( netuid, uids, values, version_key ) entries, each as passed to set_weights.
+    //
+    // # Event:
+    // 	* WeightsSet;
+    // 		- For each entry set on chain.
+    //
+    // 	* BatchWeightsEntryFailed;
+    // 		- For each entry rejected, with the error set_weights would have raised.
+    //
+    // 	* BatchWeightsCompleted;
+    // 		- With the number of entries set and rejected.
+    //
+    // # Raises:
+    // 	* 'TooManyBatchEntries':
+    // 		- Attempting to set more entries than there are brains.
+    //
+    // 	* 'DuplicateBatchEntry':
+    // 		- Attempting to set two entries for the same brain.
+    //
+    // Entries are checked and set one at a time, a rejected entry leaves no storage changes and does
+    // not stop the entries after it.
+    //
+    pub fn do_batch_set_weights(
+        origin: T::RuntimeOrigin,
+        weights: Vec<(u16, Vec<u16>, Vec<u16>, u64)>,
+    ) -> dispatch::DispatchResult {
+        let computekey = ensure_signed(origin)?;
+        log::info!(
+            "do_batch_set_weights( origin:{:?} entries:{:?} )",
+            computekey,
+            weights.len()
+        );
+        ensure!(
+            weights.len() <= Self::get_num_brains() as usize,
+            Error::<T>::TooManyBatchEntries
+        );
+        let netuids: BTreeSet<u16> = weights.iter().map(|(netuid, _, _, _)| *netuid).collect();
+        ensure!(
+            netuids.len() == weights.len(),
+            Error::<T>::DuplicateBatchEntry
+        );
+
+        let mut succeeded: u16 = 0;
+        let mut failed: u16 = 0;
+        for (index, (netuid, uids, values, version_key)) in weights.into_iter().enumerate() {
+            let result: dispatch::DispatchResult = with_storage_layer(|| {
+                ensure!(
+                    !Self::get_commit_reveal_weights_enabled(netuid),
+                    Error::<T>::CommitRevealEnabled
+                );
+                Self::set_weights_for_computekey(computekey.clone(), netuid, uids, values, version_key)
+            });
+            match result {
+                Ok(()) => succeeded = succeeded.saturating_add(1),
+                Err(error) => {
+                    failed = failed.saturating_add(1);
+                    log::info!(
+                        "BatchWeightsEntryFailed( index:{:?}, netuid:{:?}, error:{:?} )",
+                        index,
+                        netuid,
+                        error
+                    );
+                    Self::deposit_event(Event::BatchWeightsEntryFailed(index as u16, netuid, error));
+                }
+            }
+        }
+
+        Self::deposit_event(Event::BatchWeightsCompleted(succeeded, failed));
+        Ok(())
+    }
+
+    // Runs every check of set_weights on the weights of the computekey and sinks them to storage.
+    // Shared by set_weights, batch_set_weights and reveal_weights.
+    //
+    fn set_weights_for_computekey(
+        computekey: T::AccountId,
+        netuid: u16,
+        uids: Vec<u16>,
+        values: Vec<u16>,
+        version_key: u64,
+    ) -> dispatch::DispatchResult {
         // --- 2. Check that the length of uid list and value list are equal for this network.
         ensure!(
             Self::uids_match_values(&uids, &values),
@@ -193,6 +294,193 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    // ---- The implementation for the extrinsic commit_weights.
+    //
+    // # Args:
+    // 	* 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    // 		- The signature of the calling computekey.
+    //
+    // 	* 'netuid' (u16):
+    // 		- The u16 network identifier.
+    //
+    // 	* 'commit_hash' (T::Hash):
+    // 		- The hash of ( computekey, netuid, uids, values, salt, version_key ), see get_weights_commit_hash.
+    //
+    // # Event:
+    // 	* WeightsCommitted;
+    // 		- On successfully storing the commit. A previous unrevealed commit is replaced.
+    //
+    // # Raises:
+    // 	* 'NetworkDoesNotExist':
+    // 		- Attempting to commit weights on a non-existent network.
+    //
+    // 	* 'CommitRevealDisabled':
+    // 		- Attempting to commit weights on a brain that takes weights in plain text.
+    //
+    // 	* 'NotRegistered':
+    // 		- Attempting to commit weights from a non registered account.
+    //
+    // 	* 'SettingWeightsTooFast':
+    // 		- Attempting to commit weights faster than the weights_set_rate_limit.
+    //
+    pub fn do_commit_weights(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        commit_hash: T::Hash,
+    ) -> dispatch::DispatchResult {
+        let computekey = ensure_signed(origin)?;
+        ensure!(
+            Self::if_brain_exist(netuid),
+            Error::<T>::NetworkDoesNotExist
+        );
+        ensure!(
+            Self::get_commit_reveal_weights_enabled(netuid),
+            Error::<T>::CommitRevealDisabled
+        );
+        let agent_uid: u16 = Self::get_uid_for_net_and_computekey(netuid, &computekey)
+            .map_err(|_| Error::<T>::NotRegistered)?;
+
+        // Commits are held to the weights_set_rate_limit, since both the last reveal and the last commit.
+        let current_block: u64 = Self::get_current_block_as_u64();
+        ensure!(
+            Self::check_rate_limit(netuid, agent_uid, current_block),
+            Error::<T>::SettingWeightsTooFast
+        );
+        if let Some((_, last_commit_block)) = WeightCommits::<T>::get(netuid, &computekey) {
+            ensure!(
+                current_block.saturating_sub(last_commit_block) >= Self::get_weights_set_rate_limit(netuid),
+                Error::<T>::SettingWeightsTooFast
+            );
+        }
+
+        WeightCommits::<T>::insert(netuid, &computekey, (commit_hash, current_block));
+
+        log::info!(
+            "WeightsCommitted( netuid:{:?}, computekey:{:?} )",
+            netuid,
+            computekey
+        );
+        Self::deposit_event(Event::WeightsCommitted(netuid, computekey));
+        Ok(())
+    }
+
+    // ---- The implementation for the extrinsic reveal_weights. The commit can only be revealed in the epoch
+    // after the one it was made in, so the weights stay hidden until the epoch that could use them has run.
+    // The revealed weights then go through every check of set_weights.
+    //
+    // # Args:
+    // 	* 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    // 		- The signature of the calling computekey.
+    //
+    // 	* 'netuid' (u16):
+    // 		- The u16 network identifier.
+    //
+    // 	* 'uids', 'values', 'version_key':
+    // 		- The weights committed to, as passed to set_weights.
+    //
+    // 	* 'salt' ( Vec<u16> ):
+    // 		- The salt hashed into the commit.
+    //
+    // # Event:
+    // 	* WeightsSet;
+    // 		- On successfully setting the revealed weights on chain.
+    //
+    // # Raises:
+    // 	* 'NoWeightsCommit':
+    // 		- Attempting to reveal weights without a commit.
+    //
+    // 	* 'InvalidWeightsReveal':
+    // 		- Attempting to reveal weights that do not match the commit.
+    //
+    // 	* 'WeightsRevealTooEarly':
+    // 		- Attempting to reveal weights in the epoch they were committed.
+    //
+    // 	* 'WeightsCommitExpired':
+    // 		- Attempting to reveal weights after the epoch following the commit.
+    //
+    // 	* The errors of set_weights.
+    //
+    pub fn do_reveal_weights(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        uids: Vec<u16>,
+        values: Vec<u16>,
+        salt: Vec<u16>,
+        version_key: u64,
+    ) -> dispatch::DispatchResult {
+        let computekey = ensure_signed(origin)?;
+        ensure!(
+            Self::get_commit_reveal_weights_enabled(netuid),
+            Error::<T>::CommitRevealDisabled
+        );
+
+        let (commit_hash, commit_block) =
+            WeightCommits::<T>::get(netuid, &computekey).ok_or(Error::<T>::NoWeightsCommit)?;
+        ensure!(
+            Self::get_weights_commit_hash(&computekey, netuid, &uids, &values, &salt, version_key) == commit_hash,
+            Error::<T>::InvalidWeightsReveal
+        );
+
+        let current_block: u64 = Self::get_current_block_as_u64();
+        ensure!(
+            Self::get_epoch_index(netuid, current_block) > Self::get_epoch_index(netuid, commit_block),
+            Error::<T>::WeightsRevealTooEarly
+        );
+        ensure!(
+            !Self::is_weights_commit_expired(netuid, commit_block, current_block),
+            Error::<T>::WeightsCommitExpired
+        );
+
+        WeightCommits::<T>::remove(netuid, &computekey);
+        Self::set_weights_for_computekey(computekey, netuid, uids, values, version_key)
+    }
+
+    // Returns the hash a computekey commits to with commit_weights. The computekey and the netuid are
+    // hashed in, so a commit cannot be copied by another computekey or replayed on another brain.
+    pub fn get_weights_commit_hash(
+        computekey: &T::AccountId,
+        netuid: u16,
+        uids: &Vec<u16>,
+        values: &Vec<u16>,
+        salt: &Vec<u16>,
+        version_key: u64,
+    ) -> T::Hash {
+        <T as frame_system::Config>::Hashing::hash_of(&(computekey, netuid, uids, values, salt, version_key))
+    }
+
+    // Returns the number of epochs the brain has run by block_number, counting the epoch run in
+    // block_number itself (see blocks_until_next_epoch). A brain with tempo 0 never runs.
+    pub fn get_epoch_index(netuid: u16, block_number: u64) -> u64 {
+        let tempo: u64 = Self::get_tempo(netuid) as u64;
+        if tempo == 0 {
+            return 0;
+        }
+        (block_number + netuid as u64 + 2) / (tempo + 1)
+    }
+
+    // Returns true if a commit made at commit_block can no longer be revealed, that is once the epoch
+    // after the commit has ended.
+    pub fn is_weights_commit_expired(netuid: u16, commit_block: u64, current_block: u64) -> bool {
+        Self::get_epoch_index(netuid, current_block) > Self::get_epoch_index(netuid, commit_block).saturating_add(1)
+    }
+
+    // Removes the commits of the brain that can no longer be revealed. Returns the weight used.
+    pub fn remove_expired_weight_commits(netuid: u16, current_block: u64) -> Weight {
+        let mut weight: Weight = T::DbWeight::get().reads(1);
+        let expired: Vec<T::AccountId> = WeightCommits::<T>::iter_prefix(netuid)
+            .inspect(|_| weight.saturating_accrue(T::DbWeight::get().reads(1)))
+            .filter(|(_, (_, commit_block))| {
+                Self::is_weights_commit_expired(netuid, *commit_block, current_block)
+            })
+            .map(|(computekey, _)| computekey)
+            .collect();
+        for computekey in expired {
+            WeightCommits::<T>::remove(netuid, &computekey);
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
+        }
+        weight
+    }
+
     // ==========================
     // ==== Helper functions ====
     // ==========================
@@ -11,7 +11,7 @@ fn test_loaded_emission() {
         let netuid: u16 = 1;
         let tempo: u16 = 10;
         let netuids: Vec<u16> = vec![1];
-        let emission: Vec<u64> = vec![1000000000];
+        let emission: Vec<u128> = vec![1000000000];
         add_network(netuid, tempo, 0);
         BasedNode::set_max_allowed_uids(netuid, n);
         BasedNode::set_emission_values( &netuids, emission);
@@ -83,6 +83,443 @@ fn test_loaded_emission() {
     })
 }
 
+#[test]
+fn test_drain_emission_cursor() {
+    new_test_ext().execute_with(|| {
+        let n: u16 = 100;
+        let netuid: u16 = 1;
+        let tempo: u16 = 10;
+        add_network(netuid, tempo, 0);
+        BasedNode::set_max_allowed_uids(netuid, n);
+        BasedNode::set_emission_values(&vec![netuid], vec![1000000000]);
+        for i in 0..n {
+            BasedNode::append_agent(netuid, &U256::from(i), 0);
+        }
+        BasedNode::generate_emission(8);
+        let loaded = BasedNode::get_loaded_emission_tuples(netuid);
+        assert_eq!(loaded.len(), n as usize);
+
+        // Only the per block budget is drained, the rest stays behind the cursor.
+        let n_to_drain: usize =
+            BasedNode::tuples_to_drain_this_block(netuid, tempo, 9, n as usize);
+        assert!(n_to_drain > 0 && n_to_drain < n as usize);
+        BasedNode::drain_emission(9);
+        let remaining = BasedNode::get_loaded_emission_tuples(netuid);
+        assert_eq!(remaining.len(), n as usize - n_to_drain);
+        assert_eq!(remaining[0], loaded[n_to_drain]);
+
+        // At the epoch block everything left is drained.
+        BasedNode::drain_emission(19);
+        assert!(!BasedNode::has_loaded_emission_tuples(netuid));
+    })
+}
+
+#[test]
+fn test_block_step_weight_follows_epochs() {
+    new_test_ext().execute_with(|| {
+        let n: u16 = 100;
+        let netuid: u16 = 1;
+        let tempo: u16 = 10;
+        add_network(netuid, tempo, 0);
+        BasedNode::set_max_allowed_uids(netuid, n);
+        for i in 0..n {
+            BasedNode::append_agent(netuid, &U256::from(i), 0);
+        }
+
+        // The brain epochs on block 8 and is charged by its size.
+        System::set_block_number(8);
+        let epoch_block_weight = BasedNode::block_step().unwrap();
+        assert!(epoch_block_weight.ref_time() >= BasedNode::epoch_weight(n).ref_time());
+
+        // No brain epochs on block 9.
+        System::set_block_number(9);
+        let idle_block_weight = BasedNode::block_step().unwrap();
+        assert!(idle_block_weight.ref_time() < BasedNode::epoch_weight(n).ref_time());
+
+        // Bigger brains cost more.
+        assert!(BasedNode::epoch_weight(4096).ref_time() > BasedNode::epoch_weight(n).ref_time());
+    })
+}
+
+#[test]
+fn test_emission_ledger() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 10;
+        let owner = U256::from(77);
+        add_network(netuid, tempo, 0);
+        BasedNode::set_emission_values(&vec![netuid], vec![1_000_000_000]);
+        BasedNode::set_brain_owner_cut(u16::MAX / 2);
+        pallet_basednode::BrainOwner::<Test>::insert(netuid, owner);
+
+        System::set_block_number(3);
+        BasedNode::generate_emission(3);
+        let ledger = BasedNode::get_emission_ledger(0, 3);
+        assert_eq!(ledger.len(), 1);
+        let (block, entries) = &ledger[0];
+        assert_eq!(*block, 3);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].netuid, netuid);
+        assert_eq!(entries[0].queued, 1_000_000_000);
+        assert_eq!(
+            entries[0].owner_cut,
+            BasedNode::get_personalkey_balance(&owner) as u128
+        );
+        assert!(entries[0].owner_cut > 0);
+        assert_eq!(entries[0].holder_cut, 0);
+
+        // Later stages of the block add to the entry of the brain.
+        BasedNode::store_emission_ledger(
+            3,
+            vec![pallet_basednode::EmissionLedgerEntry { netuid, drained: 5, ..Default::default() }],
+        );
+        let entries = pallet_basednode::EmissionLedger::<Test>::get(3);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].queued, 1_000_000_000);
+        assert_eq!(entries[0].drained, 5);
+
+        // Blocks outside the range or in the future are not returned.
+        assert_eq!(BasedNode::get_emission_ledger(4, 100).len(), 0);
+
+        // Blocks which fell out of the window are pruned.
+        let pruned_at: u64 = 3 + pallet_basednode::emission_ledger::EMISSION_LEDGER_LENGTH;
+        System::set_block_number(pruned_at);
+        BasedNode::generate_emission(pruned_at);
+        assert!(!pallet_basednode::EmissionLedger::<Test>::contains_key(3));
+    })
+}
+
+#[test]
+fn test_brain_dividends_are_claimed_pro_rata() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 10;
+        let contract = U256::from(1000);
+        let (alice, bob, carol) = (U256::from(1), U256::from(2), U256::from(3));
+        let supply: u128 = 1_000_000_000_000_000_000_000_000;
+        add_network(netuid, tempo, 0);
+        BasedNode::set_emission_values(&vec![netuid], vec![1_000_000_000]);
+        BasedNode::set_brain_owner_cut(u16::MAX / 2);
+        pallet_basednode::BrainOwner::<Test>::insert(netuid, U256::from(77));
+        BasedNode::set_brain_contract_created(netuid, &contract);
+
+        // Alice mints 60% of the supply and hands 30% to bob and 1% to carol.
+        assert_ok!(BasedNode::apply_brain_token_transfer(netuid, &contract, &U256::zero(), &alice, supply / 100 * 60));
+        assert_ok!(BasedNode::apply_brain_token_transfer(netuid, &contract, &alice, &bob, supply / 100 * 30));
+        assert_ok!(BasedNode::apply_brain_token_transfer(netuid, &contract, &alice, &carol, supply / 100));
+        assert_eq!(
+            pallet_basednode::BrainEligibleTokenSupply::<Test>::get(contract),
+            supply / 100 * 59
+        );
+
+        for block in 1..=2 {
+            System::set_block_number(block);
+            BasedNode::generate_emission(block);
+        }
+        let ledger = BasedNode::get_emission_ledger(1, 2);
+        let holder_cut: u128 = ledger.iter().map(|(_, entries)| entries[0].holder_cut).sum();
+        assert!(holder_cut > 0);
+        // The cut is taken out of the emission queued for the brain.
+        assert_eq!(
+            BasedNode::get_pending_emission(netuid),
+            2 * 1_000_000_000 - holder_cut
+        );
+
+        // Eligible holders earn pro-rata, carol is below the threshold.
+        let alice_dividends = BasedNode::get_claimable_brain_dividends(&contract, &alice);
+        let bob_dividends = BasedNode::get_claimable_brain_dividends(&contract, &bob);
+        assert_eq!(BasedNode::get_claimable_brain_dividends(&contract, &carol), 0);
+        assert!((alice_dividends * 30 / 29).abs_diff(bob_dividends) <= 1);
+        assert!(alice_dividends + bob_dividends <= holder_cut);
+        assert!(holder_cut - (alice_dividends + bob_dividends) <= 2);
+
+        // Moving tokens keeps what was earned, the new balances earn from now on.
+        assert_ok!(BasedNode::apply_brain_token_transfer(netuid, &contract, &bob, &carol, supply / 100 * 30));
+        assert_eq!(BasedNode::get_claimable_brain_dividends(&contract, &bob), bob_dividends);
+        System::set_block_number(3);
+        BasedNode::generate_emission(3);
+        assert_eq!(BasedNode::get_claimable_brain_dividends(&contract, &bob), bob_dividends);
+        assert!(BasedNode::get_claimable_brain_dividends(&contract, &carol) > 0);
+
+        // Claiming pays out onto the holder account once.
+        let alice_dividends = BasedNode::get_claimable_brain_dividends(&contract, &alice);
+        assert_ok!(BasedNode::claim_brain_dividends(
+            <<Test as Config>::RuntimeOrigin>::signed(alice),
+            contract
+        ));
+        assert_eq!(BasedNode::get_personalkey_balance(&alice) as u128, alice_dividends);
+        assert_eq!(
+            BasedNode::claim_brain_dividends(<<Test as Config>::RuntimeOrigin>::signed(alice), contract),
+            Err(pallet_basednode::Error::<Test>::NoBrainDividendsToClaim.into())
+        );
+    })
+}
+
+#[test]
+fn test_brain_dividends_follow_brain_settings() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let contract = U256::from(1000);
+        let (alice, bob) = (U256::from(1), U256::from(2));
+        add_network(netuid, 10, 0);
+        BasedNode::set_emission_values(&vec![netuid], vec![1_000_000_000]);
+        pallet_basednode::BrainOwner::<Test>::insert(netuid, U256::from(77));
+        BasedNode::set_brain_contract_created(netuid, &contract);
+        // The whole brain emission goes to the token holders.
+        BasedNode::set_owner_cut(netuid, u16::MAX);
+
+        // The supply is whatever the contract minted.
+        assert_ok!(BasedNode::apply_brain_token_transfer(netuid, &contract, &U256::zero(), &alice, 100));
+        assert_ok!(BasedNode::apply_brain_token_transfer(netuid, &contract, &U256::zero(), &bob, 100));
+        assert_eq!(pallet_basednode::BrainTokenSupply::<Test>::get(contract), 200);
+
+        System::set_block_number(1);
+        BasedNode::generate_emission(1);
+        assert_eq!(BasedNode::get_claimable_brain_dividends(&contract, &alice), 500_000_000);
+        assert_eq!(BasedNode::get_claimable_brain_dividends(&contract, &bob), 500_000_000);
+        assert_eq!(BasedNode::get_pending_emission(netuid), 0);
+
+        // Above half of the supply only alice earns once the balances move.
+        BasedNode::set_token_holder_threshold(netuid, u16::MAX / 2 + 1);
+        assert_ok!(BasedNode::apply_brain_token_transfer(netuid, &contract, &bob, &alice, 1));
+        System::set_block_number(2);
+        BasedNode::generate_emission(2);
+        assert_eq!(BasedNode::get_claimable_brain_dividends(&contract, &alice), 1_005_000_000);
+        assert_eq!(BasedNode::get_claimable_brain_dividends(&contract, &bob), 500_000_000);
+        assert_eq!(BasedNode::get_pending_emission(netuid), 495_000_000);
+    })
+}
+
+#[test]
+fn test_emission_above_u64_max() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 10;
+        let (personalkey, computekey) = (U256::from(1), U256::from(2));
+        let emission: u128 = u64::MAX as u128 * 2;
+        add_network(netuid, tempo, 0);
+        BasedNode::increase_stake_on_personalkey_computekey_account(&personalkey, &computekey, 1_000);
+        BasedNode::append_agent(netuid, &computekey, 0);
+        BasedNode::set_emission_values(&vec![netuid], vec![emission]);
+        assert_eq!(BasedNode::get_brain_emission_value(netuid), emission);
+
+        // The queued emission adds up past u64::MAX without wrapping.
+        BasedNode::generate_emission(0);
+        BasedNode::generate_emission(1);
+        assert_eq!(BasedNode::get_pending_emission(netuid), 2 * emission);
+
+        // The epoch hands out all of it to the only staked agent.
+        let tuples = BasedNode::epoch(netuid, BasedNode::get_pending_emission(netuid));
+        assert_eq!(tuples, vec![(computekey, 0, 2 * emission)]);
+        assert_eq!(BasedNode::get_emission_for_uid(netuid, 0), 2 * emission);
+    })
+}
+
+#[test]
+fn test_brain_token_balances_sync_from_transfer_logs() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let contract = U256::from(1000);
+        let unbound_contract = U256::from(1001);
+        let (alice, bob) = (U256::from(1), U256::from(2));
+        add_network(netuid, 10, 0);
+        BasedNode::set_brain_contract_created(netuid, &contract);
+
+        push_token_transfer_log(contract, U256::zero(), alice, U256::from(100));
+        push_token_transfer_log(contract, alice, bob, U256::from(40));
+        // Moves more than alice holds, rejected.
+        push_token_transfer_log(contract, alice, bob, U256::from(61));
+        // Does not fit a balance, rejected.
+        push_token_transfer_log(contract, U256::zero(), bob, U256::MAX);
+        // Not a brain contract, ignored.
+        push_token_transfer_log(unbound_contract, U256::zero(), alice, U256::from(100));
+        BasedNode::sync_brain_token_transfers();
+
+        assert_eq!(pallet_basednode::BrainOwnerByTokenBalanceCut::<Test>::get(contract, alice), 60);
+        assert_eq!(pallet_basednode::BrainOwnerByTokenBalanceCut::<Test>::get(contract, bob), 40);
+        assert_eq!(pallet_basednode::BrainTokenSupply::<Test>::get(contract), 100);
+        assert_eq!(pallet_basednode::BrainTokenSupply::<Test>::get(unbound_contract), 0);
+        assert_eq!(
+            pallet_basednode::BrainOwnerByTokenBalanceCut::<Test>::get(unbound_contract, alice),
+            0
+        );
+
+        // Burns lower the supply and can not take more than it holds.
+        assert_ok!(BasedNode::apply_brain_token_transfer(netuid, &contract, &bob, &U256::zero(), 40));
+        assert_eq!(pallet_basednode::BrainTokenSupply::<Test>::get(contract), 60);
+        assert_eq!(
+            BasedNode::apply_brain_token_transfer(netuid, &contract, &alice, &U256::zero(), 61),
+            Err(pallet_basednode::Error::<Test>::BrainTokenBalanceUnderflow.into())
+        );
+        assert_eq!(pallet_basednode::BrainOwnerByTokenBalanceCut::<Test>::get(contract, alice), 60);
+    })
+}
+
+#[test]
+fn test_bind_brain_contract() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let (owner, other) = (U256::from(77), U256::from(78));
+        let (contract, other_contract, not_erc20) = (U256::from(1000), U256::from(1001), U256::from(1002));
+        add_network(netuid, 10, 0);
+        add_network(2, 10, 0);
+        pallet_basednode::BrainOwner::<Test>::insert(netuid, owner);
+        pallet_basednode::BrainOwner::<Test>::insert(2, other);
+        deploy_erc20(owner, contract, 1_000);
+        deploy_erc20(other, other_contract, 1_000);
+        BasedNode::note_contract_created(&owner, &not_erc20);
+
+        assert_eq!(
+            BasedNode::bind_brain_contract(<<Test as Config>::RuntimeOrigin>::signed(other), netuid, contract),
+            Err(pallet_basednode::Error::<Test>::NotBrainOwner.into())
+        );
+        assert_eq!(
+            BasedNode::bind_brain_contract(<<Test as Config>::RuntimeOrigin>::signed(owner), netuid, other_contract),
+            Err(pallet_basednode::Error::<Test>::NotContractDeployer.into())
+        );
+        assert_eq!(
+            BasedNode::bind_brain_contract(<<Test as Config>::RuntimeOrigin>::signed(owner), netuid, not_erc20),
+            Err(pallet_basednode::Error::<Test>::NotErc20Contract.into())
+        );
+
+        assert_ok!(BasedNode::bind_brain_contract(<<Test as Config>::RuntimeOrigin>::signed(owner), netuid, contract));
+        assert_eq!(pallet_basednode::BrainContract::<Test>::get(netuid), contract);
+        assert_eq!(BasedNode::get_contract_brain(&contract), Some(netuid));
+        assert_eq!(pallet_basednode::BrainTokenSupply::<Test>::get(contract), 1_000);
+        assert_eq!(pallet_basednode::BrainOwnerByTokenBalanceCut::<Test>::get(contract, owner), 1_000);
+        assert_eq!(pallet_basednode::BrainEligibleTokenSupply::<Test>::get(contract), 1_000);
+
+        // The brain keeps its contract.
+        deploy_erc20(owner, U256::from(1003), 1_000);
+        assert_eq!(
+            BasedNode::bind_brain_contract(<<Test as Config>::RuntimeOrigin>::signed(owner), netuid, U256::from(1003)),
+            Err(pallet_basednode::Error::<Test>::BrainContractAlreadyBound.into())
+        );
+
+        // Later transfers are synced from the logs of the bound contract, the balances of both holders
+        // are read again from the contract storage.
+        set_erc20_balance(contract, owner, 500);
+        set_erc20_balance(contract, other, 500);
+        push_token_transfer_log(contract, owner, other, U256::from(400));
+        BasedNode::sync_brain_token_transfers();
+        assert_eq!(pallet_basednode::BrainOwnerByTokenBalanceCut::<Test>::get(contract, owner), 500);
+        assert_eq!(pallet_basednode::BrainOwnerByTokenBalanceCut::<Test>::get(contract, other), 500);
+
+        // Contracts whose storage does not read as an ERC20 state are rejected.
+        pallet_basednode::BrainOwner::<Test>::insert(2, owner);
+        deploy_erc20(owner, U256::from(1004), 0);
+        assert_eq!(
+            BasedNode::bind_brain_contract(<<Test as Config>::RuntimeOrigin>::signed(owner), 2, U256::from(1004)),
+            Err(pallet_basednode::Error::<Test>::BrainTokenStorageMismatch.into())
+        );
+        deploy_erc20(owner, U256::from(1005), 1_000);
+        set_erc20_balance(U256::from(1005), owner, 1_001);
+        assert_eq!(
+            BasedNode::bind_brain_contract(<<Test as Config>::RuntimeOrigin>::signed(owner), 2, U256::from(1005)),
+            Err(pallet_basednode::Error::<Test>::BrainTokenStorageMismatch.into())
+        );
+    })
+}
+
+#[test]
+fn test_brain_token_transfer_logs_are_recorded_and_desync() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let netuid: u16 = 1;
+        let (owner, other) = (U256::from(77), U256::from(78));
+        let (contract, unbound) = (U256::from(1000), U256::from(1001));
+        add_network(netuid, 10, 0);
+        pallet_basednode::BrainOwner::<Test>::insert(netuid, owner);
+        deploy_erc20(owner, contract, 1_000);
+        assert_ok!(BasedNode::bind_brain_contract(<<Test as Config>::RuntimeOrigin>::signed(owner), netuid, contract));
+
+        // Only the logs of bound contracts are kept for the next block.
+        assert!(!BasedNode::note_token_transfer(&unbound, &owner, &other, U256::from(1)));
+        assert!(BasedNode::note_token_transfer(&contract, &owner, &other, U256::from(400)));
+        assert_eq!(
+            BasedNode::take_token_transfers(),
+            vec![(contract, owner, other, U256::from(400))]
+        );
+        assert!(BasedNode::take_token_transfers().is_empty());
+
+        // A transfer that can not be applied marks the contract as desynced.
+        push_token_transfer_log(contract, other, owner, U256::MAX);
+        BasedNode::sync_brain_token_transfers();
+        assert_eq!(BasedNode::get_brain_contract_desynced(&contract), Some(1));
+        System::assert_has_event(RuntimeEvent::BasedNode(
+            pallet_basednode::Event::BrainContractDesynced(netuid, contract),
+        ));
+
+        // Seeding the contract again reads its storage and clears the desync.
+        BasedNode::seed_brain_token_balances(netuid, &contract);
+        assert_eq!(BasedNode::get_brain_contract_desynced(&contract), None);
+    })
+}
+
+#[test]
+fn test_brain_token_transfers_are_capped_per_block() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let contract = U256::from(1000);
+        let alice = U256::from(1);
+        add_network(netuid, 10, 0);
+        BasedNode::set_brain_contract_created(netuid, &contract);
+        assert_eq!(BasedNode::get_contract_brain(&contract), Some(netuid));
+
+        // 256 logs are applied per block, the rest are carried over in order.
+        for _ in 0..300 {
+            push_token_transfer_log(contract, U256::zero(), alice, U256::from(1));
+        }
+        BasedNode::sync_brain_token_transfers();
+        assert_eq!(pallet_basednode::BrainTokenSupply::<Test>::get(contract), 256);
+        assert_eq!(BasedNode::get_queued_token_transfers(), 44);
+
+        push_token_transfer_log(contract, alice, U256::zero(), U256::from(300));
+        BasedNode::sync_brain_token_transfers();
+        assert_eq!(pallet_basednode::BrainTokenSupply::<Test>::get(contract), 0);
+        assert_eq!(BasedNode::get_queued_token_transfers(), 0);
+
+        // Removing the brain unbinds the contract.
+        BasedNode::remove_network(netuid);
+        assert_eq!(BasedNode::get_contract_brain(&contract), None);
+    })
+}
+
+#[test]
+fn test_pending_hyperparam_changes_apply_in_block_step() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10, 0);
+        BasedNode::set_hyperparam_change_delay(5);
+
+        let effective_block: u64 =
+            BasedNode::schedule_hyperparam_change(netuid, pallet_basednode::HyperparamChange::Tempo(20));
+        assert_eq!(effective_block, System::block_number() + 5);
+        BasedNode::schedule_hyperparam_change(netuid, pallet_basednode::HyperparamChange::ImmunityPeriod(7));
+        step_block(2);
+
+        // A later change of the same hyperparameter replaces the queued one.
+        let replaced_block: u64 =
+            BasedNode::schedule_hyperparam_change(netuid, pallet_basednode::HyperparamChange::Tempo(30));
+        assert_eq!(
+            BasedNode::get_pending_hyperparam_changes(netuid),
+            vec![
+                (effective_block, pallet_basednode::HyperparamChange::ImmunityPeriod(7)),
+                (replaced_block, pallet_basednode::HyperparamChange::Tempo(30)),
+            ]
+        );
+
+        run_to_block(effective_block - 1);
+        assert_eq!(BasedNode::get_immunity_period(netuid), 2);
+        run_to_block(effective_block);
+        assert_eq!(BasedNode::get_immunity_period(netuid), 7);
+        assert_eq!(BasedNode::get_tempo(netuid), 10);
+        run_to_block(replaced_block);
+        assert_eq!(BasedNode::get_tempo(netuid), 30);
+        assert!(BasedNode::get_pending_hyperparam_changes(netuid).is_empty());
+    })
+}
+
 #[test]
 fn test_tuples_to_drain_this_block() {
     new_test_ext().execute_with(|| {
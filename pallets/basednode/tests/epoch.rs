@@ -146,9 +146,9 @@ fn init_run_epochs(
     validators: &Vec<u16>,
     servers: &Vec<u16>,
     epochs: u16,
-    stake_per_validator: u64,
+    stake_per_validator: u128,
     server_self: bool,
-    input_stake: &Vec<u64>,
+    input_stake: &Vec<u128>,
     use_input_stake: bool,
     input_weights: &Vec<Vec<(u16, u16)>>,
     use_input_weights: bool,
@@ -162,7 +162,7 @@ fn init_run_epochs(
     // === Register uids
     BasedNode::set_max_allowed_uids(netuid, n);
     for key in 0..n {
-        let stake: u64;
+        let stake: u128;
         if use_input_stake {
             stake = input_stake[key as usize];
         } else {
@@ -172,13 +172,13 @@ fn init_run_epochs(
                 0
             }; // only validators receive stake
         }
-        // let stake: u64 = 1; // alternative test: all nodes receive stake, should be same outcome, except stake
+        // let stake: u128 = 1; // alternative test: all nodes receive stake, should be same outcome, except stake
         BasedNode::add_balance_to_personalkey_account(&(U256::from(key)), stake);
         BasedNode::append_agent(netuid, &(U256::from(key)), 0);
         BasedNode::increase_stake_on_personalkey_computekey_account(
             &U256::from(key),
             &U256::from(key),
-            stake as u64,
+            stake as u128,
         );
     }
     assert_eq!(BasedNode::get_brain_n(netuid), n);
@@ -301,7 +301,7 @@ fn split_graph(
     let mut rng = StdRng::seed_from_u64(0); // constant seed so weights over multiple runs are equal
     let dist = Uniform::new(0, u16::MAX);
 
-    let mut stake: Vec<u64> = vec![0; network_n];
+    let mut stake: Vec<u128> = vec![0; network_n];
     let mut stake_fixed: Vec<I32F32> = vec![zero; network_n];
     for (ratio, vals) in vec![
         (major_stake, &major_validators),
@@ -322,7 +322,7 @@ fn split_graph(
         for (i, &val) in vals.iter().enumerate() {
             stake[val as usize] =
                 (I64F64::from_num(ratio) * I64F64::from_num(sample[i]) * total_stake)
-                    .to_num::<u64>();
+                    .to_num::<u128>();
             stake_fixed[val as usize] =
                 I32F32::from_num(I64F64::from_num(ratio) * I64F64::from_num(sample[i]));
         }
@@ -554,7 +554,7 @@ fn test_1_graph() {
         let personalkey = U256::from(0);
         let computekey = U256::from(0);
         let uid: u16 = 0;
-        let stake_amount: u64 = 1;
+        let stake_amount: u128 = 1;
         add_network(netuid, u16::MAX - 1, 0); // set higher tempo to avoid built-in epoch, then manual epoch instead
         BasedNode::set_max_allowed_uids(netuid, 1);
         BasedNode::add_balance_to_personalkey_account(&personalkey, stake_amount);
@@ -596,7 +596,7 @@ fn test_10_graph() {
     new_test_ext().execute_with(|| {
         log::info!("test_10_graph");
         // Function for adding a nodes to the graph.
-        pub fn add_node(netuid: u16, personalkey: U256, computekey: U256, uid: u16, stake_amount: u64) {
+        pub fn add_node(netuid: u16, personalkey: U256, computekey: U256, uid: u16, stake_amount: u128) {
             log::info!(
                 "+Add net:{:?} personalkey:{:?} computekey:{:?} uid:{:?} stake_amount: {:?} subn: {:?}",
                 netuid,
@@ -661,7 +661,7 @@ fn test_512_graph() {
     let netuid: u16 = 1;
     let network_n: u16 = 512;
     let validators_n: u16 = 64;
-    let max_stake_per_validator: u64 = 328_125_000_000_000; // 21_000_000_000_000_000 / 64
+    let max_stake_per_validator: u128 = 328_125_000_000_000; // 21_000_000_000_000_000 / 64
     let epochs: u16 = 3;
     log::info!("test_{network_n:?}_graph ({validators_n:?} validators)");
     for interleave in 0..3 {
@@ -748,7 +748,7 @@ fn test_512_graph_random_weights() {
                 Vec<u16>,
                 Vec<u16>,
                 Vec<u16>,
-                Vec<u64>,
+                Vec<u128>,
                 Vec<I32F32>,
                 Vec<I32F32>,
             ) = (vec![], vec![], vec![], vec![], vec![], vec![]);
@@ -836,7 +836,7 @@ fn test_4096_graph() {
     let network_n: u16 = 4096;
     let validators_n: u16 = 256;
     let epochs: u16 = 1;
-    let max_stake_per_validator: u64 = 82_031_250_000_000; // 21_000_000_000_000_000 / 256
+    let max_stake_per_validator: u128 = 82_031_250_000_000; // 21_000_000_000_000_000 / 256
     log::info!("test_{network_n:?}_graph ({validators_n:?} validators)");
     for interleave in 0..3 {
         let (validators, servers) = distribute_nodes(
@@ -976,8 +976,8 @@ fn test_bonds() {
 		let netuid: u16 = 1;
 		let tempo: u16 = u16::MAX - 1;  // high tempo to skip automatic epochs in on_initialize, use manual epochs instead
 		let block_number: u64 = 0;
-		let max_stake: u64 = 4;
-		let stakes: Vec<u64> = vec![1, 2, 3, 4, 0, 0, 0, 0];
+		let max_stake: u128 = 4;
+		let stakes: Vec<u128> = vec![1, 2, 3, 4, 0, 0, 0, 0];
 		add_network(netuid, tempo, 0);
 		BasedNode::set_max_allowed_uids( netuid, n );
 		assert_eq!(BasedNode::get_max_allowed_uids(netuid), n);
@@ -1271,7 +1271,7 @@ fn test_active_stake() {
         let netuid: u16 = 1;
         let tempo: u16 = u16::MAX - 1; // high tempo to skip automatic epochs in on_initialize, use manual epochs instead
         let block_number: u64 = 0;
-        let stake: u64 = 1;
+        let stake: u128 = 1;
         add_network(netuid, tempo, 0);
         BasedNode::set_max_allowed_uids(netuid, n);
         assert_eq!(BasedNode::get_max_allowed_uids(netuid), n);
@@ -1479,7 +1479,7 @@ fn test_outdated_weights() {
         let netuid: u16 = 1;
         let tempo: u16 = u16::MAX - 1; // high tempo to skip automatic epochs in on_initialize, use manual epochs instead
         let mut block_number: u64 = 0;
-        let stake: u64 = 1;
+        let stake: u128 = 1;
         add_network(netuid, tempo, 0);
         BasedNode::set_max_allowed_uids(netuid, n);
         BasedNode::set_weights_set_rate_limit(netuid, 0);
@@ -1661,7 +1661,7 @@ fn test_zero_weights() {
         let netuid: u16 = 1;
         let tempo: u16 = u16::MAX - 1; // high tempo to skip automatic epochs in on_initialize, use manual epochs instead
         let mut block_number: u64 = 0;
-        let stake: u64 = 1;
+        let stake: u128 = 1;
         add_network(netuid, tempo, 0);
         BasedNode::set_max_allowed_uids(netuid, n);
         BasedNode::set_weights_set_rate_limit(netuid, 0);
@@ -1860,7 +1860,7 @@ fn test_validator_permits() {
                     interleave as usize,
                 );
                 let correct: bool = true;
-                let mut stake: Vec<u64> = vec![0; network_n];
+                let mut stake: Vec<u128> = vec![0; network_n];
                 for validator in &validators {
                     stake[*validator as usize] = match assignment {
                         1 => *validator as u64 + network_n as u64,
@@ -2074,3 +2074,107 @@ fn test_validator_permits() {
 //     }
 //     println!("]");
 // }
+
+// Test that the epoch dry run returns what the epoch stores, without storing it.
+#[test]
+fn test_epoch_dry_run() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let n: u16 = 4;
+        add_network(netuid, u16::MAX - 1, 0); // set higher tempo to avoid built-in epoch, then manual epoch instead
+        BasedNode::set_max_allowed_uids(netuid, n);
+        for uid in 0..n {
+            let key = U256::from(uid);
+            let stake_amount: u128 = 1_000 * (uid as u128 + 1);
+            BasedNode::add_balance_to_personalkey_account(&key, stake_amount);
+            BasedNode::increase_stake_on_personalkey_computekey_account(&key, &key, stake_amount);
+            BasedNode::append_agent(netuid, &key, 0);
+        }
+        run_to_block(1);
+        BasedNode::epoch(netuid, 1_000_000_000); // hands out the validator permits
+
+        for uid in 0..n {
+            pallet_basednode::Weights::<Test>::insert(netuid, uid, vec![(0, u16::MAX), (1, u16::MAX / 2)]);
+            BasedNode::set_last_update_for_uid(netuid, uid, 1);
+        }
+        pallet_basednode::PendingEmission::<Test>::insert(netuid, 1_000_000_000);
+
+        // Nothing is written by the dry run.
+        let dry_run = BasedNode::epoch_dry_run(netuid).unwrap();
+        assert_eq!(dry_run.rao_emission, 1_000_000_000);
+        assert!(dry_run.ranks[0] > 0);
+        assert_eq!(BasedNode::get_rank_for_uid(netuid, 0), 0);
+        assert_eq!(pallet_basednode::PendingEmission::<Test>::get(netuid), 1_000_000_000);
+
+        // The epoch stores what the dry run returned.
+        let emission = BasedNode::epoch(netuid, 1_000_000_000);
+        for uid in 0..n {
+            assert_eq!(dry_run.ranks[uid as usize], BasedNode::get_rank_for_uid(netuid, uid));
+            assert_eq!(dry_run.consensus[uid as usize], BasedNode::get_consensus_for_uid(netuid, uid));
+            assert_eq!(dry_run.incentive[uid as usize], BasedNode::get_incentive_for_uid(netuid, uid));
+            assert_eq!(dry_run.dividends[uid as usize], BasedNode::get_dividends_for_uid(netuid, uid));
+        }
+        assert_eq!(
+            dry_run.emission.iter().map(|(_, server, validator)| (*server, *validator)).collect::<Vec<_>>(),
+            emission.iter().map(|(_, server, validator)| (*server, *validator)).collect::<Vec<_>>()
+        );
+        assert!(BasedNode::epoch_dry_run(netuid + 1).is_none());
+    });
+}
+
+// Test that a brain set to stake-weighted average consensus ranks agents without clipping or bonds,
+// where Yuma clips weights without majority support.
+#[test]
+fn test_stake_weighted_average_consensus() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let n: u16 = 4;
+        add_network(netuid, u16::MAX - 1, 0); // set higher tempo to avoid built-in epoch, then manual epoch instead
+        BasedNode::set_max_allowed_uids(netuid, n);
+        for uid in 0..n {
+            let key = U256::from(uid);
+            let stake_amount: u128 = 1_000 * (uid as u128 + 1);
+            BasedNode::add_balance_to_personalkey_account(&key, stake_amount);
+            BasedNode::increase_stake_on_personalkey_computekey_account(&key, &key, stake_amount);
+            BasedNode::append_agent(netuid, &key, 0);
+        }
+        run_to_block(1);
+        BasedNode::epoch(netuid, 1_000_000_000); // hands out the validator permits
+
+        // Neither server has the support of a stake majority.
+        pallet_basednode::Weights::<Test>::insert(netuid, 2, vec![(0, u16::MAX)]);
+        pallet_basednode::Weights::<Test>::insert(netuid, 3, vec![(1, u16::MAX)]);
+        for uid in 0..n {
+            BasedNode::set_last_update_for_uid(netuid, uid, 1);
+        }
+
+        // Yuma clips both servers to zero.
+        assert_eq!(
+            BasedNode::get_consensus_mechanism(netuid),
+            pallet_basednode::ConsensusMechanismType::Yuma
+        );
+        BasedNode::epoch(netuid, 1_000_000_000);
+        assert_eq!(BasedNode::get_incentive_for_uid(netuid, 0), 0);
+        assert_eq!(BasedNode::get_incentive_for_uid(netuid, 1), 0);
+
+        // The stake-weighted average ranks both servers by the stake behind them.
+        BasedNode::set_consensus_mechanism(netuid, pallet_basednode::ConsensusMechanismType::StakeWeightedAverage);
+        let emission = BasedNode::epoch(netuid, 1_000_000_000);
+        assert!(BasedNode::get_incentive_for_uid(netuid, 0) > 0);
+        assert!(BasedNode::get_incentive_for_uid(netuid, 1) > BasedNode::get_incentive_for_uid(netuid, 0));
+        assert_eq!(BasedNode::get_incentive_for_uid(netuid, 2), 0);
+
+        // Only the validators that set weights earn dividends, by stake.
+        assert_eq!(BasedNode::get_dividends_for_uid(netuid, 0), 0);
+        assert!(BasedNode::get_dividends_for_uid(netuid, 2) > 0);
+        assert!(BasedNode::get_dividends_for_uid(netuid, 3) > BasedNode::get_dividends_for_uid(netuid, 2));
+
+        // No bonds are kept.
+        for row in BasedNode::get_bonds(netuid) {
+            assert!(row.iter().all(|bond| *bond == I32F32::from_num(0)));
+        }
+        let emission_sum: u128 = emission.iter().map(|(_, server, validator)| server + validator).sum();
+        assert!(emission_sum <= 1_000_000_000);
+        assert!(emission_sum > 999_000_000);
+    });
+}
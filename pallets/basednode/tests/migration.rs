@@ -5,6 +5,8 @@ use sp_core::U256;
 #[test]
 fn test_migration_fix_total_stake_maps() {
     new_test_ext().execute_with(|| {
+        use frame_support::storage::unhashed;
+
         let ck1 = U256::from(1);
         let ck2 = U256::from(2);
         let ck3 = U256::from(3);
@@ -12,60 +14,46 @@ fn test_migration_fix_total_stake_maps() {
         let hk1 = U256::from(1 + 100);
         let hk2 = U256::from(2 + 100);
 
-        let mut total_stake_amount = 0;
-
-        // Give each personalkey some stake in the maps
-        BasedNode::increase_stake_on_personalkey_computekey_account(&ck1, &hk1, 100);
-        total_stake_amount += 100;
-
-        BasedNode::increase_stake_on_personalkey_computekey_account(&ck2, &hk1, 10_101);
-        total_stake_amount += 10_101;
-
-        BasedNode::increase_stake_on_personalkey_computekey_account(&ck3, &hk2, 100_000_000);
-        total_stake_amount += 100_000_000;
-
-        BasedNode::increase_stake_on_personalkey_computekey_account(&ck1, &hk2, 1_123_000_000);
-        total_stake_amount += 1_123_000_000;
-
-        // Check that the total stake is correct
-        assert_eq!(BasedNode::get_total_stake(), total_stake_amount);
-
-        // Check that the total personalkey stake is correct
-        assert_eq!(
-            BasedNode::get_total_stake_for_personalkey(&ck1),
-            100 + 1_123_000_000
+        // Chains that still need this migration keep stake in the old u64 encoding
+        let stakes: Vec<(U256, U256, u64)> = vec![
+            (ck1, hk1, 100),
+            (ck2, hk1, 10_101),
+            (ck3, hk2, 100_000_000),
+            (ck1, hk2, 1_123_000_000),
+        ];
+        let total_stake_amount: u64 = stakes.iter().map(|(_, _, stake)| stake).sum();
+        for (personalkey, computekey, stake) in stakes.iter() {
+            unhashed::put(
+                &pallet_basednode::Stake::<Test>::hashed_key_for(computekey, personalkey),
+                stake,
+            );
+        }
+        unhashed::put(
+            &pallet_basednode::TotalComputekeyStake::<Test>::hashed_key_for(hk1),
+            &(100u64 + 10_101),
         );
-        assert_eq!(BasedNode::get_total_stake_for_personalkey(&ck2), 10_101);
-        assert_eq!(
-            BasedNode::get_total_stake_for_personalkey(&ck3),
-            100_000_000
+        unhashed::put(
+            &pallet_basednode::TotalComputekeyStake::<Test>::hashed_key_for(hk2),
+            &(100_000_000u64 + 1_123_000_000),
         );
 
-        // Check that the total computekey stake is correct
-        assert_eq!(
-            BasedNode::get_total_stake_for_computekey(&hk1),
-            100 + 10_101
+        // Mess up the total personalkey stake and the total stake
+        unhashed::put(
+            &pallet_basednode::TotalPersonalkeyStake::<Test>::hashed_key_for(ck1),
+            &0u64,
         );
-        assert_eq!(
-            BasedNode::get_total_stake_for_computekey(&hk2),
-            100_000_000 + 1_123_000_000
+        unhashed::put(
+            &pallet_basednode::TotalPersonalkeyStake::<Test>::hashed_key_for(ck2),
+            &7u64,
         );
+        unhashed::put(&pallet_basednode::TotalStake::<Test>::hashed_key(), &123_456_789u64);
 
-        // Mess up the total personalkey stake
-        pallet_basednode::TotalPersonalkeyStake::<Test>::insert(ck1, 0);
-        // Verify that the total personalkey stake is now 0 for ck1
-        assert_eq!(BasedNode::get_total_stake_for_personalkey(&ck1), 0);
-
-        // Mess up the total stake
-        pallet_basednode::TotalStake::<Test>::put(123_456_789);
-        // Verify that the total stake is now wrong
-        assert_ne!(BasedNode::get_total_stake(), total_stake_amount);
-
-        // Run the migration to fix the total stake maps
+        // Run the migration to fix the total stake maps, then widen them as the runtime upgrade does
         pallet_basednode::migration::migrate_to_v2_fixed_total_stake::<Test>();
+        pallet_basednode::migration::migrate_to_v6_u128_stake::<Test>();
 
         // Verify that the total stake is now correct
-        assert_eq!(BasedNode::get_total_stake(), total_stake_amount);
+        assert_eq!(BasedNode::get_total_stake(), total_stake_amount as u128);
         // Verify that the total personalkey stake is now correct for each personalkey
         assert_eq!(
             BasedNode::get_total_stake_for_personalkey(&ck1),
@@ -86,6 +74,10 @@ fn test_migration_fix_total_stake_maps() {
             BasedNode::get_total_stake_for_computekey(&hk2),
             100_000_000 + 1_123_000_000
         );
+        assert_eq!(
+            BasedNode::get_stake_for_personalkey_and_computekey(&ck1, &hk2),
+            1_123_000_000
+        );
 
         // Verify that the Stake map has no extra entries
         assert_eq!(pallet_basednode::Stake::<Test>::iter().count(), 4); // 4 entries total
@@ -148,3 +140,135 @@ fn test_migration_delete_brain_21() {
         assert_eq!(BasedNode::if_brain_exist(21), false);
     })
 }
+
+#[test]
+fn test_migration_u128_stake() {
+    new_test_ext().execute_with(|| {
+        use frame_support::storage::unhashed;
+
+        let ck = U256::from(1);
+        let hk = U256::from(1 + 100);
+        let stake: u64 = 1_123_000_000;
+
+        // Write stake in the old u64 encoding
+        unhashed::put(&pallet_basednode::TotalStake::<Test>::hashed_key(), &stake);
+        unhashed::put(
+            &pallet_basednode::TotalComputekeyStake::<Test>::hashed_key_for(hk),
+            &stake,
+        );
+        unhashed::put(
+            &pallet_basednode::TotalPersonalkeyStake::<Test>::hashed_key_for(ck),
+            &stake,
+        );
+        unhashed::put(
+            &pallet_basednode::Stake::<Test>::hashed_key_for(hk, ck),
+            &stake,
+        );
+
+        // Run the migration to widen the stake maps
+        pallet_basednode::migration::migrate_to_v6_u128_stake::<Test>();
+
+        // Verify that the stake is now decoded as u128
+        assert_eq!(BasedNode::get_total_stake(), stake as u128);
+        assert_eq!(
+            BasedNode::get_total_stake_for_computekey(&hk),
+            stake as u128
+        );
+        assert_eq!(
+            BasedNode::get_total_stake_for_personalkey(&ck),
+            stake as u128
+        );
+        assert_eq!(
+            BasedNode::get_stake_for_personalkey_and_computekey(&ck, &hk),
+            stake as u128
+        );
+    })
+}
+
+#[test]
+fn test_migration_u128_emission() {
+    new_test_ext().execute_with(|| {
+        use codec::Encode;
+        use frame_support::storage::{storage_prefix, unhashed};
+
+        let netuid: u16 = 1;
+        let emission: u64 = u64::MAX;
+
+        // Write the emission in the old u64 encoding
+        unhashed::put(
+            &pallet_basednode::EmissionValues::<Test>::hashed_key_for(netuid),
+            &emission,
+        );
+        unhashed::put(
+            &pallet_basednode::PendingEmission::<Test>::hashed_key_for(netuid),
+            &emission,
+        );
+        let mut uid_emission_key: Vec<u8> = storage_prefix(b"BasedNode", b"Emission").to_vec();
+        uid_emission_key.extend(netuid.encode());
+        unhashed::put(&uid_emission_key, &vec![emission, 1]);
+
+        pallet_basednode::migration::migrate_to_v6_u128_stake::<Test>();
+
+        // Verify that the emission is now decoded as u128
+        assert_eq!(BasedNode::get_emission_value(netuid), emission as u128);
+        assert_eq!(BasedNode::get_pending_emission(netuid), emission as u128);
+        assert_eq!(BasedNode::get_emission(netuid), vec![emission as u128, 1]);
+    })
+}
+
+#[test]
+fn test_migration_loaded_emission_tuples() {
+    new_test_ext().execute_with(|| {
+        use codec::Encode;
+        use frame_support::storage::{storage_prefix, unhashed};
+
+        let netuid: u16 = 1;
+        let tuples: Vec<(U256, u128, u128)> =
+            vec![(U256::from(1), 1, 2), (U256::from(2), 3, 4), (U256::from(3), 5, 6)];
+
+        // Write the tuples as one vector, with the first one already drained.
+        let mut loaded_emission_key: Vec<u8> = storage_prefix(b"BasedNode", b"LoadedEmission").to_vec();
+        loaded_emission_key.extend(netuid.encode());
+        unhashed::put(&loaded_emission_key, &tuples);
+        let mut cursor_key: Vec<u8> = storage_prefix(b"BasedNode", b"LoadedEmissionCursor").to_vec();
+        cursor_key.extend(netuid.encode());
+        unhashed::put(&cursor_key, &1u32);
+
+        pallet_basednode::migration::migrate_to_v8_loaded_emission_tuples::<Test>();
+
+        // The tuples not drained yet are stored by position and the vector is gone.
+        assert!(unhashed::get_raw(&loaded_emission_key).is_none());
+        assert!(BasedNode::has_loaded_emission_tuples(netuid));
+        assert_eq!(BasedNode::get_loaded_emission_tuples(netuid), tuples[1..].to_vec());
+    })
+}
+
+#[test]
+fn test_migration_seed_brain_token_supply() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(77);
+        let contract = U256::from(1000);
+        let supply: u128 = 1_000_000_000;
+        add_network(netuid, 10, 0);
+        pallet_basednode::BrainOwner::<Test>::insert(netuid, owner);
+        deploy_erc20(owner, contract, supply);
+
+        // A contract bound before the supply was tracked accrues nothing.
+        pallet_basednode::BrainContract::<Test>::insert(netuid, contract);
+        assert_eq!(BasedNode::accrue_brain_dividends(&contract, 1_000), 0);
+        assert_eq!(BasedNode::get_contract_brain(&contract), None);
+
+        // Seed the supply and the owner balance from the contract
+        pallet_basednode::migration::migrate_to_v7_seed_brain_token_supply::<Test>();
+        assert_eq!(BasedNode::get_contract_brain(&contract), Some(netuid));
+
+        assert_eq!(pallet_basednode::BrainTokenSupply::<Test>::get(contract), supply);
+        assert_eq!(
+            pallet_basednode::BrainOwnerByTokenBalanceCut::<Test>::get(contract, owner),
+            supply
+        );
+        assert_eq!(pallet_basednode::BrainEligibleTokenSupply::<Test>::get(contract), supply);
+        assert_eq!(BasedNode::accrue_brain_dividends(&contract, 1_000), 1_000);
+    })
+}
@@ -63,7 +63,7 @@ pub type Address = AccountId;
 
 // Balance of an account.
 #[allow(dead_code)]
-pub type Balance = u64;
+pub type Balance = u128;
 
 // An index to a block.
 #[allow(dead_code)]
@@ -134,13 +134,13 @@ parameter_types! {
     pub const InitialWeightsVersionKey: u16 = 0;
     pub const InitialServingRateLimit: u64 = 0; // No limit.
     pub const InitialTxRateLimit: u64 = 0; // Disable rate limit for testing
-    pub const InitialBurn: u64 = 0;
-    pub const InitialMinBurn: u64 = 0;
-    pub const InitialMaxBurn: u64 = 1_000_000_000;
+    pub const InitialBurn: u128 = 0;
+    pub const InitialMinBurn: u128 = 0;
+    pub const InitialMaxBurn: u128 = 1_000_000_000;
     pub const InitialValidatorPruneLen: u64 = 0;
     pub const InitialScalingLawPower: u16 = 50;
     pub const InitialMaxAllowedValidators: u16 = 100;
-    pub const InitialIssuance: u64 = 0;
+    pub const InitialIssuance: u128 = 0;
     pub const InitialDifficulty: u64 = 10000;
     pub const InitialActivityCutoff: u16 = 5000;
     pub const InitialAdjustmentInterval: u16 = 100;
@@ -151,16 +151,26 @@ parameter_types! {
     pub const InitialRegistrationRequirement: u16 = u16::MAX; // Top 100%
     pub const InitialMinDifficulty: u64 = 1;
     pub const InitialMaxDifficulty: u64 = u64::MAX;
-    pub const InitialRAORecycledForRegistration: u64 = 0;
+    pub const InitialRAORecycledForRegistration: u128 = 0;
     pub const InitialSenateRequiredStakePercentage: u64 = 2; // 2 percent of total stake
     pub const InitialNetworkImmunityPeriod: u64 = 7200 * 7;
     pub const InitialNetworkMinAllowedUids: u16 = 128;
-    pub const InitialNetworkMinLockCost: u64 = 100_000_000_000;
+    pub const InitialNetworkMinLockCost: u128 = 100_000_000_000;
     pub const InitialBrainOwnerCut: u16 = 0; // 0%. 100% of rewards go to validators + miners.
     pub const InitialNetworkLockReductionInterval: u64 = 2; // 2 blocks.
     pub const InitialBrainLimit: u16 = 10; // Max 10 brains.
     pub const InitialNetworkRateLimit: u64 = 0;
 	pub const InitialBrainOwnerByTokenBalanceCut: u128 = 10;
+    pub const InitialDelegateTakeIncreaseDelay: u64 = 10; // 10 blocks.
+    pub const InitialUnbondingPeriod: u64 = 0; // Release removed stake immediately.
+    pub const InitialEmissionSplit: u16 = 80; // 80% by root weights, 20% split equally.
+    pub const InitialHalvingInterval: u64 = 3_153_600; // A year of 10s blocks.
+    pub const InitialTokenHolderThreshold: u16 = 1_311; // 2% of the token supply.
+    pub const InitialBrainOwnershipProposalExpiry: u64 = 10; // 10 blocks.
+    pub const InitialHyperparamChangeDelay: u64 = 10; // 10 blocks.
+    pub const InitialBrainMetadataDeposit: u128 = 1_000;
+    pub TransactionFeeDestination: pallet_basednode::TransactionFeeDestination<U256> =
+        pallet_basednode::TransactionFeeDestination::Treasury(U256::from(1_000_000)); // Collected fees go to account 1_000_000.
 
 }
 
@@ -313,14 +323,77 @@ impl pallet_membership::Config<SenateMembership> for Test {
     type WeightInfo = pallet_membership::weights::SubstrateWeight<Test>;
 }
 
+thread_local! {
+    // ERC20 Transfer logs handed to the pallet on its next sync, ( contract, from, to, value ).
+    static TOKEN_TRANSFER_LOGS: std::cell::RefCell<Vec<(U256, U256, U256, U256)>> = std::cell::RefCell::new(Vec::new());
+    // ERC20 contracts deployed on the mock EVM, contract --> ( total supply, holder balances ).
+    static ERC20_CONTRACTS: std::cell::RefCell<Vec<(U256, u128, Vec<(U256, u128)>)>> = std::cell::RefCell::new(Vec::new());
+}
+
+pub struct MockTokenTransferLogs;
+impl pallet_basednode::TokenTransferLogs<AccountId> for MockTokenTransferLogs {
+    fn transfers() -> Vec<(AccountId, AccountId, AccountId, U256)> {
+        TOKEN_TRANSFER_LOGS.with(|logs| logs.borrow_mut().drain(..).collect())
+    }
+}
+
+#[allow(dead_code)]
+pub fn push_token_transfer_log(contract: U256, from: U256, to: U256, value: U256) {
+    TOKEN_TRANSFER_LOGS.with(|logs| logs.borrow_mut().push((contract, from, to, value)));
+}
+
+pub struct MockBrainTokenInspector;
+impl pallet_basednode::BrainTokenInspector<AccountId> for MockBrainTokenInspector {
+    fn is_erc20(contract: &AccountId) -> bool {
+        ERC20_CONTRACTS.with(|contracts| contracts.borrow().iter().any(|(c, _, _)| c == contract))
+    }
+
+    fn total_supply(contract: &AccountId) -> Option<u128> {
+        ERC20_CONTRACTS.with(|contracts| {
+            contracts.borrow().iter().find(|(c, _, _)| c == contract).map(|(_, supply, _)| *supply)
+        })
+    }
+
+    fn balance_of(contract: &AccountId, holder: &AccountId) -> Option<u128> {
+        ERC20_CONTRACTS.with(|contracts| {
+            contracts.borrow().iter().find(|(c, _, _)| c == contract).map(|(_, _, balances)| {
+                balances.iter().find(|(h, _)| h == holder).map(|(_, b)| *b).unwrap_or(0)
+            })
+        })
+    }
+}
+
+// Writes the balance of a holder to the storage of a mock ERC20 contract, as a transfer on the EVM would.
+#[allow(dead_code)]
+pub fn set_erc20_balance(contract: U256, holder: U256, balance: u128) {
+    ERC20_CONTRACTS.with(|contracts| {
+        if let Some((_, _, balances)) = contracts.borrow_mut().iter_mut().find(|(c, _, _)| *c == contract) {
+            balances.retain(|(h, _)| *h != holder);
+            balances.push((holder, balance));
+        }
+    });
+}
+
+// Deploys an ERC20 contract on the mock EVM, minting the whole supply to the deployer.
+#[allow(dead_code)]
+pub fn deploy_erc20(deployer: U256, contract: U256, supply: u128) {
+    ERC20_CONTRACTS.with(|contracts| contracts.borrow_mut().push((contract, supply, vec![(deployer, supply)])));
+    BasedNode::note_contract_created(&deployer, &contract);
+}
+
 impl pallet_basednode::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
     type Currency = Balances;
     type InitialIssuance = InitialIssuance;
     type SudoRuntimeCall = TestRuntimeCall;
     type CouncilOrigin = frame_system::EnsureSigned<AccountId>;
     type SenateMembers = ManageSenateMembers;
     type TriumvirateInterface = TriumvirateVotes;
+    type FindAuthor = ();
+    type TokenTransferLogs = MockTokenTransferLogs;
+    type BrainTokenInspector = MockBrainTokenInspector;
+    type TransactionFeeDestination = TransactionFeeDestination;
 
     type InitialMinAllowedWeights = InitialMinAllowedWeights;
     type InitialEmissionValue = InitialEmissionValue;
@@ -360,6 +433,14 @@ impl pallet_basednode::Config for Test {
     type InitialBrainLimit = InitialBrainLimit;
     type InitialNetworkRateLimit = InitialNetworkRateLimit;
 	type InitialBrainOwnerByTokenBalanceCut = InitialBrainOwnerByTokenBalanceCut;
+    type InitialDelegateTakeIncreaseDelay = InitialDelegateTakeIncreaseDelay;
+    type InitialUnbondingPeriod = InitialUnbondingPeriod;
+    type InitialEmissionSplit = InitialEmissionSplit;
+    type InitialHalvingInterval = InitialHalvingInterval;
+    type InitialTokenHolderThreshold = InitialTokenHolderThreshold;
+    type InitialBrainOwnershipProposalExpiry = InitialBrainOwnershipProposalExpiry;
+    type InitialHyperparamChangeDelay = InitialHyperparamChangeDelay;
+    type InitialBrainMetadataDeposit = InitialBrainMetadataDeposit;
 
 }
 
@@ -395,8 +476,8 @@ pub fn test_ext_with_balances(balances: Vec<(U256, u128)>) -> sp_io::TestExterna
     pallet_balances::GenesisConfig::<Test> {
         balances: balances
             .iter()
-            .map(|(a, b)| (*a, *b as u64))
-            .collect::<Vec<(U256, u64)>>(),
+            .map(|(a, b)| (*a, *b))
+            .collect::<Vec<(U256, u128)>>(),
     }
     .assimilate_storage(&mut t)
     .unwrap();
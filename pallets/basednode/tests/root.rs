@@ -590,15 +590,336 @@ fn test_network_prune_results() {
         step_block(3);
 
         // lowest emission
-        BasedNode::set_emission_values(&vec![1u16, 2u16, 3u16], vec![5u64, 4u64, 4u64]);
+        BasedNode::set_emission_values(&vec![1u16, 2u16, 3u16], vec![5u128, 4u128, 4u128]);
         assert_eq!(BasedNode::get_brain_to_prune(), 2u16);
 
         // equal emission, creation date
-        BasedNode::set_emission_values(&vec![1u16, 2u16, 3u16], vec![5u64, 5u64, 4u64]);
+        BasedNode::set_emission_values(&vec![1u16, 2u16, 3u16], vec![5u128, 5u128, 4u128]);
         assert_eq!(BasedNode::get_brain_to_prune(), 3u16);
 
         // equal emission, creation date
-        BasedNode::set_emission_values(&vec![1u16, 2u16, 3u16], vec![4u64, 5u64, 5u64]);
+        BasedNode::set_emission_values(&vec![1u16, 2u16, 3u16], vec![4u128, 5u128, 5u128]);
         assert_eq!(BasedNode::get_brain_to_prune(), 1u16);
     });
 }
+
+#[test]
+fn test_register_and_dissolve_network() {
+    new_test_ext().execute_with(|| {
+        migration::migrate_create_root_network::<Test>();
+        let owner: U256 = U256::from(1);
+        let other: U256 = U256::from(2);
+        BasedNode::add_balance_to_personalkey_account(&owner, 1_000_000_000_000_000);
+
+        // The lock is taken from the personalkey of the owner.
+        let lock_cost: u128 = BasedNode::get_network_lock_cost();
+        assert_ok!(BasedNode::register_network(<<Test as Config>::RuntimeOrigin>::signed(owner)));
+        assert!(BasedNode::if_brain_exist(1));
+        assert_eq!(pallet_basednode::BrainOwner::<Test>::get(1), owner);
+        assert_eq!(BasedNode::get_brain_locked_balance(1), lock_cost);
+        assert_eq!(
+            BasedNode::get_personalkey_balance(&owner),
+            1_000_000_000_000_000 - lock_cost
+        );
+
+        // Registrations are rate limited.
+        BasedNode::set_network_rate_limit(2);
+        step_block(1);
+        assert_eq!(
+            BasedNode::register_network(<<Test as Config>::RuntimeOrigin>::signed(owner)),
+            Err(Error::<Test>::TxRateLimitExceeded.into())
+        );
+
+        // Without the balance for the lock the registration fails.
+        step_block(1);
+        assert_eq!(
+            BasedNode::register_network(<<Test as Config>::RuntimeOrigin>::signed(other)),
+            Err(Error::<Test>::NotEnoughBalanceToStake.into())
+        );
+
+        // Only the owner dissolves the brain, the lock is returned to it.
+        assert_eq!(
+            BasedNode::dissolve_network(<<Test as Config>::RuntimeOrigin>::signed(other), 1),
+            Err(Error::<Test>::NotBrainOwner.into())
+        );
+        assert_ok!(BasedNode::dissolve_network(<<Test as Config>::RuntimeOrigin>::signed(owner), 1));
+        assert!(!BasedNode::if_brain_exist(1));
+        assert_eq!(BasedNode::get_brain_locked_balance(1), 0);
+        assert_eq!(BasedNode::get_personalkey_balance(&owner), 1_000_000_000_000_000);
+        assert_eq!(
+            BasedNode::dissolve_network(<<Test as Config>::RuntimeOrigin>::signed(owner), 1),
+            Err(Error::<Test>::NetworkDoesNotExist.into())
+        );
+    });
+}
+
+#[test]
+fn test_register_network_prunes_at_brain_limit() {
+    new_test_ext().execute_with(|| {
+        migration::migrate_create_root_network::<Test>();
+        BasedNode::set_max_brains(1);
+        BasedNode::set_network_immunity_period(2);
+        BasedNode::set_network_min_lock(100);
+        let (first, second): (U256, U256) = (U256::from(1), U256::from(2));
+        BasedNode::add_balance_to_personalkey_account(&first, 1_000_000_000_000_000);
+        BasedNode::add_balance_to_personalkey_account(&second, 1_000_000_000_000_000);
+
+        assert_ok!(BasedNode::register_network(<<Test as Config>::RuntimeOrigin>::signed(first)));
+        let first_lock: u128 = BasedNode::get_brain_locked_balance(1);
+
+        // The only brain is still immune.
+        step_block(1);
+        assert_eq!(
+            BasedNode::register_network(<<Test as Config>::RuntimeOrigin>::signed(second)),
+            Err(Error::<Test>::AllNetworksInImmunity.into())
+        );
+
+        // Once it is not, it is pruned and its netuid reused, the lock goes back to its owner.
+        step_block(2);
+        assert_ok!(BasedNode::register_network(<<Test as Config>::RuntimeOrigin>::signed(second)));
+        assert_eq!(BasedNode::get_num_brains(), 2);
+        assert_eq!(pallet_basednode::BrainOwner::<Test>::get(1), second);
+        assert_eq!(BasedNode::get_personalkey_balance(&first), 1_000_000_000_000_000);
+        assert!(first_lock > 0);
+    });
+}
+
+#[test]
+fn test_brain_ownership_transfer() {
+    new_test_ext().execute_with(|| {
+        migration::migrate_create_root_network::<Test>();
+        let (owner, new_owner, other): (U256, U256, U256) = (U256::from(1), U256::from(2), U256::from(3));
+        let contract: U256 = U256::from(1000);
+        BasedNode::add_balance_to_personalkey_account(&owner, 1_000_000_000_000_000);
+        assert_ok!(BasedNode::register_network(<<Test as Config>::RuntimeOrigin>::signed(owner)));
+        let lock: u128 = BasedNode::get_brain_locked_balance(1);
+        deploy_erc20(owner, contract, 1_000);
+        assert_ok!(BasedNode::bind_brain_contract(<<Test as Config>::RuntimeOrigin>::signed(owner), 1, contract));
+
+        // Only the owner proposes, and not itself.
+        assert_eq!(
+            BasedNode::propose_brain_owner(<<Test as Config>::RuntimeOrigin>::signed(other), 1, other),
+            Err(Error::<Test>::NotBrainOwner.into())
+        );
+        assert_eq!(
+            BasedNode::propose_brain_owner(<<Test as Config>::RuntimeOrigin>::signed(owner), 1, owner),
+            Err(Error::<Test>::AlreadyBrainOwner.into())
+        );
+
+        // Proposals expire.
+        assert_ok!(BasedNode::propose_brain_owner(<<Test as Config>::RuntimeOrigin>::signed(owner), 1, new_owner));
+        step_block(BasedNode::get_brain_ownership_proposal_expiry() as u16 + 1);
+        assert_eq!(
+            BasedNode::accept_brain_ownership(<<Test as Config>::RuntimeOrigin>::signed(new_owner), 1),
+            Err(Error::<Test>::BrainOwnershipProposalExpired.into())
+        );
+
+        // Only the proposed owner accepts.
+        assert_ok!(BasedNode::propose_brain_owner(<<Test as Config>::RuntimeOrigin>::signed(owner), 1, new_owner));
+        assert_eq!(
+            BasedNode::accept_brain_ownership(<<Test as Config>::RuntimeOrigin>::signed(other), 1),
+            Err(Error::<Test>::NoBrainOwnershipProposal.into())
+        );
+        assert_ok!(BasedNode::accept_brain_ownership(<<Test as Config>::RuntimeOrigin>::signed(new_owner), 1));
+        assert_eq!(pallet_basednode::BrainOwner::<Test>::get(1), new_owner);
+        assert_eq!(pallet_basednode::PendingBrainOwner::<Test>::get(1), None);
+        assert_eq!(pallet_basednode::BrainContract::<Test>::get(1), contract);
+        assert_eq!(
+            BasedNode::accept_brain_ownership(<<Test as Config>::RuntimeOrigin>::signed(new_owner), 1),
+            Err(Error::<Test>::NoBrainOwnershipProposal.into())
+        );
+
+        // The lock went with the brain.
+        assert_ok!(BasedNode::dissolve_network(<<Test as Config>::RuntimeOrigin>::signed(new_owner), 1));
+        assert_eq!(BasedNode::get_personalkey_balance(&new_owner), lock);
+        assert_eq!(BasedNode::get_personalkey_balance(&owner), 1_000_000_000_000_000 - lock);
+    });
+}
+
+#[test]
+fn test_grant_and_revoke_brain_roles() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let (owner, new_owner, admin): (U256, U256, U256) = (U256::from(1), U256::from(2), U256::from(3));
+        let role = pallet_basednode::BrainRole::HyperparamAdmin;
+        add_network(netuid, 10, 0);
+        pallet_basednode::BrainOwner::<Test>::insert(netuid, owner);
+
+        // Only the owner or root manages the roles of existing brains.
+        assert_eq!(
+            BasedNode::grant_brain_role(<<Test as Config>::RuntimeOrigin>::signed(admin), netuid, admin, role),
+            Err(sp_runtime::DispatchError::BadOrigin.into())
+        );
+        assert_eq!(
+            BasedNode::grant_brain_role(<<Test as Config>::RuntimeOrigin>::root(), netuid + 1, admin, role),
+            Err(Error::<Test>::NetworkDoesNotExist.into())
+        );
+        assert_ok!(BasedNode::grant_brain_role(<<Test as Config>::RuntimeOrigin>::signed(owner), netuid, admin, role));
+        assert!(BasedNode::has_brain_role(netuid, &admin, role));
+        assert_eq!(
+            BasedNode::grant_brain_role(<<Test as Config>::RuntimeOrigin>::signed(owner), netuid, admin, role),
+            Err(Error::<Test>::BrainRoleAlreadyGranted.into())
+        );
+        assert_ok!(BasedNode::ensure_brain_role_or_root(<<Test as Config>::RuntimeOrigin>::signed(admin), netuid, role));
+        assert!(BasedNode::ensure_brain_role_or_root(
+            <<Test as Config>::RuntimeOrigin>::signed(admin),
+            netuid,
+            pallet_basednode::BrainRole::EmergencyPauser
+        )
+        .is_err());
+
+        assert_ok!(BasedNode::revoke_brain_role(<<Test as Config>::RuntimeOrigin>::signed(owner), netuid, admin, role));
+        assert!(!BasedNode::has_brain_role(netuid, &admin, role));
+        assert_eq!(
+            BasedNode::revoke_brain_role(<<Test as Config>::RuntimeOrigin>::signed(owner), netuid, admin, role),
+            Err(Error::<Test>::BrainRoleNotGranted.into())
+        );
+
+        // A new owner starts without the admins of the previous one.
+        assert_ok!(BasedNode::grant_brain_role(<<Test as Config>::RuntimeOrigin>::signed(owner), netuid, admin, role));
+        assert_ok!(BasedNode::propose_brain_owner(<<Test as Config>::RuntimeOrigin>::signed(owner), netuid, new_owner));
+        assert_ok!(BasedNode::accept_brain_ownership(<<Test as Config>::RuntimeOrigin>::signed(new_owner), netuid));
+        assert!(!BasedNode::has_brain_role(netuid, &admin, role));
+
+        // So does an owner root sets.
+        assert_ok!(BasedNode::grant_brain_role(<<Test as Config>::RuntimeOrigin>::signed(new_owner), netuid, admin, role));
+        assert_ok!(BasedNode::create_or_update_brain(<<Test as Config>::RuntimeOrigin>::root(), netuid, new_owner));
+        assert!(BasedNode::has_brain_role(netuid, &admin, role));
+        assert_ok!(BasedNode::create_or_update_brain(<<Test as Config>::RuntimeOrigin>::root(), netuid, owner));
+        assert_eq!(pallet_basednode::BrainOwner::<Test>::get(netuid), owner);
+        assert!(!BasedNode::has_brain_role(netuid, &admin, role));
+    });
+}
+
+#[test]
+fn test_emission_schedule_follows_on_chain_parameters() {
+    new_test_ext().execute_with(|| {
+        let block_emission: u128 = BasedNode::get_block_emission();
+        BasedNode::set_halving_interval(10);
+        BasedNode::set_emission_split(60);
+
+        // Emission halves every interval.
+        assert_eq!(BasedNode::current_block_emission(9), block_emission);
+        assert_eq!(BasedNode::current_block_emission(10), block_emission / 2);
+        assert_eq!(BasedNode::current_block_emission(25), block_emission / 4);
+        // And is gone once the halvings run out.
+        assert_eq!(BasedNode::current_block_emission(10 * 128), 0);
+
+        // The projection splits the emission with the stored percentage.
+        let (total, weighted, floor) = BasedNode::project_block_emission(0);
+        assert_eq!(total, block_emission);
+        assert_eq!(weighted, block_emission / 100 * 60);
+        assert_eq!(floor, block_emission / 100 * 40);
+
+        // Values above 100 percent are clamped.
+        BasedNode::set_emission_split(150);
+        let (total, weighted, floor) = BasedNode::project_block_emission(0);
+        assert_eq!(weighted, total / 100 * 100);
+        assert_eq!(floor, 0);
+    });
+}
+
+#[test]
+fn test_halving_interval_change_is_not_retroactive() {
+    new_test_ext().execute_with(|| {
+        let block_emission: u128 = BasedNode::get_block_emission();
+        BasedNode::set_halving_interval(10);
+        assert_eq!(BasedNode::current_block_emission(25), block_emission / 4);
+
+        // A longer interval keeps the two halvings already passed.
+        System::set_block_number(25);
+        BasedNode::set_halving_interval(100);
+        assert_eq!(BasedNode::get_halving_anchor(), (25, 2));
+        assert_eq!(BasedNode::current_block_emission(25), block_emission / 4);
+        assert_eq!(BasedNode::current_block_emission(124), block_emission / 4);
+        assert_eq!(BasedNode::current_block_emission(125), block_emission / 8);
+
+        // A shorter interval does not add halvings for the blocks before it was set.
+        System::set_block_number(130);
+        BasedNode::set_halving_interval(10);
+        assert_eq!(BasedNode::get_halving_anchor(), (130, 3));
+        assert_eq!(BasedNode::current_block_emission(139), block_emission / 8);
+        assert_eq!(BasedNode::current_block_emission(140), block_emission / 16);
+    });
+}
+
+#[test]
+fn test_brain_metadata_deposit() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let (owner, new_owner): (U256, U256) = (U256::from(1), U256::from(2));
+        add_network(netuid, 10, 0);
+        pallet_basednode::BrainOwner::<Test>::insert(netuid, owner);
+        BasedNode::add_balance_to_personalkey_account(&owner, 10_000);
+        BasedNode::add_balance_to_personalkey_account(&new_owner, 10_000);
+        let deposit: u128 = BasedNode::get_brain_metadata_deposit();
+        let metadata = pallet_basednode::BrainMetadata {
+            name: b"vision".to_vec().try_into().unwrap(),
+            repository: b"https://github.com/example/vision".to_vec().try_into().unwrap(),
+            modality: pallet_basednode::BrainModality::Image,
+            ..Default::default()
+        };
+
+        // Only the owner publishes metadata.
+        assert_eq!(
+            BasedNode::set_brain_metadata(<<Test as Config>::RuntimeOrigin>::signed(new_owner), netuid, metadata.clone()),
+            Err(Error::<Test>::NotBrainOwner.into())
+        );
+
+        // The deposit is locked once, updates are free.
+        assert_ok!(BasedNode::set_brain_metadata(<<Test as Config>::RuntimeOrigin>::signed(owner), netuid, metadata.clone()));
+        assert_ok!(BasedNode::set_brain_metadata(<<Test as Config>::RuntimeOrigin>::signed(owner), netuid, metadata.clone()));
+        assert_eq!(BasedNode::get_personalkey_balance(&owner), 10_000 - deposit);
+        assert_eq!(pallet_basednode::NetworkModality::<Test>::get(netuid), 1);
+        assert!(BasedNode::get_brain_metadata(netuid).is_some());
+
+        // A new owner pays its own deposit and the previous one is returned.
+        pallet_basednode::BrainOwner::<Test>::insert(netuid, new_owner);
+        assert_ok!(BasedNode::set_brain_metadata(<<Test as Config>::RuntimeOrigin>::signed(new_owner), netuid, metadata));
+        assert_eq!(BasedNode::get_personalkey_balance(&owner), 10_000);
+        assert_eq!(BasedNode::get_personalkey_balance(&new_owner), 10_000 - deposit);
+
+        // Clearing returns the deposit and resets the modality.
+        assert_ok!(BasedNode::clear_brain_metadata(<<Test as Config>::RuntimeOrigin>::root(), netuid));
+        assert_eq!(BasedNode::get_personalkey_balance(&new_owner), 10_000);
+        assert_eq!(pallet_basednode::NetworkModality::<Test>::get(netuid), 0);
+        assert!(BasedNode::get_brain_metadata(netuid).is_none());
+        assert_eq!(
+            BasedNode::clear_brain_metadata(<<Test as Config>::RuntimeOrigin>::root(), netuid),
+            Err(Error::<Test>::NoBrainMetadata.into())
+        );
+    });
+}
+
+#[test]
+fn test_brain_metadata_rate_limit() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let owner: U256 = U256::from(1);
+        add_network(netuid, 10, 0);
+        pallet_basednode::BrainOwner::<Test>::insert(netuid, owner);
+        BasedNode::add_balance_to_personalkey_account(&owner, 10_000);
+        BasedNode::set_tx_rate_limit(2);
+        step_block(3);
+        let metadata = pallet_basednode::BrainMetadata {
+            name: b"vision".to_vec().try_into().unwrap(),
+            ..Default::default()
+        };
+
+        assert_ok!(BasedNode::set_brain_metadata(<<Test as Config>::RuntimeOrigin>::signed(owner), netuid, metadata.clone()));
+        assert_eq!(
+            BasedNode::set_brain_metadata(<<Test as Config>::RuntimeOrigin>::signed(owner), netuid, metadata.clone()),
+            Err(Error::<Test>::TxRateLimitExceeded.into())
+        );
+        assert_eq!(
+            BasedNode::clear_brain_metadata(<<Test as Config>::RuntimeOrigin>::signed(owner), netuid),
+            Err(Error::<Test>::TxRateLimitExceeded.into())
+        );
+
+        // Root is not rate limited.
+        assert_ok!(BasedNode::clear_brain_metadata(<<Test as Config>::RuntimeOrigin>::root(), netuid));
+
+        step_block(3);
+        assert_ok!(BasedNode::set_brain_metadata(<<Test as Config>::RuntimeOrigin>::signed(owner), netuid, metadata));
+    });
+}
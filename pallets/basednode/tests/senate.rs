@@ -538,7 +538,7 @@ fn test_senate_not_leave_when_stake_removed() {
         ));
 
         let staker_personalkey = U256::from(7);
-        let stake_amount: u64 = 100_000;
+        let stake_amount: u128 = 100_000;
         BasedNode::add_balance_to_personalkey_account(&staker_personalkey, stake_amount);
 
         assert_ok!(BasedNode::add_stake(
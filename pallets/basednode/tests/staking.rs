@@ -1,10 +1,11 @@
 use frame_support::{assert_noop, assert_ok, traits::Currency};
 use frame_system::Config;
 mod mock;
-use frame_support::dispatch::{DispatchClass, DispatchInfo, GetDispatchInfo, Pays};
+use frame_support::dispatch::{DispatchClass, DispatchInfo, GetDispatchInfo, Pays, PostDispatchInfo};
 use frame_support::sp_runtime::DispatchError;
 use mock::*;
-use pallet_basednode::Error;
+use pallet_basednode::{BasednodeSignedExtension, CallType, Error, RewardDestination};
+use sp_runtime::traits::SignedExtension;
 use sp_core::{H256, U256};
 
 /***********************************************************
@@ -31,6 +32,91 @@ fn test_add_stake_dispatch_info_ok() {
         );
     });
 }
+#[test]
+fn test_add_stake_transaction_fee() {
+    new_test_ext().execute_with(|| {
+        let computekey = U256::from(0);
+        let personalkey = U256::from(1);
+        let treasury = U256::from(1_000_000);
+        let fee: u128 = 100_000;
+        BasedNode::set_transaction_fee(CallType::AddStake, fee);
+        BasedNode::add_balance_to_personalkey_account(&personalkey, 1_000_000);
+
+        let call = RuntimeCall::BasedNode(BasednodeCall::add_stake {
+            computekey,
+            amount_staked: 5000,
+        });
+        let info = call.get_dispatch_info();
+
+        // The full fee is withdrawn before dispatch and sent to the treasury when all weight is used.
+        let pre = BasednodeSignedExtension::<Test>::new()
+            .pre_dispatch(&personalkey, &call, &info, 0)
+            .unwrap();
+        assert_eq!(BasedNode::get_personalkey_balance(&personalkey), 900_000);
+        assert_ok!(BasednodeSignedExtension::<Test>::post_dispatch(
+            Some(pre),
+            &info,
+            &PostDispatchInfo::default(),
+            0,
+            &Ok(())
+        ));
+        assert_eq!(BasedNode::get_personalkey_balance(&personalkey), 900_000);
+        assert_eq!(BasedNode::get_personalkey_balance(&treasury), 100_000);
+
+        // Unused weight is refunded.
+        let pre = BasednodeSignedExtension::<Test>::new()
+            .pre_dispatch(&personalkey, &call, &info, 0)
+            .unwrap();
+        let post_info = PostDispatchInfo {
+            actual_weight: Some(frame_support::weights::Weight::from_ref_time(info.weight.ref_time() / 2)),
+            pays_fee: Pays::No,
+        };
+        assert_ok!(BasednodeSignedExtension::<Test>::post_dispatch(
+            Some(pre),
+            &info,
+            &post_info,
+            0,
+            &Ok(())
+        ));
+        assert_eq!(BasedNode::get_personalkey_balance(&personalkey), 850_000);
+        assert_eq!(BasedNode::get_personalkey_balance(&treasury), 150_000);
+
+        // Calls without a scheduled fee are free.
+        let call = RuntimeCall::BasedNode(BasednodeCall::remove_stake {
+            computekey,
+            amount_unstaked: 5000,
+        });
+        assert_ok!(BasednodeSignedExtension::<Test>::new().pre_dispatch(
+            &U256::from(2),
+            &call,
+            &call.get_dispatch_info(),
+            0
+        ));
+    });
+}
+
+#[test]
+fn test_add_stake_transaction_fee_cannot_pay() {
+    new_test_ext().execute_with(|| {
+        let personalkey = U256::from(1);
+        BasedNode::set_transaction_fee(CallType::AddStake, 100_000);
+        BasedNode::add_balance_to_personalkey_account(&personalkey, 1_000);
+
+        let call = RuntimeCall::BasedNode(BasednodeCall::add_stake {
+            computekey: U256::from(0),
+            amount_staked: 500,
+        });
+        let info = call.get_dispatch_info();
+        assert!(BasednodeSignedExtension::<Test>::new()
+            .validate(&personalkey, &call, &info, 0)
+            .is_err());
+        assert!(BasednodeSignedExtension::<Test>::new()
+            .pre_dispatch(&personalkey, &call, &info, 0)
+            .is_err());
+        assert_eq!(BasedNode::get_personalkey_balance(&personalkey), 1_000);
+    });
+}
+
 #[test]
 fn test_add_stake_ok_no_emission() {
     new_test_ext().execute_with(|| {
@@ -87,7 +173,7 @@ fn test_dividends_with_run_to_block() {
         let personalkey_account_id = U256::from(667);
         let netuid: u16 = 1;
 
-        let initial_stake: u64 = 5000;
+        let initial_stake: u128 = 5000;
 
         //add network
         add_network(netuid, 13, 0);
@@ -404,6 +490,155 @@ fn test_remove_stake_ok_no_emission() {
     });
 }
 
+#[test]
+fn test_remove_stake_unbonding_then_withdraw() {
+    new_test_ext().execute_with(|| {
+        let personalkey_account_id = U256::from(4343);
+        let computekey_account_id = U256::from(4968585);
+        let amount = 10000;
+        let netuid: u16 = 1;
+        let tempo: u16 = 13;
+        let unbonding_period: u64 = 5;
+
+        add_network(netuid, tempo, 0);
+        register_ok_agent(netuid, computekey_account_id, personalkey_account_id, 0);
+        BasedNode::set_unbonding_period(unbonding_period);
+        BasedNode::increase_stake_on_computekey_account(&computekey_account_id, amount);
+
+        let unlock_block: u64 = BasedNode::get_current_block_as_u64() + unbonding_period;
+        assert_ok!(BasedNode::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(personalkey_account_id),
+            computekey_account_id,
+            amount
+        ));
+
+        // The stake no longer counts for the computekey but is not paid out yet.
+        assert_eq!(
+            BasedNode::get_total_stake_for_computekey(&computekey_account_id),
+            0
+        );
+        assert_eq!(BasedNode::get_total_stake(), 0);
+        assert_eq!(BasedNode::get_personalkey_balance(&personalkey_account_id), 0);
+        assert_eq!(
+            BasedNode::get_unbonding_for_personalkey(&personalkey_account_id),
+            vec![(computekey_account_id, amount, unlock_block)]
+        );
+
+        // Nothing to withdraw before the unlock block.
+        assert_noop!(
+            BasedNode::withdraw_unbonded(<<Test as Config>::RuntimeOrigin>::signed(
+                personalkey_account_id
+            )),
+            Error::<Test>::NoUnbondedStake
+        );
+
+        run_to_block(unlock_block);
+        assert_ok!(BasedNode::withdraw_unbonded(
+            <<Test as Config>::RuntimeOrigin>::signed(personalkey_account_id)
+        ));
+        assert_eq!(
+            BasedNode::get_personalkey_balance(&personalkey_account_id),
+            amount
+        );
+        assert_eq!(
+            BasedNode::get_total_unbonding_for_personalkey(&personalkey_account_id),
+            0
+        );
+    });
+}
+
+#[test]
+fn test_remove_stake_unbonding_chunks_are_capped() {
+    new_test_ext().execute_with(|| {
+        let personalkey_account_id = U256::from(4343);
+        let computekey_account_id = U256::from(4968585);
+        let netuid: u16 = 1;
+        let tempo: u16 = 13;
+        let max_unbonding_chunks: u64 = 32;
+
+        add_network(netuid, tempo, 0);
+        register_ok_agent(netuid, computekey_account_id, personalkey_account_id, 0);
+        BasedNode::set_unbonding_period(100);
+        BasedNode::increase_stake_on_computekey_account(&computekey_account_id, 1_000);
+
+        // One chunk per block up to the cap.
+        for chunk in 0..max_unbonding_chunks {
+            if chunk > 0 {
+                step_block(1);
+            }
+            assert_ok!(BasedNode::remove_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(personalkey_account_id),
+                computekey_account_id,
+                1
+            ));
+        }
+
+        // Stake removed in the block of the last chunk still merges into it.
+        assert_ok!(BasedNode::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(personalkey_account_id),
+            computekey_account_id,
+            1
+        ));
+        assert_eq!(
+            BasedNode::get_unbonding_for_personalkey(&personalkey_account_id).len() as u64,
+            max_unbonding_chunks
+        );
+
+        // A new chunk is refused until some are withdrawn.
+        step_block(1);
+        assert_noop!(
+            BasedNode::remove_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(personalkey_account_id),
+                computekey_account_id,
+                1
+            ),
+            Error::<Test>::TooManyUnbondingChunks
+        );
+    });
+}
+
+#[test]
+fn test_withdraw_unbonded_keeps_locked_chunks() {
+    new_test_ext().execute_with(|| {
+        let personalkey_account_id = U256::from(4343);
+        let computekey_account_id = U256::from(4968585);
+        let netuid: u16 = 1;
+        let tempo: u16 = 13;
+
+        add_network(netuid, tempo, 0);
+        register_ok_agent(netuid, computekey_account_id, personalkey_account_id, 0);
+        BasedNode::set_unbonding_period(5);
+        BasedNode::increase_stake_on_computekey_account(&computekey_account_id, 300);
+
+        assert_ok!(BasedNode::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(personalkey_account_id),
+            computekey_account_id,
+            100
+        ));
+        run_to_block(BasedNode::get_current_block_as_u64() + 3);
+        assert_ok!(BasedNode::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(personalkey_account_id),
+            computekey_account_id,
+            200
+        ));
+        assert_eq!(
+            BasedNode::get_total_unbonding_for_personalkey(&personalkey_account_id),
+            300
+        );
+
+        // Only the first chunk has unlocked.
+        run_to_block(BasedNode::get_current_block_as_u64() + 2);
+        assert_ok!(BasedNode::withdraw_unbonded(
+            <<Test as Config>::RuntimeOrigin>::signed(personalkey_account_id)
+        ));
+        assert_eq!(BasedNode::get_personalkey_balance(&personalkey_account_id), 100);
+        assert_eq!(
+            BasedNode::get_total_unbonding_for_personalkey(&personalkey_account_id),
+            200
+        );
+    });
+}
+
 #[test]
 fn test_remove_stake_amount_zero() {
     new_test_ext().execute_with(|| {
@@ -626,6 +861,206 @@ fn test_remove_stake_total_issuance_no_change() {
     });
 }
 
+/***********************************************************
+    staking::move_stake() tests
+************************************************************/
+#[test]
+fn test_move_stake_ok() {
+    new_test_ext().execute_with(|| {
+        let personalkey = U256::from(1);
+        let origin_computekey = U256::from(2);
+        let delegate_personalkey = U256::from(3);
+        let destination_computekey = U256::from(4);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        register_ok_agent(netuid, origin_computekey, personalkey, 0);
+        register_ok_agent(netuid, destination_computekey, delegate_personalkey, 100);
+        assert_ok!(BasedNode::become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(delegate_personalkey),
+            destination_computekey
+        ));
+        BasedNode::increase_stake_on_computekey_account(&origin_computekey, 10_000);
+        let total_stake: u128 = BasedNode::get_total_stake();
+
+        assert_ok!(BasedNode::move_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(personalkey),
+            origin_computekey,
+            destination_computekey,
+            4_000
+        ));
+
+        assert_eq!(
+            BasedNode::get_stake_for_personalkey_and_computekey(&personalkey, &origin_computekey),
+            6_000
+        );
+        assert_eq!(
+            BasedNode::get_stake_for_personalkey_and_computekey(&personalkey, &destination_computekey),
+            4_000
+        );
+        assert_eq!(BasedNode::get_total_stake_for_computekey(&origin_computekey), 6_000);
+        assert_eq!(BasedNode::get_total_stake_for_computekey(&destination_computekey), 4_000);
+        assert_eq!(BasedNode::get_total_stake_for_personalkey(&personalkey), 10_000);
+        assert_eq!(BasedNode::get_total_stake(), total_stake);
+        assert_eq!(BasedNode::get_personalkey_balance(&personalkey), 0);
+    });
+}
+
+#[test]
+fn test_move_stake_err() {
+    new_test_ext().execute_with(|| {
+        let personalkey = U256::from(1);
+        let origin_computekey = U256::from(2);
+        let other_personalkey = U256::from(3);
+        let destination_computekey = U256::from(4);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        register_ok_agent(netuid, origin_computekey, personalkey, 0);
+        register_ok_agent(netuid, destination_computekey, other_personalkey, 100);
+        BasedNode::increase_stake_on_computekey_account(&origin_computekey, 10_000);
+
+        // The destination is not a delegate.
+        assert_noop!(
+            BasedNode::move_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(personalkey),
+                origin_computekey,
+                destination_computekey,
+                1_000
+            ),
+            Error::<Test>::NonAssociatedpersonalkey
+        );
+        assert_noop!(
+            BasedNode::move_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(personalkey),
+                origin_computekey,
+                origin_computekey,
+                1_000
+            ),
+            Error::<Test>::SameComputekey
+        );
+
+        assert_ok!(BasedNode::become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(other_personalkey),
+            destination_computekey
+        ));
+        assert_noop!(
+            BasedNode::move_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(personalkey),
+                origin_computekey,
+                destination_computekey,
+                10_001
+            ),
+            Error::<Test>::NotEnoughStaketoWithdraw
+        );
+        assert_noop!(
+            BasedNode::move_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(personalkey),
+                origin_computekey,
+                U256::from(5),
+                1_000
+            ),
+            Error::<Test>::NotRegistered
+        );
+    });
+}
+
+/***********************************************************
+    staking::set_reward_destination() tests
+************************************************************/
+
+#[test]
+fn test_set_reward_destination_ok() {
+    new_test_ext().execute_with(|| {
+        let personalkey = U256::from(1);
+        let computekey = U256::from(2);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        register_ok_agent(netuid, computekey, personalkey, 0);
+
+        // Rewards are compounded by default.
+        assert_eq!(
+            BasedNode::get_reward_destination(&personalkey, &computekey),
+            RewardDestination::Stake
+        );
+
+        assert_ok!(BasedNode::set_reward_destination(
+            <<Test as Config>::RuntimeOrigin>::signed(personalkey),
+            computekey,
+            RewardDestination::Balance
+        ));
+        assert_eq!(
+            BasedNode::get_reward_destination(&personalkey, &computekey),
+            RewardDestination::Balance
+        );
+
+        // Setting it back to stake clears the entry.
+        assert_ok!(BasedNode::set_reward_destination(
+            <<Test as Config>::RuntimeOrigin>::signed(personalkey),
+            computekey,
+            RewardDestination::Stake
+        ));
+        assert_eq!(
+            pallet_basednode::RewardDestinations::<Test>::get(computekey, personalkey),
+            None
+        );
+
+        // The computekey must exist.
+        assert_noop!(
+            BasedNode::set_reward_destination(
+                <<Test as Config>::RuntimeOrigin>::signed(personalkey),
+                U256::from(3),
+                RewardDestination::Balance
+            ),
+            Error::<Test>::NotRegistered
+        );
+    });
+}
+
+#[test]
+fn test_reward_destination_emission() {
+    new_test_ext().execute_with(|| {
+        let personalkey = U256::from(1);
+        let computekey = U256::from(2);
+        let payee = U256::from(3);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        register_ok_agent(netuid, computekey, personalkey, 0);
+
+        // Compounded onto the stake.
+        BasedNode::emit_inflation_through_computekey_account(&computekey, 0, 1_000);
+        assert_eq!(BasedNode::get_total_stake_for_computekey(&computekey), 1_000);
+        assert_eq!(BasedNode::get_personalkey_balance(&personalkey), 0);
+
+        // Paid to the personalkey free balance.
+        assert_ok!(BasedNode::set_reward_destination(
+            <<Test as Config>::RuntimeOrigin>::signed(personalkey),
+            computekey,
+            RewardDestination::Balance
+        ));
+        BasedNode::emit_inflation_through_computekey_account(&computekey, 200, 1_000);
+        assert_eq!(BasedNode::get_total_stake_for_computekey(&computekey), 1_000);
+        assert_eq!(BasedNode::get_personalkey_balance(&personalkey), 1_200);
+
+        // Paid to another account.
+        assert_ok!(BasedNode::set_reward_destination(
+            <<Test as Config>::RuntimeOrigin>::signed(personalkey),
+            computekey,
+            RewardDestination::Account(payee)
+        ));
+        BasedNode::emit_inflation_through_computekey_account(&computekey, 0, 500);
+        assert_eq!(BasedNode::get_total_stake_for_computekey(&computekey), 1_000);
+        assert_eq!(BasedNode::get_personalkey_balance(&personalkey), 1_200);
+        assert_eq!(BasedNode::get_personalkey_balance(&payee), 500);
+
+        // A reward the payee can not be credited is compounded onto the stake and counted once.
+        BasedNode::set_balance_on_personalkey_account(&payee, u128::MAX);
+        let total_issuance: u128 = BasedNode::get_total_issuance();
+        BasedNode::emit_inflation_through_computekey_account(&computekey, 0, 500);
+        assert_eq!(BasedNode::get_total_stake_for_computekey(&computekey), 1_500);
+        assert_eq!(BasedNode::get_personalkey_balance(&payee), u128::MAX);
+        assert_eq!(BasedNode::get_total_issuance(), total_issuance + 500);
+    });
+}
+
 /***********************************************************
     staking::get_personalkey_balance() tests
 ************************************************************/
@@ -664,7 +1099,7 @@ fn test_add_stake_to_computekey_account_ok() {
     new_test_ext().execute_with(|| {
         let computekey_id = U256::from(5445);
         let personalkey_id = U256::from(5443433);
-        let amount: u64 = 10000;
+        let amount: u128 = 10000;
         let netuid: u16 = 1;
         let tempo: u16 = 13;
         let start_nonce: u64 = 0;
@@ -698,7 +1133,7 @@ fn test_remove_stake_from_computekey_account() {
     new_test_ext().execute_with(|| {
         let computekey_id = U256::from(5445);
         let personalkey_id = U256::from(5443433);
-        let amount: u64 = 10000;
+        let amount: u128 = 10000;
         let netuid: u16 = 1;
         let tempo: u16 = 13;
         let start_nonce: u64 = 0;
@@ -734,7 +1169,7 @@ fn test_remove_stake_from_computekey_account_registered_in_various_networks() {
     new_test_ext().execute_with(|| {
         let computekey_id = U256::from(5445);
         let personalkey_id = U256::from(5443433);
-        let amount: u64 = 10000;
+        let amount: u128 = 10000;
         let netuid: u16 = 1;
         let netuid_ex = 2;
         let tempo: u16 = 13;
@@ -2066,6 +2501,136 @@ fn test_full_block_emission_occurs() {
     });
 }
 
+#[test]
+fn test_delegate_decrease_take_applies_immediately() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let computekey = U256::from(1);
+        let personalkey = U256::from(3);
+        add_network(netuid, 1, 0);
+        register_ok_agent(netuid, computekey, personalkey, 2341312);
+        assert_ok!(BasedNode::become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(personalkey),
+            computekey
+        ));
+        assert_eq!(
+            BasedNode::get_delegate_take(&computekey),
+            InitialDefaultTake::get()
+        );
+
+        // Only the owner can lower the take.
+        assert_noop!(
+            BasedNode::decrease_take(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(4)),
+                computekey,
+                10_000
+            ),
+            Error::<Test>::NonAssociatedpersonalkey
+        );
+        // The take must go down.
+        assert_noop!(
+            BasedNode::decrease_take(
+                <<Test as Config>::RuntimeOrigin>::signed(personalkey),
+                computekey,
+                InitialDefaultTake::get()
+            ),
+            Error::<Test>::DelegateTakeTooLow
+        );
+
+        assert_ok!(BasedNode::decrease_take(
+            <<Test as Config>::RuntimeOrigin>::signed(personalkey),
+            computekey,
+            10_000
+        ));
+        assert_eq!(BasedNode::get_delegate_take(&computekey), 10_000);
+        assert_eq!(BasedNode::get_pending_delegate_take(&computekey), None);
+    });
+}
+
+#[test]
+fn test_delegate_increase_take_waits_for_delay() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let computekey = U256::from(1);
+        let personalkey = U256::from(3);
+        add_network(netuid, 1, 0);
+        register_ok_agent(netuid, computekey, personalkey, 2341312);
+        assert_ok!(BasedNode::become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(personalkey),
+            computekey
+        ));
+        assert_ok!(BasedNode::decrease_take(
+            <<Test as Config>::RuntimeOrigin>::signed(personalkey),
+            computekey,
+            10_000
+        ));
+
+        // Can not raise above the default take.
+        assert_noop!(
+            BasedNode::increase_take(
+                <<Test as Config>::RuntimeOrigin>::signed(personalkey),
+                computekey,
+                InitialDefaultTake::get() + 1
+            ),
+            Error::<Test>::DelegateTakeTooHigh
+        );
+
+        let delay: u64 = BasedNode::get_delegate_take_increase_delay();
+        let apply_at_block: u64 = System::block_number() + delay;
+        assert_ok!(BasedNode::increase_take(
+            <<Test as Config>::RuntimeOrigin>::signed(personalkey),
+            computekey,
+            20_000
+        ));
+        assert_eq!(
+            BasedNode::get_pending_delegate_take(&computekey),
+            Some((20_000, apply_at_block))
+        );
+
+        // The current take is used until the delay passes.
+        run_to_block(apply_at_block - 1);
+        assert_eq!(BasedNode::get_delegate_take(&computekey), 10_000);
+
+        run_to_block(apply_at_block);
+        assert_eq!(BasedNode::get_delegate_take(&computekey), 20_000);
+        assert_eq!(BasedNode::get_pending_delegate_take(&computekey), None);
+    });
+}
+
+#[test]
+fn test_delegate_decrease_take_cancels_pending_increase() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let computekey = U256::from(1);
+        let personalkey = U256::from(3);
+        add_network(netuid, 1, 0);
+        register_ok_agent(netuid, computekey, personalkey, 2341312);
+        assert_ok!(BasedNode::become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(personalkey),
+            computekey
+        ));
+        assert_ok!(BasedNode::decrease_take(
+            <<Test as Config>::RuntimeOrigin>::signed(personalkey),
+            computekey,
+            10_000
+        ));
+        assert_ok!(BasedNode::increase_take(
+            <<Test as Config>::RuntimeOrigin>::signed(personalkey),
+            computekey,
+            20_000
+        ));
+        assert_ok!(BasedNode::decrease_take(
+            <<Test as Config>::RuntimeOrigin>::signed(personalkey),
+            computekey,
+            5_000
+        ));
+        assert_eq!(BasedNode::get_pending_delegate_take(&computekey), None);
+
+        run_to_block(System::block_number() + BasedNode::get_delegate_take_increase_delay());
+        assert_eq!(BasedNode::get_delegate_take(&computekey), 5_000);
+    });
+}
+
 /************************************************************
     staking::unstake_all_personalkeys_from_computekey_account() tests
 ************************************************************/
@@ -2080,7 +2645,7 @@ fn test_unstake_all_personalkeys_from_computekey_account() {
         let personalkey2_id = U256::from(123562);
         let personalkey3_id = U256::from(123563);
 
-        let amount: u64 = 10000;
+        let amount: u128 = 10000;
 
         let netuid: u16 = 1;
         let tempo: u16 = 13;
@@ -2164,7 +2729,7 @@ fn test_unstake_all_personalkeys_from_computekey_account_single_staker() {
         let computekey_id = U256::from(123570);
         let personalkey0_id = U256::from(123560);
 
-        let amount: u64 = 891011;
+        let amount: u128 = 891011;
 
         let netuid: u16 = 1;
         let tempo: u16 = 13;
@@ -2209,6 +2774,44 @@ fn test_unstake_all_personalkeys_from_computekey_account_single_staker() {
     });
 }
 
+#[test]
+fn test_unstake_all_personalkeys_from_computekey_account_unbonds() {
+    new_test_ext().execute_with(|| {
+        let computekey_id = U256::from(123570);
+        let personalkey0_id = U256::from(123560);
+        let personalkey1_id = U256::from(123561);
+        let other_computekey_id = U256::from(123571);
+        let amount: u128 = 10000;
+
+        add_network(1, 13, 0);
+        register_ok_agent(1, computekey_id, personalkey0_id, 0);
+        BasedNode::set_unbonding_period(100);
+        BasedNode::increase_stake_on_personalkey_computekey_account(&personalkey0_id, &computekey_id, amount);
+        BasedNode::increase_stake_on_personalkey_computekey_account(&personalkey1_id, &computekey_id, amount);
+        // personalkey1 already has every unbonding chunk it can queue.
+        for block in 1..=32 {
+            BasedNode::add_unbonding_chunk(&personalkey1_id, &other_computekey_id, 1, block);
+        }
+
+        // Deregistered stake unbonds instead of being paid out.
+        BasedNode::unstake_all_personalkeys_from_computekey_account(&computekey_id);
+        let unlock_block: u64 = BasedNode::get_current_block_as_u64() + 100;
+        assert_eq!(BasedNode::get_total_stake_for_computekey(&computekey_id), 0);
+        assert_eq!(Balances::free_balance(personalkey0_id), 0);
+        assert_eq!(Balances::free_balance(personalkey1_id), 0);
+        assert_eq!(
+            BasedNode::get_unbonding_for_personalkey(&personalkey0_id),
+            vec![(computekey_id, amount, unlock_block)]
+        );
+
+        // Without room for another chunk it merges into the last unlocking one, which unlocks no earlier.
+        let chunks = BasedNode::get_unbonding_for_personalkey(&personalkey1_id);
+        assert_eq!(chunks.len(), 32);
+        assert_eq!(chunks[31], (other_computekey_id, amount + 1, unlock_block));
+        assert_eq!(BasedNode::get_total_unbonding_for_personalkey(&personalkey1_id), amount + 32);
+    });
+}
+
 #[test]
 fn test_faucet_ok() {
     new_test_ext().execute_with(|| {
@@ -2247,3 +2850,49 @@ fn test_faucet_ok() {
         );
     });
 }
+
+#[test]
+fn test_delegate_take_increase_reannounced_applies_at_latest_block() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let computekey = U256::from(1);
+        let personalkey = U256::from(3);
+        add_network(netuid, 1, 0);
+        register_ok_agent(netuid, computekey, personalkey, 2341312);
+        assert_ok!(BasedNode::become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(personalkey),
+            computekey
+        ));
+        assert_ok!(BasedNode::decrease_take(
+            <<Test as Config>::RuntimeOrigin>::signed(personalkey),
+            computekey,
+            10_000
+        ));
+
+        let delay: u64 = BasedNode::get_delegate_take_increase_delay();
+        let first_apply_at_block: u64 = System::block_number() + delay;
+        assert_ok!(BasedNode::increase_take(
+            <<Test as Config>::RuntimeOrigin>::signed(personalkey),
+            computekey,
+            20_000
+        ));
+
+        // Announcing again replaces the first increase.
+        run_to_block(System::block_number() + 1);
+        let second_apply_at_block: u64 = System::block_number() + delay;
+        assert_ok!(BasedNode::increase_take(
+            <<Test as Config>::RuntimeOrigin>::signed(personalkey),
+            computekey,
+            15_000
+        ));
+
+        run_to_block(first_apply_at_block);
+        assert_eq!(BasedNode::get_delegate_take(&computekey), 10_000);
+        assert!(pallet_basednode::PendingDelegateTakeQueue::<Test>::get(first_apply_at_block).is_empty());
+
+        run_to_block(second_apply_at_block);
+        assert_eq!(BasedNode::get_delegate_take(&computekey), 15_000);
+        assert_eq!(BasedNode::get_pending_delegate_take(&computekey), None);
+        assert!(pallet_basednode::PendingDelegateTakeQueue::<Test>::get(second_apply_at_block).is_empty());
+    });
+}
@@ -896,3 +896,164 @@ fn test_check_len_uids_within_allowed_not_within_network_pool() {
         );
     });
 }
+
+// Test that commit-reveal brains only take weights revealed in the epoch after the commit.
+#[test]
+fn test_commit_reveal_weights() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 5;
+        let computekey = U256::from(55);
+        let personalkey = U256::from(66);
+        add_network(netuid, tempo, 0);
+        register_ok_agent(netuid, computekey, personalkey, 2143124);
+        BasedNode::set_weights_set_rate_limit(netuid, 0);
+        BasedNode::set_commit_reveal_weights_enabled(netuid, true);
+
+        let uids: Vec<u16> = vec![0];
+        let values: Vec<u16> = vec![1];
+        let salt: Vec<u16> = vec![7, 8, 9];
+        let commit_hash = BasedNode::get_weights_commit_hash(&computekey, netuid, &uids, &values, &salt, 0);
+
+        // Plain text weights are refused.
+        assert_eq!(
+            BasedNode::set_weights(RuntimeOrigin::signed(computekey), netuid, uids.clone(), values.clone(), 0),
+            Err(Error::<Test>::CommitRevealEnabled.into())
+        );
+        assert_eq!(
+            BasedNode::reveal_weights(RuntimeOrigin::signed(computekey), netuid, uids.clone(), values.clone(), salt.clone(), 0),
+            Err(Error::<Test>::NoWeightsCommit.into())
+        );
+
+        // The reveal must match the commit and come after the epoch of the commit.
+        let commit_block: u64 = System::block_number();
+        let epoch_block: u64 = commit_block + BasedNode::blocks_until_next_epoch(netuid, tempo, commit_block + 1) + 1;
+        assert_ok!(BasedNode::commit_weights(RuntimeOrigin::signed(computekey), netuid, commit_hash));
+        System::set_block_number(epoch_block - 1);
+        assert_eq!(
+            BasedNode::reveal_weights(RuntimeOrigin::signed(computekey), netuid, uids.clone(), values.clone(), salt.clone(), 0),
+            Err(Error::<Test>::WeightsRevealTooEarly.into())
+        );
+        System::set_block_number(epoch_block);
+        assert_eq!(
+            BasedNode::reveal_weights(RuntimeOrigin::signed(computekey), netuid, uids.clone(), values.clone(), vec![1], 0),
+            Err(Error::<Test>::InvalidWeightsReveal.into())
+        );
+
+        // A commit is bound to the computekey and the brain it was made for.
+        let other_hash = BasedNode::get_weights_commit_hash(&U256::from(77), netuid, &uids, &values, &salt, 0);
+        assert_ne!(other_hash, commit_hash);
+        assert_ne!(BasedNode::get_weights_commit_hash(&computekey, 2, &uids, &values, &salt, 0), commit_hash);
+
+        assert_ok!(BasedNode::reveal_weights(
+            RuntimeOrigin::signed(computekey),
+            netuid,
+            uids.clone(),
+            values.clone(),
+            salt.clone(),
+            0
+        ));
+        assert_eq!(pallet_basednode::Weights::<Test>::get(netuid, 0), vec![(0, u16::MAX)]);
+        assert!(pallet_basednode::WeightCommits::<Test>::get(netuid, computekey).is_none());
+
+        // Commits not revealed in the epoch after the commit expire.
+        let commit_block: u64 = System::block_number();
+        assert_ok!(BasedNode::commit_weights(RuntimeOrigin::signed(computekey), netuid, commit_hash));
+        System::set_block_number(commit_block + 2 * (tempo as u64 + 1));
+        assert_eq!(
+            BasedNode::reveal_weights(RuntimeOrigin::signed(computekey), netuid, uids.clone(), values.clone(), salt.clone(), 0),
+            Err(Error::<Test>::WeightsCommitExpired.into())
+        );
+        BasedNode::remove_expired_weight_commits(netuid, System::block_number());
+        assert!(pallet_basednode::WeightCommits::<Test>::get(netuid, computekey).is_none());
+
+        // Turning commit-reveal off drops the pending commits.
+        assert_ok!(BasedNode::commit_weights(RuntimeOrigin::signed(computekey), netuid, commit_hash));
+        BasedNode::set_commit_reveal_weights_enabled(netuid, false);
+        assert!(pallet_basednode::WeightCommits::<Test>::get(netuid, computekey).is_none());
+    });
+}
+
+// Test that commit_weights is held to the weights set rate limit.
+#[test]
+fn test_commit_weights_rate_limit() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let computekey = U256::from(55);
+        let personalkey = U256::from(66);
+        add_network(netuid, 5, 0);
+        register_ok_agent(netuid, computekey, personalkey, 2143124);
+        BasedNode::set_weights_set_rate_limit(netuid, 10);
+        BasedNode::set_commit_reveal_weights_enabled(netuid, true);
+        let commit_hash = BasedNode::get_weights_commit_hash(&computekey, netuid, &vec![0], &vec![1], &vec![7], 0);
+
+        let commit_block: u64 = System::block_number();
+        assert_ok!(BasedNode::commit_weights(RuntimeOrigin::signed(computekey), netuid, commit_hash));
+        System::set_block_number(commit_block + 9);
+        assert_eq!(
+            BasedNode::commit_weights(RuntimeOrigin::signed(computekey), netuid, commit_hash),
+            Err(Error::<Test>::SettingWeightsTooFast.into())
+        );
+        System::set_block_number(commit_block + 10);
+        assert_ok!(BasedNode::commit_weights(RuntimeOrigin::signed(computekey), netuid, commit_hash));
+    });
+}
+
+// Test that batch_set_weights sets every valid entry and reports the rejected ones without failing.
+#[test]
+fn test_batch_set_weights() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let computekey = U256::from(55);
+        let personalkey = U256::from(66);
+        for netuid in 1..=3 {
+            add_network(netuid, 13, 0);
+            register_ok_agent(netuid, computekey, personalkey, 2143124 * netuid as u64);
+            BasedNode::set_weights_set_rate_limit(netuid, 0);
+        }
+        BasedNode::set_commit_reveal_weights_enabled(3, true);
+        add_network(4, 13, 0);
+
+        assert_ok!(BasedNode::batch_set_weights(
+            RuntimeOrigin::signed(computekey),
+            vec![
+                (1, vec![0], vec![1], 0),
+                (2, vec![0], vec![1], 0),
+                (3, vec![0], vec![1], 0), // Commit-reveal brain.
+                (9, vec![0], vec![1], 0), // Unknown brain.
+            ]
+        ));
+
+        assert_eq!(pallet_basednode::Weights::<Test>::get(1, 0), vec![(0, u16::MAX)]);
+        assert_eq!(pallet_basednode::Weights::<Test>::get(2, 0), vec![(0, u16::MAX)]);
+        assert!(pallet_basednode::Weights::<Test>::get(3, 0).is_empty());
+        System::assert_has_event(RuntimeEvent::BasedNode(pallet_basednode::Event::WeightsSet(2, 0)));
+        System::assert_has_event(RuntimeEvent::BasedNode(pallet_basednode::Event::BatchWeightsEntryFailed(
+            2,
+            3,
+            Error::<Test>::CommitRevealEnabled.into(),
+        )));
+        System::assert_has_event(RuntimeEvent::BasedNode(pallet_basednode::Event::BatchWeightsEntryFailed(
+            3,
+            9,
+            Error::<Test>::NetworkDoesNotExist.into(),
+        )));
+        System::assert_last_event(RuntimeEvent::BasedNode(pallet_basednode::Event::BatchWeightsCompleted(2, 2)));
+
+        // A batch has at most one entry per brain.
+        assert_eq!(
+            BasedNode::batch_set_weights(
+                RuntimeOrigin::signed(computekey),
+                vec![(1, vec![0], vec![1], 0); BasedNode::get_num_brains() as usize + 1]
+            ),
+            Err(Error::<Test>::TooManyBatchEntries.into())
+        );
+        assert_eq!(
+            BasedNode::batch_set_weights(
+                RuntimeOrigin::signed(computekey),
+                vec![(1, vec![0], vec![1], 0), (2, vec![0], vec![1], 0), (1, vec![0], vec![1], 0)]
+            ),
+            Err(Error::<Test>::DuplicateBatchEntry.into())
+        );
+    });
+}
@@ -74,7 +74,7 @@ use fp_rpc::TransactionStatus;
 use pallet_ethereum::{Call::transact, PostLogContent, Transaction as EthereumTransaction};
 use pallet_evm::{
     Account as EVMAccount, EVMCurrencyAdapter, EnsureAddressNever, EnsureAddressRoot,
-    FeeCalculator, IdentityAddressMapping, Runner,
+    FeeCalculator, GasWeightMapping, IdentityAddressMapping, Runner,
 };
 
 mod precompiles;
@@ -141,7 +141,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 141,
+    spec_version: 142,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
     transaction_version: 1,
@@ -324,6 +324,279 @@ impl<F: FindAuthor<u32>> FindAuthor<H160> for FindAuthorTruncated<F> {
     }
 }
 
+pub struct FindAuthorAccountId<F>(PhantomData<F>);
+impl<F: FindAuthor<u32>> FindAuthor<AccountId> for FindAuthorAccountId<F> {
+    fn find_author<'a, I>(digests: I) -> Option<AccountId>
+    where
+        I: 'a + IntoIterator<Item = (ConsensusEngineId, &'a [u8])>,
+    {
+        if let Some(author_index) = F::find_author(digests) {
+            let authority_id = Aura::authorities().get(author_index as usize)?.clone();
+            return AccountId::try_from(&authority_id.to_raw_vec()[..]).ok();
+        }
+        None
+    }
+}
+
+// keccak256("Transfer(address,address,uint256)"), the first topic of ERC20 Transfer logs.
+const ERC20_TRANSFER_TOPIC: [u8; 32] = [
+    0xdd, 0xf2, 0x52, 0xad, 0x1b, 0xe2, 0xc8, 0x9b, 0x69, 0xc2, 0xb0, 0x68, 0xfc, 0x37, 0x8d, 0xaa,
+    0x95, 0x2b, 0xa7, 0xf1, 0x63, 0xc4, 0xa1, 0x16, 0x28, 0xf5, 0x5a, 0x4d, 0xf5, 0x23, 0xb3, 0xef,
+];
+
+// Reads the ERC20 Transfer logs of bound brain contracts that BrainTokenRunner recorded in the previous block.
+pub struct EvmTokenTransferLogs;
+impl pallet_basednode::TokenTransferLogs<AccountId> for EvmTokenTransferLogs {
+    fn transfers() -> Vec<(AccountId, AccountId, AccountId, U256)> {
+        BasedNode::take_token_transfers()
+    }
+}
+
+type StackRunner = pallet_evm::runner::stack::Runner<Runtime>;
+
+// Runs every EVM execution on the stack runner and records the ERC20 Transfer logs of the successful
+// ones with basednode. Ethereum transactions, pallet_evm calls and executions started by the runtime
+// all go through the runner, and precompiles run inside those executions.
+pub struct BrainTokenRunner;
+impl BrainTokenRunner {
+    // Charges the weight of looking up the contract of every Transfer log, and of recording and
+    // applying the logs of bound brain contracts, as gas on top of what the execution used. An
+    // execution whose gas limit does not cover it fails and records nothing.
+    fn record_transfer_logs(
+        logs: &[fp_evm::Log],
+        used_gas: U256,
+        gas_limit: u64,
+    ) -> Result<U256, pallet_evm::runner::RunnerError<pallet_evm::Error<Runtime>>> {
+        let transfers: Vec<&fp_evm::Log> = logs
+            .iter()
+            .filter(|log| {
+                log.topics.len() == 3 && log.topics[0] == H256(ERC20_TRANSFER_TOPIC) && log.data.len() == 32
+            })
+            .collect();
+        if transfers.is_empty() {
+            return Ok(used_gas);
+        }
+        let bound = transfers
+            .iter()
+            .filter(|log| BasedNode::get_contract_brain(&AccountId::from(log.address)).is_some())
+            .count() as u64;
+        let weight = <Runtime as frame_system::Config>::DbWeight::get()
+            .reads(transfers.len() as u64)
+            .saturating_add(BasedNode::token_transfer_weight().saturating_mul(bound));
+        let used_gas = used_gas.saturating_add(U256::from(
+            <Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(weight),
+        ));
+        if used_gas > U256::from(gas_limit) {
+            return Err(pallet_evm::runner::RunnerError {
+                error: pallet_evm::Error::<Runtime>::GasLimitTooLow,
+                weight: <Runtime as pallet_evm::Config>::GasWeightMapping::gas_to_weight(gas_limit, true),
+            });
+        }
+
+        for log in transfers {
+            BasedNode::note_token_transfer(
+                &AccountId::from(log.address),
+                &AccountId::from(H160::from(log.topics[1])),
+                &AccountId::from(H160::from(log.topics[2])),
+                U256::from_big_endian(&log.data),
+            );
+        }
+        Ok(used_gas)
+    }
+}
+impl pallet_evm::Runner<Runtime> for BrainTokenRunner {
+    type Error = <StackRunner as pallet_evm::Runner<Runtime>>::Error;
+
+    fn validate(
+        source: H160,
+        target: Option<H160>,
+        input: Vec<u8>,
+        value: U256,
+        gas_limit: u64,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        access_list: Vec<(H160, Vec<H256>)>,
+        is_transactional: bool,
+        evm_config: &fp_evm::Config,
+    ) -> Result<(), pallet_evm::runner::RunnerError<Self::Error>> {
+        StackRunner::validate(
+            source,
+            target,
+            input,
+            value,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce,
+            access_list,
+            is_transactional,
+            evm_config,
+        )
+    }
+
+    fn call(
+        source: H160,
+        target: H160,
+        input: Vec<u8>,
+        value: U256,
+        gas_limit: u64,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        access_list: Vec<(H160, Vec<H256>)>,
+        is_transactional: bool,
+        validate: bool,
+        config: &fp_evm::Config,
+    ) -> Result<pallet_evm::CallInfo, pallet_evm::runner::RunnerError<Self::Error>> {
+        let mut info = StackRunner::call(
+            source,
+            target,
+            input,
+            value,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce,
+            access_list,
+            is_transactional,
+            validate,
+            config,
+        )?;
+        if info.exit_reason.is_succeed() {
+            info.used_gas = Self::record_transfer_logs(&info.logs, info.used_gas, gas_limit)?;
+        }
+        Ok(info)
+    }
+
+    fn create(
+        source: H160,
+        init: Vec<u8>,
+        value: U256,
+        gas_limit: u64,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        access_list: Vec<(H160, Vec<H256>)>,
+        is_transactional: bool,
+        validate: bool,
+        config: &fp_evm::Config,
+    ) -> Result<pallet_evm::CreateInfo, pallet_evm::runner::RunnerError<Self::Error>> {
+        let mut info = StackRunner::create(
+            source,
+            init,
+            value,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce,
+            access_list,
+            is_transactional,
+            validate,
+            config,
+        )?;
+        if info.exit_reason.is_succeed() {
+            info.used_gas = Self::record_transfer_logs(&info.logs, info.used_gas, gas_limit)?;
+        }
+        Ok(info)
+    }
+
+    fn create2(
+        source: H160,
+        init: Vec<u8>,
+        salt: H256,
+        value: U256,
+        gas_limit: u64,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        access_list: Vec<(H160, Vec<H256>)>,
+        is_transactional: bool,
+        validate: bool,
+        config: &fp_evm::Config,
+    ) -> Result<pallet_evm::CreateInfo, pallet_evm::runner::RunnerError<Self::Error>> {
+        let mut info = StackRunner::create2(
+            source,
+            init,
+            salt,
+            value,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce,
+            access_list,
+            is_transactional,
+            validate,
+            config,
+        )?;
+        if info.exit_reason.is_succeed() {
+            info.used_gas = Self::record_transfer_logs(&info.logs, info.used_gas, gas_limit)?;
+        }
+        Ok(info)
+    }
+}
+
+// Records the deployer of every contract created through the EVM, brain owners can only bind the
+// ERC20 contracts they deployed themselves.
+pub struct RecordContractDeployer;
+impl pallet_evm::OnCreate<Runtime> for RecordContractDeployer {
+    fn on_create(owner: H160, contract: H160) {
+        BasedNode::note_contract_created(&AccountId::from(owner), &AccountId::from(contract));
+    }
+}
+
+// Selectors of the functions of the ERC20 interface, a contract implements it when its dispatcher
+// pushes all of them.
+const ERC20_SELECTORS: [[u8; 4]; 6] = [
+    [0x18, 0x16, 0x0d, 0xdd], // totalSupply()
+    [0x70, 0xa0, 0x82, 0x31], // balanceOf(address)
+    [0xa9, 0x05, 0x9c, 0xbb], // transfer(address,uint256)
+    [0x23, 0xb8, 0x72, 0xdd], // transferFrom(address,address,uint256)
+    [0x09, 0x5e, 0xa7, 0xb3], // approve(address,uint256)
+    [0xdd, 0x62, 0xed, 0x3e], // allowance(address,address)
+];
+// PUSH4, the opcode solc dispatchers compare the calldata selector with.
+const EVM_PUSH4: u8 = 0x63;
+// Storage slots of the OpenZeppelin ERC20 layout, _balances is slot 0 and _totalSupply slot 2.
+const ERC20_BALANCES_SLOT: u64 = 0;
+const ERC20_TOTAL_SUPPLY_SLOT: u64 = 2;
+
+// Inspects the ERC20 contracts brain owners bind, reading their code and the OpenZeppelin storage
+// layout from pallet_evm.
+pub struct EvmBrainTokenInspector;
+impl EvmBrainTokenInspector {
+    fn storage_u128(contract: &AccountId, key: H256) -> Option<u128> {
+        let value = pallet_evm::AccountStorages::<Runtime>::get(H160::from(*contract), key);
+        let value = U256::from_big_endian(value.as_bytes());
+        if value > U256::from(u128::MAX) {
+            return None;
+        }
+        Some(value.low_u128())
+    }
+}
+impl pallet_basednode::BrainTokenInspector<AccountId> for EvmBrainTokenInspector {
+    fn is_erc20(contract: &AccountId) -> bool {
+        let code = pallet_evm::AccountCodes::<Runtime>::get(H160::from(*contract));
+        ERC20_SELECTORS.iter().all(|selector| {
+            code.windows(5)
+                .any(|window| window[0] == EVM_PUSH4 && window[1..] == selector[..])
+        })
+    }
+
+    fn total_supply(contract: &AccountId) -> Option<u128> {
+        Self::storage_u128(contract, H256::from_low_u64_be(ERC20_TOTAL_SUPPLY_SLOT))
+    }
+
+    fn balance_of(contract: &AccountId, holder: &AccountId) -> Option<u128> {
+        // Mapping entries are stored at keccak256(pad32(key) ++ pad32(slot)).
+        let mut preimage = [0u8; 64];
+        preimage[12..32].copy_from_slice(H160::from(*holder).as_bytes());
+        preimage[32..64].copy_from_slice(H256::from_low_u64_be(ERC20_BALANCES_SLOT).as_bytes());
+        let key = H256(frame_support::sp_io::hashing::keccak_256(&preimage));
+        Self::storage_u128(contract, key)
+    }
+}
+
 // pub struct ToAuthor<R>(sp_std::marker::PhantomData<R>);
 // impl<R> OnUnbalanced<NegativeImbalance<R>> for ToAuthor<R>
 // where
@@ -413,7 +686,7 @@ impl pallet_evm::Config for Runtime {
     type BlockGasLimit = BlockGasLimit;
 
     #[doc = r" EVM execution runner."]
-    type Runner = pallet_evm::runner::stack::Runner<Self>;
+    type Runner = BrainTokenRunner;
 
     #[doc = r" To handle fee deduction for EVM transactions. An example is this pallet being used by `pallet_ethereum`"]
     #[doc = r" where the chain implementing `pallet_ethereum` should be able to configure what happens to the fees"]
@@ -422,7 +695,7 @@ impl pallet_evm::Config for Runtime {
     // type OnChargeTransaction = ();
 
     #[doc = r" Called on create calls, used to record owner"]
-    type OnCreate = ();
+    type OnCreate = RecordContractDeployer;
 
     #[doc = r" Find author for the current block."]
     type FindAuthor = FindAuthorTruncated<Aura>;
@@ -853,15 +1126,30 @@ parameter_types! {
     pub const BasednodeInitialNetworkLockReductionInterval: u64 = 14 * 7200;
     pub const BasednodeInitialNetworkRateLimit: u64 = 1 * 7200;
 	pub const BasednodeInitialBrainOwnerByTokenCut: u128 = 26_214;
+    pub const BasednodeInitialDelegateTakeIncreaseDelay: u64 = 7 * 7200; // 7 days
+    pub const BasednodeInitialUnbondingPeriod: u64 = 7200; // 1 day
+    pub const BasednodeInitialEmissionSplit: u16 = 80; // 80% by root weights, 20% split equally between brains.
+    pub const BasednodeInitialHalvingInterval: u64 = (365 * 24 * 60 * 60) / 10; // A year of 10s blocks.
+    pub const BasednodeInitialTokenHolderThreshold: u16 = 1_311; // 2% of the token supply.
+    pub const BasednodeInitialBrainOwnershipProposalExpiry: u64 = 3 * 7200; // 3 days
+    pub const BasednodeInitialHyperparamChangeDelay: u64 = 7200; // 1 day
+    pub const BasednodeInitialBrainMetadataDeposit: u128 = 1_000_000_000_000_000_000; // 1 BASED
+    pub const BasednodeTransactionFeeDestination: pallet_basednode::TransactionFeeDestination<AccountId> =
+        pallet_basednode::TransactionFeeDestination::Author; // Fees charged on basednode calls go to the block author.
 }
 
 impl pallet_basednode::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = pallet_basednode::default_weights::SubstrateWeight<Runtime>;
     type SudoRuntimeCall = RuntimeCall;
     type Currency = Balances;
     type CouncilOrigin = EnsureMajoritySenate;
     type SenateMembers = ManageSenateMembers;
     type TriumvirateInterface = TriumvirateVotes;
+    type FindAuthor = FindAuthorAccountId<Aura>;
+    type TokenTransferLogs = EvmTokenTransferLogs;
+    type BrainTokenInspector = EvmBrainTokenInspector;
+    type TransactionFeeDestination = BasednodeTransactionFeeDestination;
 
     type InitialRho = BasednodeInitialRho;
     type InitialKappa = BasednodeInitialKappa;
@@ -902,6 +1190,14 @@ impl pallet_basednode::Config for Runtime {
     type InitialBrainLimit = BasednodeInitialBrainLimit;
     type InitialNetworkRateLimit = BasednodeInitialNetworkRateLimit;
     type InitialBrainOwnerByTokenBalanceCut = BasednodeInitialBrainOwnerByTokenCut;
+    type InitialDelegateTakeIncreaseDelay = BasednodeInitialDelegateTakeIncreaseDelay;
+    type InitialUnbondingPeriod = BasednodeInitialUnbondingPeriod;
+    type InitialEmissionSplit = BasednodeInitialEmissionSplit;
+    type InitialHalvingInterval = BasednodeInitialHalvingInterval;
+    type InitialTokenHolderThreshold = BasednodeInitialTokenHolderThreshold;
+    type InitialBrainOwnershipProposalExpiry = BasednodeInitialBrainOwnershipProposalExpiry;
+    type InitialHyperparamChangeDelay = BasednodeInitialHyperparamChangeDelay;
+    type InitialBrainMetadataDeposit = BasednodeInitialBrainMetadataDeposit;
 }
 
 use sp_runtime::BoundedVec;
@@ -930,6 +1226,70 @@ impl
         BasedNode::set_tx_rate_limit(rate_limit);
     }
 
+    fn set_delegate_take_increase_delay(delay: u64) {
+        BasedNode::set_delegate_take_increase_delay(delay);
+    }
+
+    fn set_unbonding_period(unbonding_period: u64) {
+        BasedNode::set_unbonding_period(unbonding_period);
+    }
+
+    fn set_transaction_fee(call_type: pallet_basednode::CallType, fee: u128) {
+        BasedNode::set_transaction_fee(call_type, fee);
+    }
+
+    fn set_emission_split(emission_split: u16) {
+        BasedNode::set_emission_split(emission_split);
+    }
+
+    fn set_halving_interval(halving_interval: u64) {
+        BasedNode::set_halving_interval(halving_interval);
+    }
+
+    fn set_owner_cut(netuid: u16, owner_cut: u16) {
+        BasedNode::set_owner_cut(netuid, owner_cut);
+    }
+
+    fn get_max_owner_cut() -> u16 {
+        BasedNode::get_max_owner_cut()
+    }
+
+    fn set_max_owner_cut(max_owner_cut: u16) {
+        BasedNode::set_max_owner_cut(max_owner_cut);
+    }
+
+    fn set_token_holder_threshold(netuid: u16, token_holder_threshold: u16) {
+        BasedNode::set_token_holder_threshold(netuid, token_holder_threshold);
+    }
+
+    fn get_max_token_holder_threshold() -> u16 {
+        BasedNode::get_max_token_holder_threshold()
+    }
+
+    fn set_max_token_holder_threshold(max_token_holder_threshold: u16) {
+        BasedNode::set_max_token_holder_threshold(max_token_holder_threshold);
+    }
+
+    fn set_brain_ownership_proposal_expiry(expiry: u64) {
+        BasedNode::set_brain_ownership_proposal_expiry(expiry);
+    }
+
+    fn set_hyperparam_change_delay(delay: u64) {
+        BasedNode::set_hyperparam_change_delay(delay);
+    }
+
+    fn schedule_hyperparam_change(netuid: u16, change: pallet_basednode::HyperparamChange) {
+        BasedNode::schedule_hyperparam_change(netuid, change);
+    }
+
+    fn cancel_hyperparam_change(netuid: u16, change: pallet_basednode::HyperparamChange) {
+        BasedNode::cancel_hyperparam_change(netuid, &change);
+    }
+
+    fn set_brain_metadata_deposit(deposit: u128) {
+        BasedNode::set_brain_metadata_deposit(deposit);
+    }
+
     fn set_serving_rate_limit(netuid: u16, rate_limit: u64) {
         BasedNode::set_serving_rate_limit(netuid, rate_limit);
     }
@@ -993,7 +1353,7 @@ impl
     fn increase_stake_on_personalkey_computekey_account(
         personalkey: &AccountId,
         computekey: &AccountId,
-        increment: u64,
+        increment: u128,
     ) {
         BasedNode::increase_stake_on_personalkey_computekey_account(
             personalkey,
@@ -1099,6 +1459,14 @@ impl
         return BasedNode::ensure_brain_owner_or_root(o, netuid);
     }
 
+    fn ensure_brain_role_or_root(
+        o: RuntimeOrigin,
+        netuid: u16,
+        role: pallet_basednode::BrainRole,
+    ) -> Result<(), DispatchError> {
+        return BasedNode::ensure_brain_role_or_root(o, netuid, role);
+    }
+
     fn set_rho(netuid: u16, rho: u16) {
         BasedNode::set_rho(netuid, rho);
     }
@@ -1139,6 +1507,14 @@ impl
         BasedNode::set_weights_set_rate_limit(netuid, weights_set_rate_limit);
     }
 
+    fn set_commit_reveal_weights_enabled(netuid: u16, enabled: bool) {
+        BasedNode::set_commit_reveal_weights_enabled(netuid, enabled);
+    }
+
+    fn set_consensus_mechanism(netuid: u16, mechanism: pallet_basednode::ConsensusMechanismType) {
+        BasedNode::set_consensus_mechanism(netuid, mechanism);
+    }
+
     fn set_rao_recycled(netuid: u16, rao_recycled: u128) {
         BasedNode::set_rao_recycled(netuid, rao_recycled);
     }
@@ -1635,6 +2011,16 @@ impl_runtime_apis! {
                 vec![]
             }
         }
+
+        fn get_brain_metadata(netuid: u16) -> Vec<u8> {
+            let _result = BasedNode::get_brain_metadata(netuid);
+            if _result.is_some() {
+                let result = _result.expect("Could not get BrainMetadataInfo");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
     }
 
     impl basednode_custom_rpc_runtime_api::StakeInfoRuntimeApi<Block> for Runtime {
@@ -1662,6 +2048,38 @@ impl_runtime_apis! {
         }
     }
 
+    impl basednode_custom_rpc_runtime_api::EmissionLedgerRuntimeApi<Block> for Runtime {
+        fn get_emission_ledger(from_block: u64, to_block: u64) -> Vec<u8> {
+            let result = BasedNode::get_emission_ledger(from_block, to_block);
+            result.encode()
+        }
+    }
+
+    impl basednode_custom_rpc_runtime_api::EmissionProjectionRuntimeApi<Block> for Runtime {
+        fn project_block_emission(block_number: u64) -> (u128, u128, u128) {
+            BasedNode::project_block_emission(block_number)
+        }
+    }
+
+    impl basednode_custom_rpc_runtime_api::HyperparamChangesRuntimeApi<Block> for Runtime {
+        fn get_pending_hyperparam_changes(netuid: u16) -> Vec<u8> {
+            let result = BasedNode::get_pending_hyperparam_changes(netuid);
+            result.encode()
+        }
+    }
+
+    impl basednode_custom_rpc_runtime_api::EpochDryRunRuntimeApi<Block> for Runtime {
+        fn epoch_dry_run(netuid: u16) -> Vec<u8> {
+            let _result = BasedNode::epoch_dry_run(netuid);
+            if _result.is_some() {
+                let result = _result.expect("Could not get EpochDryRun");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+    }
+
     impl fp_rpc::EthereumRuntimeRPCApi<Block> for Runtime {
         fn chain_id() -> u64 {
             <Runtime as pallet_evm::Config>::ChainId::get()
@@ -0,0 +1,158 @@
+// Lets smart contracts read the metagraph: stake per computekey, the consensus scores of a uid and
+// the size, tempo and owner of a brain. Every function is a view, the values are returned ABI
+// encoded and the EVM is charged the gas equivalent of the storage reads. The Solidity interface
+// is solidity/metagraph.sol.
+use frame_support::traits::Get;
+use pallet_evm::{
+    ExitSucceed, GasWeightMapping, Precompile, PrecompileFailure, PrecompileHandle,
+    PrecompileOutput, PrecompileResult,
+};
+use sp_core::{H160, U256};
+use sp_std::vec::Vec;
+
+use super::{encode_address, encode_uint, record_cost, revert, AbiReader};
+use crate::{AccountId, BasedNode, Runtime};
+
+// Selectors of the functions in solidity/metagraph.sol.
+const GET_STAKE: [u8; 4] = [0x7a, 0x76, 0x64, 0x60]; // getStake(address)
+const GET_UID: [u8; 4] = [0xa8, 0xef, 0x01, 0xb1]; // getUid(uint16,address)
+const GET_COMPUTEKEY: [u8; 4] = [0x8c, 0x7e, 0x9f, 0x1d]; // getComputekey(uint16,uint16)
+const GET_INCENTIVE: [u8; 4] = [0xd1, 0xe4, 0x44, 0x50]; // getIncentive(uint16,uint16)
+const GET_DIVIDENDS: [u8; 4] = [0x82, 0xda, 0x0b, 0x5d]; // getDividends(uint16,uint16)
+const GET_TRUST: [u8; 4] = [0x20, 0x02, 0x8c, 0x19]; // getTrust(uint16,uint16)
+const GET_CONSENSUS: [u8; 4] = [0x2c, 0x4b, 0x6b, 0xff]; // getConsensus(uint16,uint16)
+const GET_EMISSION: [u8; 4] = [0xe5, 0x81, 0x0e, 0x5c]; // getEmission(uint16,uint16)
+const GET_BRAIN_N: [u8; 4] = [0x0e, 0xeb, 0x70, 0x01]; // getBrainN(uint16)
+const GET_TEMPO: [u8; 4] = [0x58, 0x5d, 0x1e, 0x14]; // getTempo(uint16)
+const GET_BRAIN_OWNER: [u8; 4] = [0x63, 0xec, 0xdc, 0xe5]; // getBrainOwner(uint16)
+
+pub struct MetagraphPrecompile;
+
+impl Precompile for MetagraphPrecompile {
+    fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let input = handle.input().to_vec();
+        let (selector, args) = AbiReader::new(&input)?;
+
+        // Every function reads the brain or the stake and at most two more items.
+        record_cost(handle, read_cost(3))?;
+
+        let output: Vec<u8> = match selector {
+            GET_STAKE => {
+                let computekey: AccountId = args.read_address(0)?.into();
+                encode_uint(U256::from(BasedNode::get_total_stake_for_computekey(&computekey)))
+            }
+            GET_UID => {
+                let netuid = ensure_brain(args.read_u16(0)?)?;
+                let computekey: AccountId = args.read_address(1)?.into();
+                let uid = BasedNode::get_uid_for_net_and_computekey(netuid, &computekey)
+                    .map_err(|_| revert("computekey is not registered on the brain"))?;
+                encode_uint(U256::from(uid))
+            }
+            GET_COMPUTEKEY => {
+                let (netuid, uid) = ensure_uid(args.read_u16(0)?, args.read_u16(1)?)?;
+                let computekey: H160 = BasedNode::get_computekey_for_net_and_uid(netuid, uid)
+                    .map_err(|_| revert("uid does not exist on the brain"))?
+                    .into();
+                encode_address(computekey)
+            }
+            GET_INCENTIVE => {
+                let (netuid, uid) = ensure_uid(args.read_u16(0)?, args.read_u16(1)?)?;
+                encode_uint(U256::from(BasedNode::get_incentive_for_uid(netuid, uid)))
+            }
+            GET_DIVIDENDS => {
+                let (netuid, uid) = ensure_uid(args.read_u16(0)?, args.read_u16(1)?)?;
+                encode_uint(U256::from(BasedNode::get_dividends_for_uid(netuid, uid)))
+            }
+            GET_TRUST => {
+                let (netuid, uid) = ensure_uid(args.read_u16(0)?, args.read_u16(1)?)?;
+                encode_uint(U256::from(BasedNode::get_trust_for_uid(netuid, uid)))
+            }
+            GET_CONSENSUS => {
+                let (netuid, uid) = ensure_uid(args.read_u16(0)?, args.read_u16(1)?)?;
+                encode_uint(U256::from(BasedNode::get_consensus_for_uid(netuid, uid)))
+            }
+            GET_EMISSION => {
+                let (netuid, uid) = ensure_uid(args.read_u16(0)?, args.read_u16(1)?)?;
+                encode_uint(U256::from(BasedNode::get_emission_for_uid(netuid, uid)))
+            }
+            GET_BRAIN_N => {
+                let netuid = ensure_brain(args.read_u16(0)?)?;
+                encode_uint(U256::from(BasedNode::get_brain_n(netuid)))
+            }
+            GET_TEMPO => {
+                let netuid = ensure_brain(args.read_u16(0)?)?;
+                encode_uint(U256::from(BasedNode::get_tempo(netuid)))
+            }
+            GET_BRAIN_OWNER => {
+                let netuid = ensure_brain(args.read_u16(0)?)?;
+                let owner: H160 = BasedNode::get_brain_owner(netuid).into();
+                encode_address(owner)
+            }
+            _ => return Err(revert("unknown function selector")),
+        };
+
+        Ok(PrecompileOutput {
+            exit_status: ExitSucceed::Returned,
+            output,
+        })
+    }
+}
+
+// Returns the gas equivalent of the given number of storage reads.
+fn read_cost(reads: u64) -> u64 {
+    let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(reads);
+    <Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(weight)
+}
+
+fn ensure_brain(netuid: u16) -> Result<u16, PrecompileFailure> {
+    if !BasedNode::if_brain_exist(netuid) {
+        return Err(revert("brain does not exist"));
+    }
+    Ok(netuid)
+}
+
+fn ensure_uid(netuid: u16, uid: u16) -> Result<(u16, u16), PrecompileFailure> {
+    let netuid = ensure_brain(netuid)?;
+    if uid >= BasedNode::get_brain_n(netuid) {
+        return Err(revert("uid does not exist on the brain"));
+    }
+    Ok((netuid, uid))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selector_of(signature: &str) -> [u8; 4] {
+        let hash = sp_core::hashing::keccak_256(signature.as_bytes());
+        [hash[0], hash[1], hash[2], hash[3]]
+    }
+
+    #[test]
+    fn selectors_match_the_solidity_interface() {
+        assert_eq!(GET_STAKE, selector_of("getStake(address)"));
+        assert_eq!(GET_UID, selector_of("getUid(uint16,address)"));
+        assert_eq!(GET_COMPUTEKEY, selector_of("getComputekey(uint16,uint16)"));
+        assert_eq!(GET_INCENTIVE, selector_of("getIncentive(uint16,uint16)"));
+        assert_eq!(GET_DIVIDENDS, selector_of("getDividends(uint16,uint16)"));
+        assert_eq!(GET_TRUST, selector_of("getTrust(uint16,uint16)"));
+        assert_eq!(GET_CONSENSUS, selector_of("getConsensus(uint16,uint16)"));
+        assert_eq!(GET_EMISSION, selector_of("getEmission(uint16,uint16)"));
+        assert_eq!(GET_BRAIN_N, selector_of("getBrainN(uint16)"));
+        assert_eq!(GET_TEMPO, selector_of("getTempo(uint16)"));
+        assert_eq!(GET_BRAIN_OWNER, selector_of("getBrainOwner(uint16)"));
+    }
+
+    #[test]
+    fn unknown_brains_and_uids_revert() {
+        frame_support::sp_io::TestExternalities::default().execute_with(|| {
+            assert!(ensure_brain(1).is_err());
+            assert!(ensure_uid(1, 0).is_err());
+
+            // A brain without agents has no uid 0.
+            pallet_basednode::NetworksAdded::<Runtime>::insert(1, true);
+            assert_eq!(ensure_brain(1).ok(), Some(1));
+            assert!(ensure_uid(1, 0).is_err());
+        });
+    }
+}
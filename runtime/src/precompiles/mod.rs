@@ -0,0 +1,249 @@
+use pallet_evm::{
+    ExitError, ExitRevert, Precompile, PrecompileFailure, PrecompileHandle, PrecompileResult,
+    PrecompileSet,
+};
+use sp_core::{H160, U256};
+use sp_std::marker::PhantomData;
+use sp_std::vec::Vec;
+
+use pallet_evm_precompile_modexp::Modexp;
+use pallet_evm_precompile_sha3fips::Sha3FIPS256;
+use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
+
+mod metagraph;
+mod staking;
+use metagraph::MetagraphPrecompile;
+use staking::BasednodePrecompile;
+
+// Basednode precompiles live from 2048 ( 0x800 ) on.
+pub const BASEDNODE_PRECOMPILE_INDEX: u64 = 2048;
+pub const METAGRAPH_PRECOMPILE_INDEX: u64 = 2049;
+
+pub struct FrontierPrecompiles<R>(PhantomData<R>);
+
+impl<R> FrontierPrecompiles<R>
+where
+    R: pallet_evm::Config,
+{
+    pub fn new() -> Self {
+        Self(Default::default())
+    }
+    pub fn used_addresses() -> [H160; 9] {
+        [
+            hash(1),
+            hash(2),
+            hash(3),
+            hash(4),
+            hash(5),
+            hash(1024),
+            hash(1025),
+            hash(BASEDNODE_PRECOMPILE_INDEX),
+            hash(METAGRAPH_PRECOMPILE_INDEX),
+        ]
+    }
+}
+impl<R> PrecompileSet for FrontierPrecompiles<R>
+where
+    R: pallet_evm::Config,
+{
+    fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
+        match handle.code_address() {
+            // Ethereum precompiles :
+            a if a == hash(1) => Some(ECRecover::execute(handle)),
+            a if a == hash(2) => Some(Sha256::execute(handle)),
+            a if a == hash(3) => Some(Ripemd160::execute(handle)),
+            a if a == hash(4) => Some(Identity::execute(handle)),
+            a if a == hash(5) => Some(Modexp::execute(handle)),
+            // Non-Frontier specific nor Ethereum precompiles :
+            a if a == hash(1024) => Some(Sha3FIPS256::execute(handle)),
+            a if a == hash(1025) => Some(ECRecoverPublicKey::execute(handle)),
+            // Basednode precompiles :
+            a if a == hash(BASEDNODE_PRECOMPILE_INDEX) => Some(BasednodePrecompile::execute(handle)),
+            a if a == hash(METAGRAPH_PRECOMPILE_INDEX) => Some(MetagraphPrecompile::execute(handle)),
+            _ => None,
+        }
+    }
+
+    fn is_precompile(&self, address: H160) -> bool {
+        Self::used_addresses().contains(&address)
+    }
+}
+
+fn hash(a: u64) -> H160 {
+    H160::from_low_u64_be(a)
+}
+
+// Reverts the EVM call with the given reason.
+pub fn revert(reason: &str) -> PrecompileFailure {
+    PrecompileFailure::Revert {
+        exit_status: ExitRevert::Reverted,
+        output: reason.as_bytes().to_vec(),
+    }
+}
+
+// Reads the ABI encoded arguments of a call, the 4 byte selector already stripped.
+pub struct AbiReader<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> AbiReader<'a> {
+    // Splits the call input into its selector and the reader of its arguments.
+    pub fn new(input: &'a [u8]) -> Result<([u8; 4], Self), PrecompileFailure> {
+        if input.len() < 4 {
+            return Err(revert("input is missing the function selector"));
+        }
+        let mut selector = [0u8; 4];
+        selector.copy_from_slice(&input[..4]);
+        Ok((selector, Self { input: &input[4..] }))
+    }
+
+    // Returns the 32 byte word at the byte offset.
+    fn word_at(&self, offset: usize) -> Result<U256, PrecompileFailure> {
+        let end = offset
+            .checked_add(32)
+            .ok_or_else(|| revert("argument offset overflows"))?;
+        let word = self
+            .input
+            .get(offset..end)
+            .ok_or_else(|| revert("input is too short"))?;
+        Ok(U256::from_big_endian(word))
+    }
+
+    // Returns the argument at the index as an unsigned integer of at most `bits` bits.
+    pub fn read_uint(&self, index: usize, bits: usize) -> Result<U256, PrecompileFailure> {
+        self.read_uint_at(index.saturating_mul(32), bits)
+    }
+
+    fn read_uint_at(&self, offset: usize, bits: usize) -> Result<U256, PrecompileFailure> {
+        let value = self.word_at(offset)?;
+        if value.bits() > bits {
+            return Err(revert("argument out of range"));
+        }
+        Ok(value)
+    }
+
+    pub fn read_u8(&self, index: usize) -> Result<u8, PrecompileFailure> {
+        Ok(self.read_uint(index, 8)?.low_u32() as u8)
+    }
+
+    pub fn read_u16(&self, index: usize) -> Result<u16, PrecompileFailure> {
+        Ok(self.read_uint(index, 16)?.low_u32() as u16)
+    }
+
+    pub fn read_u32(&self, index: usize) -> Result<u32, PrecompileFailure> {
+        Ok(self.read_uint(index, 32)?.low_u32())
+    }
+
+    pub fn read_u64(&self, index: usize) -> Result<u64, PrecompileFailure> {
+        Ok(self.read_uint(index, 64)?.low_u64())
+    }
+
+    pub fn read_u128(&self, index: usize) -> Result<u128, PrecompileFailure> {
+        Ok(self.read_uint(index, 128)?.low_u128())
+    }
+
+    pub fn read_address(&self, index: usize) -> Result<H160, PrecompileFailure> {
+        let value = self.read_uint(index, 160)?;
+        let mut bytes = [0u8; 32];
+        value.to_big_endian(&mut bytes);
+        Ok(H160::from_slice(&bytes[12..]))
+    }
+
+    // Reads a dynamic uint16[] whose offset is the argument at the index.
+    pub fn read_u16_array(&self, index: usize) -> Result<Vec<u16>, PrecompileFailure> {
+        let offset = self.read_uint(index, 32)?.low_u32() as usize;
+        let length = self.read_uint_at(offset, 32)?.low_u32() as usize;
+        // Every element takes a word, longer arrays can not fit the input.
+        if length > self.input.len() / 32 {
+            return Err(revert("input is too short"));
+        }
+        let mut values = Vec::with_capacity(length);
+        for i in 0..length {
+            let element = offset.saturating_add(32).saturating_add(i * 32);
+            values.push(self.read_uint_at(element, 16)?.low_u32() as u16);
+        }
+        Ok(values)
+    }
+}
+
+// ABI encodes an unsigned integer return value.
+pub fn encode_uint(value: U256) -> Vec<u8> {
+    let mut word = [0u8; 32];
+    value.to_big_endian(&mut word);
+    word.to_vec()
+}
+
+// ABI encodes an address return value.
+pub fn encode_address(address: H160) -> Vec<u8> {
+    let mut word = [0u8; 32];
+    word[12..].copy_from_slice(address.as_bytes());
+    word.to_vec()
+}
+
+// Records the gas the EVM is charged for the given cost, failing when the call ran out of gas.
+pub fn record_cost(handle: &mut impl PrecompileHandle, cost: u64) -> Result<(), PrecompileFailure> {
+    handle
+        .record_cost(cost)
+        .map_err(|exit_status: ExitError| PrecompileFailure::Error { exit_status })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(selector: [u8; 4], words: &[U256]) -> Vec<u8> {
+        let mut input = selector.to_vec();
+        for word in words {
+            input.extend(encode_uint(*word));
+        }
+        input
+    }
+
+    #[test]
+    fn abi_reader_requires_a_selector() {
+        assert!(AbiReader::new(&[0x01, 0x02, 0x03]).is_err());
+        let (selector, args) = AbiReader::new(&[0x01, 0x02, 0x03, 0x04]).unwrap();
+        assert_eq!(selector, [0x01, 0x02, 0x03, 0x04]);
+        assert!(args.read_u16(0).is_err());
+    }
+
+    #[test]
+    fn abi_reader_reads_uints_in_range() {
+        let input = input([0; 4], &[U256::from(u16::MAX), U256::from(u16::MAX as u64 + 1), U256::MAX]);
+        let (_, args) = AbiReader::new(&input).unwrap();
+        assert_eq!(args.read_u16(0).unwrap(), u16::MAX);
+        assert_eq!(args.read_u32(1).unwrap(), u16::MAX as u32 + 1);
+        // Values wider than the type revert instead of being truncated.
+        assert!(args.read_u16(1).is_err());
+        assert!(args.read_u128(2).is_err());
+        assert!(args.read_u8(3).is_err());
+    }
+
+    #[test]
+    fn abi_reader_reads_addresses() {
+        let address = H160::from_low_u64_be(0xabcdef);
+        let mut input = vec![0u8; 4];
+        input.extend(encode_address(address));
+        input.extend(encode_uint(U256::one() << 160));
+        let (_, args) = AbiReader::new(&input).unwrap();
+        assert_eq!(args.read_address(0).unwrap(), address);
+        assert!(args.read_address(1).is_err());
+    }
+
+    #[test]
+    fn abi_reader_reads_u16_arrays() {
+        // One offset word pointing at a length of 3 and its elements.
+        let words: Vec<U256> = [32u64, 3, 1, 2, 3].iter().map(|w| U256::from(*w)).collect();
+        let (_, args) = AbiReader::new(&input([0; 4], &words)).unwrap();
+        assert_eq!(args.read_u16_array(0).unwrap(), vec![1, 2, 3]);
+
+        // A length past the end of the input reverts before allocating.
+        let words: Vec<U256> = [32u64, u32::MAX as u64].iter().map(|w| U256::from(*w)).collect();
+        let (_, args) = AbiReader::new(&input([0; 4], &words)).unwrap();
+        assert!(args.read_u16_array(0).is_err());
+
+        // An offset past the end of the input reverts.
+        let (_, args) = AbiReader::new(&input([0; 4], &[U256::from(4096)])).unwrap();
+        assert!(args.read_u16_array(0).is_err());
+    }
+}
@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: MIT
+pragma solidity >=0.8.0;
+
+/// @dev The basednode precompile address.
+address constant BASEDNODE_ADDRESS = 0x0000000000000000000000000000000000000800;
+
+/// @dev The basednode precompile instance.
+IBasednode constant BASEDNODE_CONTRACT = IBasednode(BASEDNODE_ADDRESS);
+
+/// @title Basednode staking and registration
+/// @notice Dispatches the basednode calls as signed by the account of `msg.sender`.
+/// Calls revert with the name of the basednode error when the call fails.
+interface IBasednode {
+    /// @notice Stakes `amount` from the caller balance onto `computekey`.
+    /// @dev selector: 6374299e
+    function addStake(address computekey, uint256 amount) external;
+
+    /// @notice Removes `amount` of the caller stake from `computekey`, it unbonds before it can be withdrawn.
+    /// @dev selector: 68ce7d8a
+    function removeStake(address computekey, uint256 amount) external;
+
+    /// @notice Registers `computekey` on brain `netuid` by burning the registration cost from the caller balance.
+    /// @dev selector: 0c49f804
+    function burnedRegister(uint16 netuid, address computekey) external;
+
+    /// @notice Serves the brainport of the caller computekey on brain `netuid`.
+    /// @dev selector: c7de6fa3
+    function serveBrainport(
+        uint16 netuid,
+        uint32 version,
+        uint128 ip,
+        uint16 port,
+        uint8 ipType,
+        uint8 protocol,
+        uint8 placeholder1,
+        uint8 placeholder2
+    ) external;
+
+    /// @notice Sets the weights of the caller computekey on brain `netuid`.
+    /// @dev selector: 34af16d6
+    function setWeights(
+        uint16 netuid,
+        uint16[] calldata dests,
+        uint16[] calldata weights,
+        uint64 versionKey
+    ) external;
+}
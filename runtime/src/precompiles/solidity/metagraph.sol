@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: MIT
+pragma solidity >=0.8.0;
+
+/// @dev The metagraph precompile address.
+address constant METAGRAPH_ADDRESS = 0x0000000000000000000000000000000000000801;
+
+/// @dev The metagraph precompile instance.
+IMetagraph constant METAGRAPH_CONTRACT = IMetagraph(METAGRAPH_ADDRESS);
+
+/// @title Basednode metagraph
+/// @notice Reads the consensus state of the brains. Scores are u16 proportions, 65535 is 1.
+/// Calls revert when the brain or the uid does not exist.
+interface IMetagraph {
+    /// @notice Total stake on `computekey` across all its stakers.
+    /// @dev selector: 7a766460
+    function getStake(address computekey) external view returns (uint256);
+
+    /// @notice Uid of `computekey` on brain `netuid`.
+    /// @dev selector: a8ef01b1
+    function getUid(uint16 netuid, address computekey) external view returns (uint16);
+
+    /// @notice Computekey registered on `uid` of brain `netuid`.
+    /// @dev selector: 8c7e9f1d
+    function getComputekey(uint16 netuid, uint16 uid) external view returns (address);
+
+    /// @notice Incentive of `uid` on brain `netuid` at its last epoch.
+    /// @dev selector: d1e44450
+    function getIncentive(uint16 netuid, uint16 uid) external view returns (uint16);
+
+    /// @notice Dividends of `uid` on brain `netuid` at its last epoch.
+    /// @dev selector: 82da0b5d
+    function getDividends(uint16 netuid, uint16 uid) external view returns (uint16);
+
+    /// @notice Trust of `uid` on brain `netuid` at its last epoch.
+    /// @dev selector: 20028c19
+    function getTrust(uint16 netuid, uint16 uid) external view returns (uint16);
+
+    /// @notice Consensus of `uid` on brain `netuid` at its last epoch.
+    /// @dev selector: 2c4b6bff
+    function getConsensus(uint16 netuid, uint16 uid) external view returns (uint16);
+
+    /// @notice Emission of `uid` on brain `netuid` at its last epoch.
+    /// @dev selector: e5810e5c
+    function getEmission(uint16 netuid, uint16 uid) external view returns (uint64);
+
+    /// @notice Number of uids registered on brain `netuid`.
+    /// @dev selector: 0eeb7001
+    function getBrainN(uint16 netuid) external view returns (uint16);
+
+    /// @notice Number of blocks between the epochs of brain `netuid`.
+    /// @dev selector: 585d1e14
+    function getTempo(uint16 netuid) external view returns (uint16);
+
+    /// @notice Owner of brain `netuid`.
+    /// @dev selector: 63ecdce5
+    function getBrainOwner(uint16 netuid) external view returns (address);
+}
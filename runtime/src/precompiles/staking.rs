@@ -0,0 +1,203 @@
+// Lets H160 wallets stake, register, serve and set weights through the EVM. The calls are
+// dispatched to pallet_basednode as signed by the account the caller maps to, the EVM is charged
+// the gas equivalent of the call weight and the account the scheduled fee of the call. The Solidity interface is solidity/basednode.sol.
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use pallet_evm::{
+    ExitSucceed, GasWeightMapping, Precompile, PrecompileFailure, PrecompileHandle,
+    PrecompileOutput, PrecompileResult,
+};
+use sp_core::H160;
+use sp_runtime::traits::Dispatchable;
+use sp_std::vec;
+
+use super::{record_cost, revert, AbiReader};
+use crate::{AccountId, BasedNode, Runtime, RuntimeCall, RuntimeOrigin};
+use pallet_basednode::BasednodeSignedExtension;
+
+// Selectors of the functions in solidity/basednode.sol.
+const ADD_STAKE: [u8; 4] = [0x63, 0x74, 0x29, 0x9e]; // addStake(address,uint256)
+const REMOVE_STAKE: [u8; 4] = [0x68, 0xce, 0x7d, 0x8a]; // removeStake(address,uint256)
+const BURNED_REGISTER: [u8; 4] = [0x0c, 0x49, 0xf8, 0x04]; // burnedRegister(uint16,address)
+const SERVE_BRAINPORT: [u8; 4] = [0xc7, 0xde, 0x6f, 0xa3]; // serveBrainport(uint16,uint32,uint128,uint16,uint8,uint8,uint8,uint8)
+const SET_WEIGHTS: [u8; 4] = [0x34, 0xaf, 0x16, 0xd6]; // setWeights(uint16,uint16[],uint16[],uint64)
+
+pub struct BasednodePrecompile;
+
+impl Precompile for BasednodePrecompile {
+    fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        ensure_direct_call(handle.code_address(), handle.context().address)?;
+
+        let input = handle.input().to_vec();
+        let (selector, args) = AbiReader::new(&input)?;
+        let call = decode_call(selector, &args)?;
+
+        // Every function changes state.
+        if handle.is_static() {
+            return Err(revert("can not be called in a static context"));
+        }
+
+        let call: RuntimeCall = call.into();
+        let weight = call.get_dispatch_info().weight;
+        record_cost(
+            handle,
+            <Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(weight),
+        )?;
+
+        let caller: AccountId = handle.context().caller.into();
+        dispatch(call, caller)?;
+
+        Ok(PrecompileOutput {
+            exit_status: ExitSucceed::Returned,
+            output: vec![],
+        })
+    }
+}
+
+// Reverts unless the precompile runs in its own context. Through DELEGATECALL or CALLCODE it runs in
+// the context of the calling contract, with the account that called that contract as caller, which
+// would let any contract stake, register and set weights for the accounts that call it.
+fn ensure_direct_call(code_address: H160, context_address: H160) -> Result<(), PrecompileFailure> {
+    if code_address != context_address {
+        return Err(revert("can only be called directly"));
+    }
+    Ok(())
+}
+
+// Decodes the pallet_basednode call of the function the selector names from its ABI encoded arguments.
+fn decode_call(
+    selector: [u8; 4],
+    args: &AbiReader,
+) -> Result<pallet_basednode::Call<Runtime>, PrecompileFailure> {
+    Ok(match selector {
+        ADD_STAKE => pallet_basednode::Call::<Runtime>::add_stake {
+            computekey: args.read_address(0)?.into(),
+            amount_staked: args.read_u128(1)?,
+        },
+        REMOVE_STAKE => pallet_basednode::Call::<Runtime>::remove_stake {
+            computekey: args.read_address(0)?.into(),
+            amount_unstaked: args.read_u128(1)?,
+        },
+        BURNED_REGISTER => pallet_basednode::Call::<Runtime>::burned_register {
+            netuid: args.read_u16(0)?,
+            computekey: args.read_address(1)?.into(),
+        },
+        SERVE_BRAINPORT => pallet_basednode::Call::<Runtime>::serve_brainport {
+            netuid: args.read_u16(0)?,
+            version: args.read_u32(1)?,
+            ip: args.read_u128(2)?,
+            port: args.read_u16(3)?,
+            ip_type: args.read_u8(4)?,
+            protocol: args.read_u8(5)?,
+            placeholder1: args.read_u8(6)?,
+            placeholder2: args.read_u8(7)?,
+        },
+        SET_WEIGHTS => pallet_basednode::Call::<Runtime>::set_weights {
+            netuid: args.read_u16(0)?,
+            dests: args.read_u16_array(1)?,
+            weights: args.read_u16_array(2)?,
+            version_key: args.read_u64(3)?,
+        },
+        _ => return Err(revert("unknown function selector")),
+    })
+}
+
+// Dispatches the call signed by the account, reverting with the name of the error on failure. The
+// precompile does not go through BasednodeSignedExtension, so the scheduled fee of the call is
+// withdrawn and settled here the same way.
+fn dispatch(call: RuntimeCall, account: AccountId) -> Result<PostDispatchInfo, PrecompileFailure> {
+    let info = call.get_dispatch_info();
+    let fee: u128 = BasednodeSignedExtension::<Runtime>::get_transaction_fee(&call);
+    if !BasedNode::withdraw_transaction_fee(&account, fee) {
+        return Err(revert("not enough balance to pay the transaction fee"));
+    }
+
+    let result = call.dispatch(RuntimeOrigin::signed(account.clone()));
+    let post_info = match &result {
+        Ok(post_info) => *post_info,
+        Err(e) => e.post_info,
+    };
+    BasedNode::settle_transaction_fee(
+        &account,
+        fee,
+        BasednodeSignedExtension::<Runtime>::get_transaction_fee_refund(fee, &info, &post_info),
+    );
+
+    result.map_err(|e| {
+        let reason: &'static str = e.error.into();
+        revert(reason)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sp_core::U256;
+
+    fn selector_of(signature: &str) -> [u8; 4] {
+        let hash = sp_core::hashing::keccak_256(signature.as_bytes());
+        [hash[0], hash[1], hash[2], hash[3]]
+    }
+
+    fn word(value: u64) -> Vec<u8> {
+        super::super::encode_uint(U256::from(value))
+    }
+
+    #[test]
+    fn selectors_match_the_solidity_interface() {
+        assert_eq!(ADD_STAKE, selector_of("addStake(address,uint256)"));
+        assert_eq!(REMOVE_STAKE, selector_of("removeStake(address,uint256)"));
+        assert_eq!(BURNED_REGISTER, selector_of("burnedRegister(uint16,address)"));
+        assert_eq!(
+            SERVE_BRAINPORT,
+            selector_of("serveBrainport(uint16,uint32,uint128,uint16,uint8,uint8,uint8,uint8)")
+        );
+        assert_eq!(SET_WEIGHTS, selector_of("setWeights(uint16,uint16[],uint16[],uint64)"));
+    }
+
+    #[test]
+    fn rejects_delegatecall_contexts() {
+        let precompile = H160::from_low_u64_be(super::super::BASEDNODE_PRECOMPILE_INDEX);
+        let contract = H160::from_low_u64_be(0xbad);
+        assert!(ensure_direct_call(precompile, precompile).is_ok());
+        // A contract delegatecalling the precompile runs it at its own address.
+        assert!(ensure_direct_call(precompile, contract).is_err());
+    }
+
+    #[test]
+    fn decodes_calls_by_selector() {
+        let computekey = H160::from_low_u64_be(7);
+        let mut input = ADD_STAKE.to_vec();
+        input.extend(super::super::encode_address(computekey));
+        input.extend(word(1_000));
+        let (selector, args) = AbiReader::new(&input).unwrap();
+        assert_eq!(
+            decode_call(selector, &args).unwrap(),
+            pallet_basednode::Call::<Runtime>::add_stake {
+                computekey: computekey.into(),
+                amount_staked: 1_000,
+            }
+        );
+
+        // setWeights(1, [2, 3], [4, 5], 6), the arrays are encoded after the head.
+        let mut input = SET_WEIGHTS.to_vec();
+        for value in [1, 128, 224, 6, 2, 2, 3, 2, 4, 5] {
+            input.extend(word(value));
+        }
+        let (selector, args) = AbiReader::new(&input).unwrap();
+        assert_eq!(
+            decode_call(selector, &args).unwrap(),
+            pallet_basednode::Call::<Runtime>::set_weights {
+                netuid: 1,
+                dests: vec![2, 3],
+                weights: vec![4, 5],
+                version_key: 6,
+            }
+        );
+
+        let (selector, args) = AbiReader::new(&[0xde, 0xad, 0xbe, 0xef]).unwrap();
+        assert!(decode_call(selector, &args).is_err());
+        // Missing arguments revert instead of defaulting.
+        let (selector, args) = AbiReader::new(&REMOVE_STAKE).unwrap();
+        assert!(decode_call(selector, &args).is_err());
+    }
+}